@@ -1,6 +1,13 @@
 use std::env;
 
 fn main() {
+    // Declare the cfgs set below so that `unexpected_cfgs` doesn't warn about them. Cargo versions
+    // that don't know about `rustc-check-cfg` treat it as build script metadata and ignore it.
+    println!("cargo:rustc-check-cfg=cfg(has_atomics)");
+    println!("cargo:rustc-check-cfg=cfg(has_atomics_64)");
+    // Enabled when building docs to show which features items require
+    println!("cargo:rustc-check-cfg=cfg(doc_cfg)");
+
     let mut has_atomic32 = true;
     let mut has_atomic64 = true;
 
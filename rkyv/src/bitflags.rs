@@ -0,0 +1,382 @@
+//! Archiving support for flags types generated by the `bitflags` crate.
+//!
+//! See [`archive_bitflags`](crate::archive_bitflags) for more details.
+
+use crate::Archived;
+use core::fmt;
+
+/// An error that occurs when an archived flags value has bits set that don't correspond to any
+/// defined flag.
+#[derive(Debug)]
+pub struct UnknownBitsError<T> {
+    /// The bits that don't correspond to any defined flag.
+    pub unknown_bits: T,
+}
+
+impl<T: fmt::LowerHex> fmt::Display for UnknownBitsError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown flag bits set: {:#x}", self.unknown_bits)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug + fmt::LowerHex> std::error::Error for UnknownBitsError<T> {}
+
+/// Integer types that can back archived flags.
+#[doc(hidden)]
+pub trait Bits: crate::Archive {
+    fn from_archived_bits(archived: &Archived<Self>) -> Self;
+
+    fn to_archived_bits(self) -> Archived<Self>;
+}
+
+macro_rules! impl_bits_byte {
+    ($($ty:ty),*) => {
+        $(
+            impl Bits for $ty {
+                #[inline]
+                fn from_archived_bits(archived: &Archived<Self>) -> Self {
+                    *archived
+                }
+
+                #[inline]
+                fn to_archived_bits(self) -> Archived<Self> {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_bits_byte!(u8, i8);
+
+macro_rules! impl_bits {
+    ($($ty:ty),*) => {
+        $(
+            impl Bits for $ty {
+                #[inline]
+                fn from_archived_bits(archived: &Archived<Self>) -> Self {
+                    from_archived!(*archived)
+                }
+
+                #[inline]
+                fn to_archived_bits(self) -> Archived<Self> {
+                    to_archived!(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_bits!(u16, u32, u64, u128, i16, i32, i64, i128);
+
+/// Implements [`Archive`](crate::Archive), [`Serialize`](crate::Serialize), and
+/// [`Deserialize`](crate::Deserialize) for a flags type generated by `bitflags!`.
+///
+/// Flags are archived as their backing integer. The archived type is declared by the macro and
+/// exposes `bits`, `from_bits`, `contains`, `intersects`, `is_empty`, and `flags`.
+///
+/// By default, validation rejects archived flags that have unknown bits set, and deserialization
+/// drops any unknown bits of unvalidated archives. Adding `allow_unknown_bits` after the backing
+/// integer type accepts all bit patterns during validation and keeps unknown bits when
+/// deserializing, which is useful for flags that may gain new values in future versions.
+///
+/// # Examples
+///
+/// ```
+/// use bitflags::bitflags;
+/// use rkyv::{archive_bitflags, archived_root, Deserialize, Infallible};
+///
+/// bitflags! {
+///     pub struct Permissions: u8 {
+///         const READ = 0b001;
+///         const WRITE = 0b010;
+///         const EXECUTE = 0b100;
+///     }
+/// }
+///
+/// archive_bitflags! {
+///     /// The archived version of `Permissions`.
+///     pub struct ArchivedPermissions for Permissions: u8;
+/// }
+///
+/// let value = Permissions::READ | Permissions::WRITE;
+/// let bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+/// let archived = unsafe { archived_root::<Permissions>(&bytes) };
+/// assert_eq!(archived.bits(), 0b011);
+/// assert!(archived.contains(Permissions::WRITE));
+/// assert!(!archived.contains(Permissions::EXECUTE));
+///
+/// let deserialized: Permissions = archived.deserialize(&mut Infallible).unwrap();
+/// assert_eq!(deserialized, value);
+/// ```
+#[macro_export]
+macro_rules! archive_bitflags {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $archived:ident for $flags:ty: $bits:ty $(, $option:ident)?;
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        #[repr(transparent)]
+        $vis struct $archived($crate::Archived<$bits>);
+
+        impl $archived {
+            /// Returns the raw bits of the archived flags.
+            #[inline]
+            pub fn bits(&self) -> $bits {
+                <$bits as $crate::bitflags::Bits>::from_archived_bits(&self.0)
+            }
+
+            /// Creates archived flags from raw bits, or returns `None` if any unknown bits are set.
+            #[inline]
+            pub fn from_bits(bits: $bits) -> ::core::option::Option<Self> {
+                <$flags>::from_bits(bits).map(|flags| {
+                    Self(<$bits as $crate::bitflags::Bits>::to_archived_bits(flags.bits()))
+                })
+            }
+
+            /// Returns whether all of the given flags are set.
+            #[inline]
+            pub fn contains(&self, other: $flags) -> bool {
+                self.bits() & other.bits() == other.bits()
+            }
+
+            /// Returns whether any of the given flags are set.
+            #[inline]
+            pub fn intersects(&self, other: $flags) -> bool {
+                self.bits() & other.bits() != <$flags>::empty().bits()
+            }
+
+            /// Returns whether no flags are set.
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.bits() == <$flags>::empty().bits()
+            }
+
+            /// Returns the archived flags as flags, or `None` if any unknown bits are set.
+            #[inline]
+            pub fn flags(&self) -> ::core::option::Option<$flags> {
+                <$flags>::from_bits(self.bits())
+            }
+        }
+
+        impl ::core::fmt::Debug for $archived {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self.flags() {
+                    ::core::option::Option::Some(flags) => ::core::fmt::Debug::fmt(&flags, f),
+                    ::core::option::Option::None => f
+                        .debug_tuple(::core::stringify!($archived))
+                        .field(&self.bits())
+                        .finish(),
+                }
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$flags> for $archived {
+            #[inline]
+            fn eq(&self, other: &$flags) -> bool {
+                self.bits() == other.bits()
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$archived> for $flags {
+            #[inline]
+            fn eq(&self, other: &$archived) -> bool {
+                self.bits() == other.bits()
+            }
+        }
+
+        impl $crate::Archive for $flags {
+            type Archived = $archived;
+            type Resolver = ();
+
+            #[inline]
+            unsafe fn resolve(&self, _: usize, _: (), out: *mut Self::Archived) {
+                out.write($archived(<$bits as $crate::bitflags::Bits>::to_archived_bits(
+                    self.bits(),
+                )));
+            }
+        }
+
+        impl<S: $crate::Fallible + ?Sized> $crate::Serialize<S> for $flags {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> ::core::result::Result<(), S::Error> {
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        impl<D: $crate::Fallible + ?Sized> $crate::Deserialize<$flags, D> for $archived {
+            #[inline]
+            fn deserialize(&self, _: &mut D) -> ::core::result::Result<$flags, D::Error> {
+                ::core::result::Result::Ok($crate::__archive_bitflags_from_bits!(
+                    $flags, self.bits() $(, $option)?
+                ))
+            }
+        }
+
+        $crate::__archive_bitflags_check_bytes!($archived, $flags, $bits $(, $option)?);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __archive_bitflags_from_bits {
+    ($flags:ty, $bits:expr) => {
+        <$flags>::from_bits_truncate($bits)
+    };
+    ($flags:ty, $bits:expr, allow_unknown_bits) => {
+        // Unknown bits are allowed by the archive format, so they're kept
+        unsafe { <$flags>::from_bits_unchecked($bits) }
+    };
+}
+
+#[cfg(feature = "validation")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __archive_bitflags_check_bytes {
+    ($archived:ident, $flags:ty, $bits:ty) => {
+        impl<C: $crate::Fallible + ?Sized> $crate::macros::bytecheck::CheckBytes<C> for $archived {
+            type Error = $crate::bitflags::UnknownBitsError<$bits>;
+
+            #[inline]
+            unsafe fn check_bytes<'a>(
+                value: *const Self,
+                _: &mut C,
+            ) -> ::core::result::Result<&'a Self, Self::Error> {
+                let value = &*value;
+                let unknown_bits = value.bits() & !<$flags>::all().bits();
+                if unknown_bits != <$flags>::empty().bits() {
+                    ::core::result::Result::Err($crate::bitflags::UnknownBitsError { unknown_bits })
+                } else {
+                    ::core::result::Result::Ok(value)
+                }
+            }
+        }
+    };
+    ($archived:ident, $flags:ty, $bits:ty, allow_unknown_bits) => {
+        impl<C: $crate::Fallible + ?Sized> $crate::macros::bytecheck::CheckBytes<C> for $archived {
+            type Error = ::core::convert::Infallible;
+
+            #[inline]
+            unsafe fn check_bytes<'a>(
+                value: *const Self,
+                _: &mut C,
+            ) -> ::core::result::Result<&'a Self, Self::Error> {
+                // All bit patterns are valid
+                ::core::result::Result::Ok(&*value)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "validation"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __archive_bitflags_check_bytes {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        archived_root,
+        ser::{serializers::CoreSerializer, Serializer},
+        AlignedBytes, Deserialize, Infallible,
+    };
+    use ::bitflags::bitflags;
+
+    bitflags! {
+        struct Flags: u32 {
+            const A = 0b0001;
+            const B = 0b0010;
+            const C = 0b1000;
+        }
+    }
+
+    archive_bitflags! {
+        struct ArchivedFlags for Flags: u32;
+    }
+
+    bitflags! {
+        struct OpenFlags: u16 {
+            const A = 0b01;
+            const B = 0b10;
+        }
+    }
+
+    archive_bitflags! {
+        struct ArchivedOpenFlags for OpenFlags: u16, allow_unknown_bits;
+    }
+
+    fn to_buffer<T>(value: &T) -> (AlignedBytes<256>, usize)
+    where
+        T: crate::Serialize<CoreSerializer<256, 256>>,
+    {
+        let mut serializer = CoreSerializer::<256, 256>::default();
+        serializer.serialize_value(value).unwrap();
+        let end = serializer.pos();
+        (serializer.into_serializer().into_inner(), end)
+    }
+
+    #[test]
+    fn bitflags_round_trip() {
+        let value = Flags::A | Flags::C;
+        let (buffer, end) = to_buffer(&value);
+        let archived = unsafe { archived_root::<Flags>(&buffer[..end]) };
+
+        assert_eq!(archived.bits(), 0b1001);
+        assert!(archived.contains(Flags::A));
+        assert!(archived.contains(Flags::A | Flags::C));
+        assert!(!archived.contains(Flags::B));
+        assert!(archived.intersects(Flags::B | Flags::C));
+        assert!(!archived.is_empty());
+        assert_eq!(archived.flags(), Some(value));
+        assert_eq!(*archived, value);
+        assert_eq!(ArchivedFlags::from_bits(0b1001), Some(*archived));
+        assert_eq!(ArchivedFlags::from_bits(0b0100), None);
+        assert_eq!(format!("{:?}", archived), format!("{:?}", value));
+
+        let deserialized: Flags = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, value);
+    }
+
+    #[test]
+    fn bitflags_unknown_bits() {
+        // Unknown bits are dropped when deserializing by default
+        let (buffer, end) = to_buffer(&0b1101u32);
+        let archived = unsafe { archived_root::<Flags>(&buffer[..end]) };
+        assert_eq!(archived.bits(), 0b1101);
+        assert_eq!(archived.flags(), None);
+        let deserialized: Flags = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, Flags::A | Flags::C);
+
+        // And kept with allow_unknown_bits
+        let (buffer, end) = to_buffer(&0b111u16);
+        let archived = unsafe { archived_root::<OpenFlags>(&buffer[..end]) };
+        assert!(archived.contains(OpenFlags::A | OpenFlags::B));
+        assert!(archived.intersects(OpenFlags::B));
+        assert!(!archived.is_empty());
+        assert_eq!(ArchivedOpenFlags::from_bits(0b100), None);
+        let deserialized: OpenFlags = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized.bits(), 0b111);
+    }
+
+    #[test]
+    #[cfg(feature = "validation")]
+    fn check_bitflags() {
+        use crate::check_archived_root;
+
+        let (buffer, end) = to_buffer(&(Flags::A | Flags::B));
+        let archived = check_archived_root::<Flags>(&buffer[..end]).unwrap();
+        assert_eq!(*archived, Flags::A | Flags::B);
+
+        let (buffer, end) = to_buffer(&0b0101u32);
+        let error = check_archived_root::<Flags>(&buffer[..end]).unwrap_err();
+        assert!(error.to_string().contains("0x4"));
+
+        let (buffer, end) = to_buffer(&0b101u16);
+        let archived = check_archived_root::<OpenFlags>(&buffer[..end]).unwrap();
+        assert_eq!(archived.bits(), 0b101);
+    }
+}
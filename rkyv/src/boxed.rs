@@ -9,6 +9,10 @@ use core::{borrow::Borrow, cmp, fmt, hash, ops::Deref, pin::Pin};
 /// An archived [`Box`].
 ///
 /// This is a thin wrapper around a [`RelPtr`] to the archived type.
+///
+/// A boxed slice like `Box<[T]>` archives as an `ArchivedBox<[T::Archived]>`, which stores only the
+/// length of the slice as the pointer's metadata. It deserializes back to a `Box<[T]>` instead of a
+/// `Vec<T>`.
 #[repr(transparent)]
 pub struct ArchivedBox<T: ArchivePointee + ?Sized>(RelPtr<T>);
 
@@ -19,7 +23,12 @@ impl<T: ArchivePointee + ?Sized> ArchivedBox<T> {
         unsafe { &*self.0.as_ptr() }
     }
 
-    /// Returns a pinned mutable reference to the value of this archived box
+    /// Returns a pinned mutable reference to the value of this archived box.
+    ///
+    /// This can be used to edit an archive in place, for example through a writable memory map.
+    /// The box is pinned because its relative pointer is only valid at its current position in the
+    /// archive. Mutations are written directly into the backing buffer, so they must leave the
+    /// archived value the same size. Values that need to grow or shrink must be serialized again.
     #[inline]
     pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
         unsafe { self.map_unchecked_mut(|s| &mut *s.0.as_mut_ptr()) }
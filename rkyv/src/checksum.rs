@@ -0,0 +1,174 @@
+//! Lightweight integrity checks for archives using a CRC32 trailer.
+//!
+//! A [`ChecksumSerializer`](crate::ser::serializers::ChecksumSerializer) appends a
+//! [`ChecksumTrailer`] to the end of an archive, and [`verify_checksum`] checks and strips it
+//! before the archive is accessed. This is much cheaper than full validation and catches bytes that
+//! were truncated or corrupted in transit, but it does not protect against maliciously crafted
+//! archives. Use validation for that.
+
+use crate::Archived;
+use core::{fmt, mem::size_of, ptr};
+
+const fn make_crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+static CRC32_TABLE: [u32; 256] = make_crc32_table();
+
+/// A running CRC32 (IEEE) checksum.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Crc32 {
+    state: u32,
+}
+
+impl Crc32 {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self { state: !0 }
+    }
+
+    #[inline]
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            let index = (self.state ^ byte as u32) & 0xff;
+            self.state = (self.state >> 8) ^ CRC32_TABLE[index as usize];
+        }
+    }
+
+    #[inline]
+    pub(crate) fn finish(&self) -> u32 {
+        !self.state
+    }
+}
+
+/// Calculates the CRC32 (IEEE) checksum of some bytes.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(rkyv::checksum::crc32(b"123456789"), 0xcbf4_3926);
+/// ```
+#[inline]
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = Crc32::new();
+    crc.update(bytes);
+    crc.finish()
+}
+
+/// The trailer appended to the end of an archive by a
+/// [`ChecksumSerializer`](crate::ser::serializers::ChecksumSerializer).
+///
+/// The trailer is always located in the last `size_of::<ChecksumTrailer>()` bytes of the buffer.
+/// It is not aligned, so it should be read with [`ptr::read_unaligned`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct ChecksumTrailer {
+    /// The CRC32 checksum of the archive bytes that precede the trailer
+    pub checksum: Archived<u32>,
+}
+
+impl ChecksumTrailer {
+    /// Returns the bytes of the trailer.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: ChecksumTrailer is repr(C) and contains only a single integer, so it has no
+        // padding bytes.
+        unsafe {
+            core::slice::from_raw_parts((self as *const Self).cast::<u8>(), size_of::<Self>())
+        }
+    }
+}
+
+/// An error that occurred while verifying the checksum of an archive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChecksumError {
+    /// The buffer is too short to contain a checksum trailer
+    MissingTrailer {
+        /// The length of the buffer
+        len: usize,
+    },
+    /// The checksum in the trailer does not match the checksum of the archive bytes
+    Mismatch {
+        /// The checksum stored in the trailer
+        expected: u32,
+        /// The checksum of the archive bytes
+        actual: u32,
+    },
+}
+
+impl fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChecksumError::MissingTrailer { len } => write!(
+                f,
+                "buffer of length {} is too short to contain a checksum trailer",
+                len
+            ),
+            ChecksumError::Mismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch: expected {:#010x}, found {:#010x}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChecksumError {}
+
+/// Verifies the checksum trailer at the end of some bytes and returns the archive bytes without
+/// the trailer.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::{
+///     archived_root,
+///     checksum::verify_checksum,
+///     ser::{serializers::{AlignedSerializer, ChecksumSerializer}, Serializer},
+///     AlignedVec,
+/// };
+///
+/// let mut serializer = ChecksumSerializer::new(AlignedSerializer::new(AlignedVec::new()));
+/// serializer.serialize_value(&42u32).unwrap();
+/// let bytes = serializer.finish().unwrap().into_inner();
+///
+/// let archive = verify_checksum(&bytes).unwrap();
+/// let archived = unsafe { archived_root::<u32>(archive) };
+/// assert_eq!(*archived, 42);
+/// ```
+#[inline]
+pub fn verify_checksum(bytes: &[u8]) -> Result<&[u8], ChecksumError> {
+    let archive_len = bytes
+        .len()
+        .checked_sub(size_of::<ChecksumTrailer>())
+        .ok_or(ChecksumError::MissingTrailer { len: bytes.len() })?;
+    let (archive, trailer) = bytes.split_at(archive_len);
+    // SAFETY: `trailer` is exactly `size_of::<ChecksumTrailer>()` bytes long, and any bit pattern is
+    // a valid `ChecksumTrailer`.
+    let trailer = unsafe { ptr::read_unaligned(trailer.as_ptr().cast::<ChecksumTrailer>()) };
+
+    let expected = from_archived!(trailer.checksum);
+    let actual = crc32(archive);
+    if expected == actual {
+        Ok(archive)
+    } else {
+        Err(ChecksumError::Mismatch { expected, actual })
+    }
+}
@@ -0,0 +1,111 @@
+//! Archived version of `BinaryHeap`.
+
+use crate::{
+    ser::{ScratchSpace, Serializer},
+    vec::{ArchivedVec, VecResolver},
+    Serialize,
+};
+use core::{borrow::Borrow, fmt, slice};
+
+/// An archived `BinaryHeap`.
+///
+/// The elements are stored in the same order as the backing vector of the heap they were
+/// serialized from, so the greatest element is always first and [`peek`](ArchivedBinaryHeap::peek)
+/// runs in constant time. This relies on the archived elements being ordered the same way as the
+/// unarchived elements.
+///
+/// Validation does not check that the elements are in heap order. A malformed archive may return
+/// an element from `peek` that is not the greatest.
+#[cfg_attr(feature = "validation", derive(bytecheck::CheckBytes))]
+#[repr(transparent)]
+pub struct ArchivedBinaryHeap<T>(ArchivedVec<T>);
+
+impl<T> ArchivedBinaryHeap<T> {
+    /// Returns the greatest item in the binary heap, or `None` if it is empty.
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        self.0.first()
+    }
+
+    /// Returns the number of items in the binary heap.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the binary heap contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets an iterator over the items in the binary heap, in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    /// Gets the items of the binary heap as a slice in heap order.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+
+    /// Resolves an archived binary heap from its length.
+    ///
+    /// # Safety
+    ///
+    /// - `len` must be the number of elements that were serialized
+    /// - `pos` must be the position of `out` within the archive
+    /// - `resolver` must be the result of serializing a binary heap
+    #[inline]
+    pub unsafe fn resolve_from_len(
+        len: usize,
+        pos: usize,
+        resolver: BinaryHeapResolver,
+        out: *mut Self,
+    ) {
+        let (fp, fo) = out_field!(out.0);
+        ArchivedVec::resolve_from_len(len, pos + fp, resolver.0, fo);
+    }
+
+    /// Serializes the items of a binary heap in heap order.
+    ///
+    /// # Safety
+    ///
+    /// The items returned by the iterator must be in heap order: every item at index `i` must be
+    /// greater than or equal to the items at indices `2 * i + 1` and `2 * i + 2`.
+    #[inline]
+    pub unsafe fn serialize_from_iter<U, B, I, S>(
+        iter: I,
+        serializer: &mut S,
+    ) -> Result<BinaryHeapResolver, S::Error>
+    where
+        U: Serialize<S, Archived = T>,
+        B: Borrow<U>,
+        I: ExactSizeIterator<Item = B>,
+        S: ScratchSpace + Serializer + ?Sized,
+    {
+        Ok(BinaryHeapResolver(ArchivedVec::serialize_from_iter(
+            iter, serializer,
+        )?))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArchivedBinaryHeap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ArchivedBinaryHeap<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The resolver for archived binary heaps.
+pub struct BinaryHeapResolver(VecResolver);
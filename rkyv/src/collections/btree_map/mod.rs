@@ -11,7 +11,7 @@ use core::{
     hash::{Hash, Hasher},
     iter::FusedIterator,
     marker::PhantomData,
-    ops::Index,
+    ops::{Bound, Index, RangeBounds},
     ptr::NonNull,
 };
 use ptr_meta::Pointee;
@@ -236,6 +236,84 @@ impl<K, V> ArchivedBTreeMap<K, V> {
         }
     }
 
+    #[inline]
+    fn last(&self) -> NonNull<NodeHeader> {
+        if let Some(mut node) = self.root() {
+            while let ClassifiedNode::Inner(inner) = node {
+                let last = &inner.tail[inner.tail.len() - 1];
+                let next = unsafe { &*last.ptr.as_ptr() };
+                node = next.classify();
+            }
+            match node {
+                ClassifiedNode::Leaf(leaf) => unsafe {
+                    let node = (leaf as *const LeafNode<K, V> as *mut LeafNode<K, V>).cast();
+                    NonNull::new_unchecked(node)
+                },
+                ClassifiedNode::Inner(_) => unsafe { core::hint::unreachable_unchecked() },
+            }
+        } else {
+            NonNull::dangling()
+        }
+    }
+
+    /// Descends the tree to the leaf node that would contain the given key.
+    fn leaf_for<Q: Ord + ?Sized>(&self, k: &Q) -> Option<&LeafNode<K, V>>
+    where
+        K: Borrow<Q> + Ord,
+    {
+        let mut current = self.root()?;
+        loop {
+            match current {
+                ClassifiedNode::Inner(node) => {
+                    // Binary search for the next node layer
+                    let next = match node
+                        .tail
+                        .binary_search_by(|probe| probe.key.borrow().cmp(k))
+                    {
+                        Ok(i) => unsafe { &*node.tail[i].ptr.as_ptr() },
+                        Err(i) => {
+                            if i == 0 {
+                                unsafe { &*node.header.ptr.as_ptr() }
+                            } else {
+                                unsafe { &*node.tail[i - 1].ptr.as_ptr() }
+                            }
+                        }
+                    };
+                    current = next.classify();
+                }
+                ClassifiedNode::Leaf(node) => break Some(node),
+            }
+        }
+    }
+
+    /// Finds the position of the first entry that lies after the given bound.
+    ///
+    /// An included bound positions before any key equal to it, an excluded bound positions after
+    /// it.
+    fn position_after<Q: Ord + ?Sized>(&self, bound: Bound<&Q>) -> (NonNull<NodeHeader>, usize)
+    where
+        K: Borrow<Q> + Ord,
+    {
+        let (k, inclusive) = match bound {
+            Bound::Included(k) => (k, true),
+            Bound::Excluded(k) => (k, false),
+            Bound::Unbounded => return (self.first(), 0),
+        };
+        if let Some(leaf) = self.leaf_for(k) {
+            let index = leaf
+                .tail
+                .partition_point(|probe| match probe.key.borrow().cmp(k) {
+                    Ordering::Less => true,
+                    Ordering::Equal => !inclusive,
+                    Ordering::Greater => false,
+                });
+            let node = (leaf as *const LeafNode<K, V> as *mut LeafNode<K, V>).cast();
+            normalize_position(unsafe { NonNull::new_unchecked(node) }, index)
+        } else {
+            (NonNull::dangling(), 0)
+        }
+    }
+
     /// Returns `true` if the map contains a value for the specified key.
     ///
     /// The key may be any borrowed form of the map's key type, but the ordering on the borrowed
@@ -268,42 +346,42 @@ impl<K, V> ArchivedBTreeMap<K, V> {
     where
         K: Borrow<Q> + Ord,
     {
-        if let Some(mut current) = self.root() {
-            loop {
-                match current {
-                    ClassifiedNode::Inner(node) => {
-                        // Binary search for the next node layer
-                        let next = match node
-                            .tail
-                            .binary_search_by(|probe| probe.key.borrow().cmp(k))
-                        {
-                            Ok(i) => unsafe { &*node.tail[i].ptr.as_ptr() },
-                            Err(i) => {
-                                if i == 0 {
-                                    unsafe { &*node.header.ptr.as_ptr() }
-                                } else {
-                                    unsafe { &*node.tail[i - 1].ptr.as_ptr() }
-                                }
-                            }
-                        };
-                        current = next.classify();
-                    }
-                    ClassifiedNode::Leaf(node) => {
-                        // Binary search for the value
-                        if let Ok(i) = node
-                            .tail
-                            .binary_search_by(|probe| probe.key.borrow().cmp(k))
-                        {
-                            let entry = &node.tail[i];
-                            break Some((&entry.key, &entry.value));
-                        } else {
-                            break None;
-                        }
-                    }
-                }
-            }
+        let node = self.leaf_for(k)?;
+        // Binary search for the value
+        if let Ok(i) = node
+            .tail
+            .binary_search_by(|probe| probe.key.borrow().cmp(k))
+        {
+            let entry = &node.tail[i];
+            Some((&entry.key, &entry.value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the first key-value pair in the map. The key in this pair is the minimum key in the
+    /// map.
+    #[inline]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        if self.is_empty() {
+            None
         } else {
+            // SAFETY: the first node of a non-empty map is always a leaf node
+            let leaf = unsafe { self.first().as_ref().classify_leaf::<K, V>() };
+            leaf.tail.first().map(|entry| (&entry.key, &entry.value))
+        }
+    }
+
+    /// Returns the last key-value pair in the map. The key in this pair is the maximum key in the
+    /// map.
+    #[inline]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        if self.is_empty() {
             None
+        } else {
+            // SAFETY: the last node of a non-empty map is always a leaf node
+            let leaf = unsafe { self.last().as_ref().classify_leaf::<K, V>() };
+            leaf.tail.last().map(|entry| (&entry.key, &entry.value))
         }
     }
 
@@ -329,6 +407,69 @@ impl<K, V> ArchivedBTreeMap<K, V> {
         }
     }
 
+    /// Gets an iterator over a sub-range of entries in the map, sorted by key.
+    ///
+    /// The iterator is lazy and descends the archived tree to find the start of the range instead
+    /// of scanning entries from the beginning of the map. Unlike
+    /// [`BTreeMap::range`](std::collections::BTreeMap::range), a range whose start lies after its
+    /// end does not panic and instead yields no entries.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering on the borrowed
+    /// form _must_ match the ordering on the key type.
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K, V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let (leaf, index) = self.position_after(range.start_bound());
+        let end = match range.end_bound() {
+            Bound::Included(k) => self.position_after(Bound::Excluded(k)),
+            Bound::Excluded(k) => self.position_after(Bound::Included(k)),
+            Bound::Unbounded => {
+                let last = self.last();
+                if self.is_empty() {
+                    (last, 0)
+                } else {
+                    (last, unsafe { last.as_ref().len() })
+                }
+            }
+        };
+
+        let mut inner = RawRange::<K, V>::new(leaf, index, end);
+        // If the first entry of the range is past the end bound, the range is empty
+        if let Some((first, _)) = inner.peek() {
+            let in_range = match range.end_bound() {
+                Bound::Included(k) => first.borrow() <= k,
+                Bound::Excluded(k) => first.borrow() < k,
+                Bound::Unbounded => true,
+            };
+            if !in_range {
+                inner = RawRange::new(end.0, end.1, end);
+            }
+        }
+
+        Range { inner }
+    }
+
+    /// Returns the number of entries in a sub-range of the map.
+    ///
+    /// This returns the same value as counting the entries of [`range`](ArchivedBTreeMap::range),
+    /// but it doesn't visit each entry. The tree is descended to find both ends of the range, and
+    /// then the entries between them are counted a whole leaf node at a time.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering on the borrowed
+    /// form _must_ match the ordering on the key type.
+    #[inline]
+    pub fn count_range<Q, R>(&self, range: R) -> usize
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        self.range(range).inner.len()
+    }
+
     /// Returns the number of items in the archived B-tree map.
     #[inline]
     pub fn len(&self) -> usize {
@@ -736,3 +877,101 @@ impl<'a, K, V> Iterator for Values<'a, K, V> {
 
 impl<'a, K, V> ExactSizeIterator for Values<'a, K, V> {}
 impl<'a, K, V> FusedIterator for Values<'a, K, V> {}
+
+// RawRange
+
+/// Moves a position past the end of a leaf node to the start of the next leaf node, so that
+/// every position has exactly one representation.
+#[inline]
+fn normalize_position(leaf: NonNull<NodeHeader>, index: usize) -> (NonNull<NodeHeader>, usize) {
+    // SAFETY: leaf always points to a leaf node header
+    let header = unsafe { leaf.as_ref() };
+    // The last leaf node's pointer is null
+    if index == header.len() && !header.ptr.is_null() {
+        // SAFETY: a non-null leaf pointer always points to the next leaf node
+        let next = unsafe { NonNull::new_unchecked(header.ptr.as_ptr() as *mut _) };
+        (next, 0)
+    } else {
+        (leaf, index)
+    }
+}
+
+struct RawRange<'a, K, V> {
+    leaf: NonNull<NodeHeader>,
+    index: usize,
+    end: (NonNull<NodeHeader>, usize),
+    _phantom: PhantomData<(&'a K, &'a V)>,
+}
+
+impl<'a, K, V> RawRange<'a, K, V> {
+    fn new(leaf: NonNull<NodeHeader>, index: usize, end: (NonNull<NodeHeader>, usize)) -> Self {
+        Self {
+            leaf,
+            index,
+            end,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Counts the remaining entries in the range without visiting each of them.
+    #[inline]
+    fn len(&self) -> usize {
+        let (mut leaf, mut index) = (self.leaf, self.index);
+        let mut count = 0;
+        while leaf != self.end.0 {
+            // SAFETY: leaf always points to a leaf node header, and every leaf node before the
+            // end of the range links to the next leaf node
+            let header = unsafe { leaf.as_ref() };
+            count += header.len() - index;
+            leaf = unsafe { NonNull::new_unchecked(header.ptr.as_ptr() as *mut _) };
+            index = 0;
+        }
+        count + self.end.1 - index
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<(&'a K, &'a V)> {
+        if (self.leaf, self.index) == self.end {
+            None
+        } else {
+            // SAFETY: self.leaf always points to a leaf node header and self.index is in bounds
+            // when the range is not exhausted
+            let leaf = unsafe { &*self.leaf.as_ptr() }.classify_leaf::<K, V>();
+            let result = &leaf.tail[self.index];
+            Some((&result.key, &result.value))
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for RawRange<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.peek()?;
+        let (leaf, index) = normalize_position(self.leaf, self.index + 1);
+        self.leaf = leaf;
+        self.index = index;
+        Some(result)
+    }
+}
+
+impl<'a, K, V> FusedIterator for RawRange<'a, K, V> {}
+
+/// An iterator over a sub-range of the key-value pairs of an archived B-tree map.
+///
+/// This is created by [`ArchivedBTreeMap::range`].
+pub struct Range<'a, K, V> {
+    inner: RawRange<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Range<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+impl<'a, K, V> FusedIterator for Range<'a, K, V> {}
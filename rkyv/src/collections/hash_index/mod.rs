@@ -55,15 +55,38 @@ impl ArchivedHashIndex {
         from_archived!(self.displace_slice()[index])
     }
 
+    /// Hashes a key with the hasher for this hash index.
+    #[inline]
+    pub fn hash<K: Hash + ?Sized>(&self, k: &K) -> u64 {
+        let mut hasher = self.hasher();
+        k.hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Returns the index where a key may be located in the hash index.
     ///
     /// The hash index does not have access to the keys used to build it, so the key at the returned
     /// index must be checked for equality.
     #[inline]
     pub fn index<K: Hash + ?Sized>(&self, k: &K) -> Option<usize> {
-        let mut hasher = self.hasher();
-        k.hash(&mut hasher);
-        let displace_index = hasher.finish() % self.len() as u64;
+        self.index_with_hash(self.hash(k), k)
+    }
+
+    /// Returns the index where a key may be located in the hash index using a precomputed hash.
+    ///
+    /// `hash` must be the result of [`hash`](ArchivedHashIndex::hash) for `k`, otherwise the
+    /// returned index may be wrong. If the key was displaced while building the hash index, it is
+    /// hashed again together with its displacement to find its final index.
+    ///
+    /// The hash index does not have access to the keys used to build it, so the key at the returned
+    /// index must be checked for equality.
+    #[inline]
+    pub fn index_with_hash<K: Hash + ?Sized>(&self, hash: u64, k: &K) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let displace_index = hash % self.len() as u64;
         let displace = self.displace(displace_index as usize);
 
         if displace == u32::MAX {
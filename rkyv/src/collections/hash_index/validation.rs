@@ -92,6 +92,10 @@ where
 
         let displace_rel_ptr =
             RelPtr::manual_check_bytes(ptr::addr_of!((*value).displace), context)?;
+        #[cfg(feature = "debug_rel_ptr")]
+        context
+            .check_rel_ptr_tag(displace_rel_ptr.raw_ptr())
+            .map_err(HashIndexError::ContextError)?;
         let displace_ptr = context
             .check_subtree_ptr::<[Archived<u32>]>(
                 displace_rel_ptr.base(),
@@ -19,7 +19,13 @@ use crate::{
     Serialize,
 };
 use core::{
-    borrow::Borrow, fmt, hash::Hash, iter::FusedIterator, marker::PhantomData, ops::Index, pin::Pin,
+    borrow::Borrow,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::Index,
+    pin::Pin,
 };
 
 /// An archived `HashMap`.
@@ -59,7 +65,26 @@ impl<K, V> ArchivedHashMap<K, V> {
         K: Borrow<Q>,
         Q: Hash + Eq,
     {
-        self.index.index(k).and_then(|i| {
+        self.find_with_hash(self.hash(k), k)
+    }
+
+    /// Hashes a key with the hasher for this hash map.
+    ///
+    /// The returned hash can be cached and passed to
+    /// [`get_with_hash`](ArchivedHashMap::get_with_hash) to avoid hashing the key again for every
+    /// lookup.
+    #[inline]
+    pub fn hash<Q: Hash + ?Sized>(&self, k: &Q) -> u64 {
+        self.index.hash(k)
+    }
+
+    #[inline]
+    fn find_with_hash<Q>(&self, hash: u64, k: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.index_with_hash(hash, k).and_then(|i| {
             let entry = unsafe { self.entry(i) };
             if entry.key.borrow() == k {
                 Some(i)
@@ -99,6 +124,9 @@ impl<K, V> ArchivedHashMap<K, V> {
     }
 
     /// Returns whether a key is present in the hash map.
+    ///
+    /// This hashes the key the same way as [`get`](ArchivedHashMap::get) and only compares it
+    /// against the key stored at the hashed index. The value is never accessed.
     #[inline]
     pub fn contains_key<Q: ?Sized>(&self, k: &Q) -> bool
     where
@@ -119,6 +147,46 @@ impl<K, V> ArchivedHashMap<K, V> {
             .map(|index| unsafe { &self.entry(index).value })
     }
 
+    /// Finds the key-value entry for a key using a precomputed hash.
+    ///
+    /// See [`get_with_hash`](ArchivedHashMap::get_with_hash) for more details.
+    #[inline]
+    pub fn get_key_value_with_hash<Q>(&self, hash: u64, k: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find_with_hash(hash, k).map(move |index| {
+            let entry = unsafe { self.entry(index) };
+            (&entry.key, &entry.value)
+        })
+    }
+
+    /// Gets the value associated with the given key using a precomputed hash.
+    ///
+    /// `hash` must be the hash of `k` produced by the hasher used to serialize the map, which is
+    /// what [`hash`](ArchivedHashMap::hash) returns. If it isn't, the key may not be found even if
+    /// it is present. A wrong value is never returned because the key is still checked for
+    /// equality.
+    ///
+    /// Keys that were alone in their bucket when the map was built are located with only the
+    /// precomputed hash. Keys that shared a bucket are displaced with a second hash that includes
+    /// the key, so looking them up hashes the key again. With the load factor used by archived hash
+    /// maps, this is true of roughly two thirds of the keys.
+    ///
+    /// Unlike the raw entry API of `hashbrown`, this takes the key instead of an equality
+    /// predicate. The second hash needs the key, so a lookup with only a hash and a predicate would
+    /// have to fall back to scanning every entry.
+    #[inline]
+    pub fn get_with_hash<Q>(&self, hash: u64, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find_with_hash(hash, k)
+            .map(|index| unsafe { &self.entry(index).value })
+    }
+
     /// Gets the mutable value associated with the given key.
     #[inline]
     pub fn get_pin<Q: ?Sized>(self: Pin<&mut Self>, k: &Q) -> Option<Pin<&mut V>>
@@ -161,6 +229,20 @@ impl<K, V> ArchivedHashMap<K, V> {
         }
     }
 
+    /// Gets an iterator over the key-value entries in the hash map along with the hash of each
+    /// key.
+    ///
+    /// Archived hash maps do not store the hashes of their keys, so each hash is computed as the
+    /// entries are iterated. The hashes are computed with the map's [`hasher`](Self::hasher),
+    /// which is the same hasher that was used to build the map when it was serialized.
+    #[inline]
+    pub fn iter_with_hashes(&self) -> IterWithHashes<'_, K, V> {
+        IterWithHashes {
+            inner: self.raw_iter(),
+            index: &self.index,
+        }
+    }
+
     /// Gets an iterator over the mutable key-value entries in the hash map.
     #[inline]
     pub fn iter_pin(self: Pin<&mut Self>) -> IterPin<K, V> {
@@ -411,6 +493,34 @@ impl<'a, K, V> Iterator for Iter<'a, K, V> {
 impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
 impl<K, V> FusedIterator for Iter<'_, K, V> {}
 
+/// An iterator over the key-value pairs of a hash map and the hashes of their keys.
+pub struct IterWithHashes<'a, K, V> {
+    inner: RawIter<'a, K, V>,
+    index: &'a ArchivedHashIndex,
+}
+
+impl<'a, K: Hash, V> Iterator for IterWithHashes<'a, K, V> {
+    type Item = (u64, &'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|x| unsafe {
+            let pair = &*x;
+            let mut hasher = self.index.hasher();
+            pair.key.hash(&mut hasher);
+            (hasher.finish(), &pair.key, &pair.value)
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K: Hash, V> ExactSizeIterator for IterWithHashes<'_, K, V> {}
+impl<K: Hash, V> FusedIterator for IterWithHashes<'_, K, V> {}
+
 /// An iterator over the mutable key-value pairs of a hash map.
 #[repr(transparent)]
 pub struct IterPin<'a, K, V> {
@@ -124,6 +124,10 @@ where
         Layout::array::<Entry<K, V>>(index.len())?;
 
         let entries_rel_ptr = RelPtr::manual_check_bytes(ptr::addr_of!((*value).entries), context)?;
+        #[cfg(feature = "debug_rel_ptr")]
+        context
+            .check_rel_ptr_tag(entries_rel_ptr.raw_ptr())
+            .map_err(HashMapError::ContextError)?;
         let entries_ptr = context
             .check_subtree_ptr::<[Entry<K, V>]>(
                 entries_rel_ptr.base(),
@@ -12,7 +12,8 @@ use crate::{
 use core::{borrow::Borrow, fmt, hash::Hash};
 
 /// An archived `HashSet`. This is a wrapper around a hash map with the same key and a value of
-/// `()`.
+/// `()`. Because `()` is zero-sized, the entries of the hash map only take up the space of their
+/// keys.
 #[cfg_attr(feature = "validation", derive(bytecheck::CheckBytes))]
 #[repr(transparent)]
 pub struct ArchivedHashSet<K>(ArchivedHashMap<K, ()>);
@@ -44,6 +45,28 @@ impl<K> ArchivedHashSet<K> {
         self.0.contains_key(k)
     }
 
+    /// Returns whether all of the keys in the hash set are also in `other`.
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool
+    where
+        K: Hash + Eq,
+    {
+        self.len() <= other.len() && self.iter().all(|key| other.contains(key))
+    }
+
+    /// Returns whether the hash set has no keys in common with `other`.
+    #[inline]
+    pub fn is_disjoint(&self, other: &Self) -> bool
+    where
+        K: Hash + Eq,
+    {
+        if self.len() <= other.len() {
+            self.iter().all(|key| !other.contains(key))
+        } else {
+            other.iter().all(|key| !self.contains(key))
+        }
+    }
+
     /// Gets the hasher for the underlying hash map.
     #[cfg(feature = "alloc")]
     #[inline]
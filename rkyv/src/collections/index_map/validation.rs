@@ -139,6 +139,10 @@ where
         // Entries
         Layout::array::<Entry<K, V>>(index.len())?;
         let entries_rel_ptr = RelPtr::manual_check_bytes(ptr::addr_of!((*value).entries), context)?;
+        #[cfg(feature = "debug_rel_ptr")]
+        context
+            .check_rel_ptr_tag(entries_rel_ptr.raw_ptr())
+            .map_err(IndexMapError::ContextError)?;
         let entries_ptr = context
             .check_subtree_ptr::<[Entry<K, V>]>(
                 entries_rel_ptr.base(),
@@ -158,6 +162,10 @@ where
         // Pivots
         Layout::array::<Archived<usize>>(index.len())?;
         let pivots_rel_ptr = RelPtr::manual_check_bytes(ptr::addr_of!((*value).pivots), context)?;
+        #[cfg(feature = "debug_rel_ptr")]
+        context
+            .check_rel_ptr_tag(pivots_rel_ptr.raw_ptr())
+            .map_err(IndexMapError::ContextError)?;
         let pivots_ptr = context
             .check_subtree_ptr::<[Archived<usize>]>(
                 pivots_rel_ptr.base(),
@@ -1,5 +1,6 @@
 //! Archived versions of standard library containers.
 
+pub mod binary_heap;
 pub mod btree_map;
 pub mod btree_set;
 pub mod hash_index;
@@ -10,6 +11,7 @@ pub mod index_map;
 pub mod index_set;
 pub mod util;
 
+pub use self::binary_heap::ArchivedBinaryHeap;
 pub use self::btree_map::ArchivedBTreeMap;
 pub use self::hash_index::ArchivedHashIndex;
 pub use self::hash_map::ArchivedHashMap;
@@ -158,7 +158,7 @@ macro_rules! impl_tuple {
     };
 }
 
-impl_tuple!(T, T, T, T, T, T, T, T, T, T, T,);
+impl_tuple!(T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,);
 
 unsafe impl<T: ArchiveCopySafe, const N: usize> ArchiveCopySafe for [T; N] {}
 
@@ -0,0 +1,157 @@
+#[cfg(feature = "alloc")]
+use crate::de::{SharedDeserializeRegistry, SharedPointer};
+use crate::Fallible;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
+use core::fmt;
+
+/// An error that can occur while deserializing with a [`BoundedDeserializer`].
+#[derive(Debug)]
+pub enum BoundedDeserializerError<E> {
+    /// The maximum depth was exceeded while deserializing nested values
+    ExceededMaximumDepth {
+        /// The maximum depth that values may be nested to
+        max_depth: usize,
+    },
+    /// The underlying deserializer returned an error
+    Inner(E),
+}
+
+impl<E: fmt::Display> fmt::Display for BoundedDeserializerError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExceededMaximumDepth { max_depth } => write!(
+                f,
+                "deserialized a value that exceeded the maximum depth of {}",
+                max_depth
+            ),
+            Self::Inner(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use std::error::Error;
+
+    impl<E: Error + 'static> Error for BoundedDeserializerError<E> {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                Self::ExceededMaximumDepth { .. } => None,
+                Self::Inner(e) => Some(e as &dyn Error),
+            }
+        }
+    }
+};
+
+/// An adapter that limits how deeply nested values may be deserialized.
+///
+/// Each value behind a pointer (for example, the contents of a `Box` or the elements of a `Vec`)
+/// is one level deeper than the value that points to it. Deserializing a value nested deeper than
+/// the maximum depth returns an error instead of overflowing the stack.
+///
+/// All other capabilities are delegated to the wrapped deserializer.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     archived_root,
+///     de::deserializers::{BoundedDeserializer, BoundedDeserializerError},
+///     Deserialize, Infallible,
+/// };
+///
+/// let value = Box::new(Box::new(Box::new(42i32)));
+/// let bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+/// let archived = unsafe { archived_root::<Box<Box<Box<i32>>>>(&bytes) };
+///
+/// let mut deserializer = BoundedDeserializer::new(Infallible, 3);
+/// let deserialized: Box<Box<Box<i32>>> = archived.deserialize(&mut deserializer).unwrap();
+/// assert_eq!(deserialized, value);
+///
+/// let mut deserializer = BoundedDeserializer::new(Infallible, 2);
+/// let result: Result<Box<Box<Box<i32>>>, _> = archived.deserialize(&mut deserializer);
+/// assert!(matches!(
+///     result,
+///     Err(BoundedDeserializerError::ExceededMaximumDepth { max_depth: 2 })
+/// ));
+/// ```
+#[derive(Debug)]
+pub struct BoundedDeserializer<D> {
+    inner: D,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl<D> BoundedDeserializer<D> {
+    /// Wraps the given deserializer and limits the depth of deserialized values to `max_depth`.
+    #[inline]
+    pub fn new(inner: D, max_depth: usize) -> Self {
+        Self {
+            inner,
+            depth: 0,
+            max_depth,
+        }
+    }
+
+    /// Returns the current depth of the deserializer.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the maximum depth of the deserializer.
+    #[inline]
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Consumes the adapter and returns the underlying deserializer.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+}
+
+impl<D: Fallible> Fallible for BoundedDeserializer<D> {
+    type Error = BoundedDeserializerError<D::Error>;
+
+    #[inline]
+    fn push_depth(&mut self) -> Result<(), Self::Error> {
+        if self.depth >= self.max_depth {
+            return Err(BoundedDeserializerError::ExceededMaximumDepth {
+                max_depth: self.max_depth,
+            });
+        }
+        self.inner
+            .push_depth()
+            .map_err(BoundedDeserializerError::Inner)?;
+        self.depth += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn pop_depth(&mut self) {
+        self.depth -= 1;
+        self.inner.pop_depth();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<D: SharedDeserializeRegistry> SharedDeserializeRegistry for BoundedDeserializer<D> {
+    #[inline]
+    fn get_shared_ptr(&mut self, ptr: *const u8) -> Option<&dyn SharedPointer> {
+        self.inner.get_shared_ptr(ptr)
+    }
+
+    #[inline]
+    fn add_shared_ptr(
+        &mut self,
+        ptr: *const u8,
+        shared: Box<dyn SharedPointer>,
+    ) -> Result<(), Self::Error> {
+        self.inner
+            .add_shared_ptr(ptr, shared)
+            .map_err(BoundedDeserializerError::Inner)
+    }
+}
@@ -2,7 +2,10 @@
 
 #[cfg(feature = "alloc")]
 mod alloc;
+mod core;
 
 #[doc(inline)]
 #[cfg(feature = "alloc")]
 pub use self::alloc::*;
+#[doc(inline)]
+pub use self::core::*;
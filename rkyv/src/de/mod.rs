@@ -6,8 +6,14 @@ pub mod deserializers;
 use crate::{ArchiveUnsized, DeserializeUnsized, Fallible};
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use ::alloc::boxed::Box;
+#[cfg(all(feature = "alloc", not(feature = "std"), has_atomics))]
+use ::alloc::sync::Arc;
 #[cfg(feature = "alloc")]
 use ::core::alloc::Layout;
+#[cfg(all(feature = "alloc", has_atomics))]
+use ::core::any::Any;
+#[cfg(all(feature = "std", has_atomics))]
+use ::std::sync::Arc;
 
 /// A deserializable shared pointer type.
 #[cfg(feature = "alloc")]
@@ -31,6 +37,29 @@ pub trait SharedDeserializeRegistry: Fallible {
         shared: Box<dyn SharedPointer>,
     ) -> Result<(), Self::Error>;
 
+    /// Pre-seeds the registry with an existing `Arc` for the archived value at `ptr`.
+    ///
+    /// When the archived `Arc` that points to `ptr` is deserialized with this registry, it will
+    /// return a clone of `arc` instead of deserializing a new value. This can be used to make values
+    /// deserialized from different archives alias the same shared allocation, for example to
+    /// intern them against a global cache.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must point to the archived value of an `Arc<T>` where `T` is the concrete type of
+    ///   `arc`. Type erasure through [`Any`] means this can't be checked when deserializing.
+    /// - `ptr` must only be deserialized as an `Arc<T>`, never as an `Rc<T>` or a `Weak`.
+    /// - The archive containing `ptr` must outlive every deserialization that uses this registry.
+    #[cfg(has_atomics)]
+    #[inline]
+    unsafe fn insert_shared(
+        &mut self,
+        ptr: *const u8,
+        arc: Arc<dyn Any>,
+    ) -> Result<(), Self::Error> {
+        self.add_shared_ptr(ptr, Box::new(arc) as Box<dyn SharedPointer>)
+    }
+
     /// Checks whether the given reference has been deserialized and either uses the existing shared
     /// pointer to it, or deserializes it and converts it to a shared pointer with `to_shared`.
     #[inline]
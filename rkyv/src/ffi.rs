@@ -165,13 +165,395 @@ pub struct CStringResolver {
     metadata_resolver: MetadataResolver<CStr>,
 }
 
+/// An archived [`OsStr`](std::ffi::OsStr).
+///
+/// The bytes of an archived OS string are a platform-specific encoding of the OS string:
+///
+/// - On Unix platforms, this is the raw bytes of the OS string.
+/// - On Windows, this is the [WTF-8](https://simonsapin.github.io/wtf-8/) encoding of the
+///   potentially ill-formed UTF-16 string. Unpaired surrogates are preserved.
+/// - On other platforms, this is the UTF-8 encoding of the OS string.
+///
+/// Archived OS strings are therefore **not portable** across platforms. OS strings that are valid
+/// UTF-8 round-trip losslessly everywhere, but other OS strings may not:
+///
+/// - On Unix platforms, the archived bytes are used as-is. A Windows path with unpaired surrogates
+///   deserializes to its raw WTF-8 bytes.
+/// - On Windows, unpaired surrogates are decoded from their WTF-8 encoding. Any other invalid
+///   sequences, like those from non-UTF-8 Unix paths, are replaced with
+///   [`U+FFFD REPLACEMENT CHARACTER`](core::char::REPLACEMENT_CHARACTER).
+/// - On other platforms, invalid sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+///
+/// Only Unix platforms can borrow an archived OS string as an [`OsStr`](std::ffi::OsStr) without
+/// allocating.
+#[cfg(feature = "os_str")]
+#[repr(transparent)]
+pub struct ArchivedOsStr([u8]);
+
+/// An archived [`OsString`](std::ffi::OsString).
+///
+/// Uses a [`RelPtr`] to an [`ArchivedOsStr`] under the hood, and has the same portability caveats.
+#[cfg(feature = "os_str")]
+#[repr(transparent)]
+pub struct ArchivedOsString(RelPtr<ArchivedOsStr>);
+
+#[cfg(feature = "os_str")]
+const _: () = {
+    use crate::{ArchivePointee, Archived};
+    use ptr_meta::Pointee;
+    use std::{
+        borrow::Cow,
+        ffi::{OsStr, OsString},
+    };
+
+    impl ArchivedOsStr {
+        /// Returns the platform-specific encoded bytes of this OS string.
+        #[inline]
+        pub fn as_encoded_bytes(&self) -> &[u8] {
+            &self.0
+        }
+
+        /// Extracts an `OsStr` slice containing the entire OS string.
+        #[cfg(unix)]
+        #[inline]
+        pub fn as_os_str(&self) -> &OsStr {
+            use std::os::unix::ffi::OsStrExt;
+
+            OsStr::from_bytes(&self.0)
+        }
+
+        /// Converts this archived OS string to a `Cow<OsStr>`.
+        ///
+        /// On Unix platforms, this borrows the archived bytes. On other platforms, the archived bytes
+        /// are borrowed if they are valid UTF-8 and decoded otherwise. See [`ArchivedOsStr`] for how
+        /// they are decoded.
+        #[inline]
+        pub fn to_os_str(&self) -> Cow<'_, OsStr> {
+            decode_os_str(&self.0)
+        }
+    }
+
+    impl Pointee for ArchivedOsStr {
+        type Metadata = usize;
+    }
+
+    impl ArchivePointee for ArchivedOsStr {
+        type ArchivedMetadata = Archived<usize>;
+
+        #[inline]
+        fn pointer_metadata(archived: &Self::ArchivedMetadata) -> <Self as Pointee>::Metadata {
+            <[u8]>::pointer_metadata(archived)
+        }
+    }
+
+    impl fmt::Debug for ArchivedOsStr {
+        #[inline]
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.to_os_str().fmt(f)
+        }
+    }
+
+    impl Eq for ArchivedOsStr {}
+
+    impl hash::Hash for ArchivedOsStr {
+        #[inline]
+        fn hash<H: hash::Hasher>(&self, state: &mut H) {
+            self.0.hash(state);
+        }
+    }
+
+    impl Ord for ArchivedOsStr {
+        #[inline]
+        fn cmp(&self, other: &Self) -> cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    impl PartialEq for ArchivedOsStr {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl PartialEq<OsStr> for ArchivedOsStr {
+        #[inline]
+        fn eq(&self, other: &OsStr) -> bool {
+            self.0 == *encode_os_str(other)
+        }
+    }
+
+    impl PartialEq<ArchivedOsStr> for OsStr {
+        #[inline]
+        fn eq(&self, other: &ArchivedOsStr) -> bool {
+            other.eq(self)
+        }
+    }
+
+    impl PartialOrd for ArchivedOsStr {
+        #[inline]
+        fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl ArchivedOsString {
+        /// Extracts an `ArchivedOsStr` slice containing the entire OS string.
+        #[inline]
+        pub fn as_archived_os_str(&self) -> &ArchivedOsStr {
+            unsafe { &*self.0.as_ptr() }
+        }
+
+        /// Resolves an archived OS string from the given OS string and parameters.
+        ///
+        /// # Safety
+        ///
+        /// - `pos` must be the position of `out` within the archive
+        /// - `resolver` must be the result of serializing an OS string
+        #[inline]
+        pub unsafe fn resolve_from_os_str(
+            os_str: &OsStr,
+            pos: usize,
+            resolver: OsStringResolver,
+            out: *mut Self,
+        ) {
+            let (fp, fo) = out_field!(out.0);
+            os_str.resolve_unsized(pos + fp, resolver.pos, resolver.metadata_resolver, fo);
+        }
+
+        /// Serializes an OS string.
+        #[inline]
+        pub fn serialize_from_os_str<S: Serializer + ?Sized>(
+            os_str: &OsStr,
+            serializer: &mut S,
+        ) -> Result<OsStringResolver, S::Error> {
+            Ok(OsStringResolver {
+                pos: os_str.serialize_unsized(serializer)?,
+                metadata_resolver: os_str.serialize_metadata(serializer)?,
+            })
+        }
+    }
+
+    impl AsRef<ArchivedOsStr> for ArchivedOsString {
+        #[inline]
+        fn as_ref(&self) -> &ArchivedOsStr {
+            self.as_archived_os_str()
+        }
+    }
+
+    impl Borrow<ArchivedOsStr> for ArchivedOsString {
+        #[inline]
+        fn borrow(&self) -> &ArchivedOsStr {
+            self.as_archived_os_str()
+        }
+    }
+
+    impl fmt::Debug for ArchivedOsString {
+        #[inline]
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.as_archived_os_str().fmt(f)
+        }
+    }
+
+    impl Deref for ArchivedOsString {
+        type Target = ArchivedOsStr;
+
+        #[inline]
+        fn deref(&self) -> &Self::Target {
+            self.as_archived_os_str()
+        }
+    }
+
+    impl Eq for ArchivedOsString {}
+
+    impl hash::Hash for ArchivedOsString {
+        #[inline]
+        fn hash<H: hash::Hasher>(&self, state: &mut H) {
+            self.as_archived_os_str().hash(state);
+        }
+    }
+
+    impl Ord for ArchivedOsString {
+        #[inline]
+        fn cmp(&self, other: &Self) -> cmp::Ordering {
+            self.as_archived_os_str().cmp(other.as_archived_os_str())
+        }
+    }
+
+    impl PartialEq for ArchivedOsString {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.as_archived_os_str() == other.as_archived_os_str()
+        }
+    }
+
+    impl PartialEq<&OsStr> for ArchivedOsString {
+        #[inline]
+        fn eq(&self, other: &&OsStr) -> bool {
+            self.as_archived_os_str() == *other
+        }
+    }
+
+    impl PartialEq<ArchivedOsString> for &OsStr {
+        #[inline]
+        fn eq(&self, other: &ArchivedOsString) -> bool {
+            other.eq(self)
+        }
+    }
+
+    impl PartialEq<OsString> for ArchivedOsString {
+        #[inline]
+        fn eq(&self, other: &OsString) -> bool {
+            self.as_archived_os_str() == other.as_os_str()
+        }
+    }
+
+    impl PartialEq<ArchivedOsString> for OsString {
+        #[inline]
+        fn eq(&self, other: &ArchivedOsString) -> bool {
+            other.eq(self)
+        }
+    }
+
+    impl PartialOrd for ArchivedOsString {
+        #[inline]
+        fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+};
+
+/// The resolver for `OsString`.
+#[cfg(feature = "os_str")]
+pub struct OsStringResolver {
+    pos: usize,
+    metadata_resolver: MetadataResolver<std::ffi::OsStr>,
+}
+
+/// Encodes an OS string into the bytes it's archived as.
+#[cfg(all(feature = "os_str", unix))]
+#[inline]
+pub(crate) fn encode_os_str(os_str: &std::ffi::OsStr) -> std::borrow::Cow<'_, [u8]> {
+    use std::os::unix::ffi::OsStrExt;
+
+    std::borrow::Cow::Borrowed(os_str.as_bytes())
+}
+
+/// Encodes an OS string into the bytes it's archived as.
+#[cfg(all(feature = "os_str", windows))]
+pub(crate) fn encode_os_str(os_str: &std::ffi::OsStr) -> std::borrow::Cow<'_, [u8]> {
+    use std::{borrow::Cow, os::windows::ffi::OsStrExt};
+
+    if let Some(s) = os_str.to_str() {
+        return Cow::Borrowed(s.as_bytes());
+    }
+
+    let mut bytes = Vec::new();
+    for c in char::decode_utf16(os_str.encode_wide()) {
+        match c {
+            Ok(c) => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            // Unpaired surrogates are encoded like any other code point in the BMP
+            Err(e) => {
+                let surrogate = e.unpaired_surrogate();
+                bytes.extend_from_slice(&[
+                    0xe0 | (surrogate >> 12) as u8,
+                    0x80 | ((surrogate >> 6) & 0x3f) as u8,
+                    0x80 | (surrogate & 0x3f) as u8,
+                ]);
+            }
+        }
+    }
+    Cow::Owned(bytes)
+}
+
+/// Encodes an OS string into the bytes it's archived as.
+#[cfg(all(feature = "os_str", not(any(unix, windows))))]
+#[inline]
+pub(crate) fn encode_os_str(os_str: &std::ffi::OsStr) -> std::borrow::Cow<'_, [u8]> {
+    use std::borrow::Cow;
+
+    match os_str.to_string_lossy() {
+        Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
+        Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+    }
+}
+
+/// Decodes the archived bytes of an OS string.
+#[cfg(all(feature = "os_str", unix))]
+#[inline]
+fn decode_os_str(bytes: &[u8]) -> std::borrow::Cow<'_, std::ffi::OsStr> {
+    use std::os::unix::ffi::OsStrExt;
+
+    std::borrow::Cow::Borrowed(std::ffi::OsStr::from_bytes(bytes))
+}
+
+/// Decodes the archived bytes of an OS string.
+#[cfg(all(feature = "os_str", windows))]
+fn decode_os_str(mut bytes: &[u8]) -> std::borrow::Cow<'_, std::ffi::OsStr> {
+    use core::str;
+    use std::{
+        borrow::Cow,
+        ffi::{OsStr, OsString},
+        os::windows::ffi::OsStringExt,
+    };
+
+    if let Ok(s) = str::from_utf8(bytes) {
+        return Cow::Borrowed(OsStr::new(s));
+    }
+
+    let mut wide = Vec::new();
+    loop {
+        match str::from_utf8(bytes) {
+            Ok(s) => {
+                wide.extend(s.encode_utf16());
+                break;
+            }
+            Err(e) => {
+                let (valid, rest) = bytes.split_at(e.valid_up_to());
+                wide.extend(unsafe { str::from_utf8_unchecked(valid) }.encode_utf16());
+                match *rest {
+                    // An unpaired surrogate, which is valid WTF-8 but not valid UTF-8
+                    [0xed, b1 @ 0xa0..=0xbf, b2 @ 0x80..=0xbf, ..] => {
+                        wide.push(0xd000 | (u16::from(b1 & 0x3f) << 6) | u16::from(b2 & 0x3f));
+                        bytes = &rest[3..];
+                    }
+                    _ => {
+                        wide.push(0xfffd);
+                        bytes = &rest[e.error_len().unwrap_or(rest.len())..];
+                    }
+                }
+            }
+        }
+    }
+    Cow::Owned(OsString::from_wide(&wide))
+}
+
+/// Decodes the archived bytes of an OS string.
+#[cfg(all(feature = "os_str", not(any(unix, windows))))]
+#[inline]
+fn decode_os_str(bytes: &[u8]) -> std::borrow::Cow<'_, std::ffi::OsStr> {
+    use std::{
+        borrow::Cow,
+        ffi::{OsStr, OsString},
+    };
+
+    match String::from_utf8_lossy(bytes) {
+        Cow::Borrowed(s) => Cow::Borrowed(OsStr::new(s)),
+        Cow::Owned(s) => Cow::Owned(OsString::from(s)),
+    }
+}
+
 #[cfg(feature = "validation")]
 const _: () = {
+    #[cfg(feature = "os_str")]
+    use crate::validation::LayoutRaw;
     use crate::validation::{
         owned::{CheckOwnedPointerError, OwnedPointerError},
         ArchiveContext,
     };
     use bytecheck::{CheckBytes, Error};
+    #[cfg(feature = "os_str")]
+    use core::alloc::Layout;
 
     impl<C: ArchiveContext + ?Sized> CheckBytes<C> for ArchivedCString
     where
@@ -201,4 +583,54 @@ const _: () = {
             Ok(&*value)
         }
     }
+
+    #[cfg(feature = "os_str")]
+    impl<C: ?Sized> CheckBytes<C> for ArchivedOsStr {
+        type Error = core::convert::Infallible;
+
+        #[inline]
+        unsafe fn check_bytes<'a>(value: *const Self, _: &mut C) -> Result<&'a Self, Self::Error> {
+            // Archived OS strings may contain any bytes
+            Ok(&*value)
+        }
+    }
+
+    #[cfg(feature = "os_str")]
+    impl LayoutRaw for ArchivedOsStr {
+        #[inline]
+        fn layout_raw(value: *const Self) -> Layout {
+            Layout::array::<u8>(ptr_meta::metadata(value)).unwrap()
+        }
+    }
+
+    #[cfg(feature = "os_str")]
+    impl<C: ArchiveContext + ?Sized> CheckBytes<C> for ArchivedOsString
+    where
+        C::Error: Error,
+    {
+        type Error = CheckOwnedPointerError<ArchivedOsStr, C>;
+
+        #[inline]
+        unsafe fn check_bytes<'a>(
+            value: *const Self,
+            context: &mut C,
+        ) -> Result<&'a Self, Self::Error> {
+            let rel_ptr = RelPtr::<ArchivedOsStr>::manual_check_bytes(value.cast(), context)
+                .map_err(OwnedPointerError::PointerCheckBytesError)?;
+            let ptr = context
+                .check_subtree_rel_ptr(rel_ptr)
+                .map_err(OwnedPointerError::ContextError)?;
+
+            let range = context
+                .push_prefix_subtree(ptr)
+                .map_err(OwnedPointerError::ContextError)?;
+            ArchivedOsStr::check_bytes(ptr, context)
+                .map_err(OwnedPointerError::ValueCheckBytesError)?;
+            context
+                .pop_prefix_range(range)
+                .map_err(OwnedPointerError::ContextError)?;
+
+            Ok(&*value)
+        }
+    }
 };
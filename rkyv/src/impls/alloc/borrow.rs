@@ -0,0 +1,108 @@
+use crate::{
+    ser::{ScratchSpace, Serializer},
+    string::{ArchivedString, StringResolver},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Deserialize, DeserializeUnsized, Fallible, Serialize, SerializeUnsized,
+};
+#[cfg(not(feature = "std"))]
+use ::alloc::borrow::Cow;
+#[cfg(feature = "std")]
+use ::std::borrow::Cow;
+
+// Cow<T>
+
+impl<'a, T: Archive + Clone> Archive for Cow<'a, T> {
+    type Archived = T::Archived;
+    type Resolver = T::Resolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        self.as_ref().resolve(pos, resolver, out);
+    }
+}
+
+impl<'a, T: Serialize<S> + Clone, S: Fallible + ?Sized> Serialize<S> for Cow<'a, T> {
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+impl<'a, T, D> Deserialize<Cow<'a, T>, D> for T::Archived
+where
+    T: Archive + Clone,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<Cow<'a, T>, D::Error> {
+        Ok(Cow::Owned(self.deserialize(deserializer)?))
+    }
+}
+
+// Cow<[T]>
+
+impl<'a, T: Archive + Clone> Archive for Cow<'a, [T]> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedVec::resolve_from_slice(self.as_ref(), pos, resolver, out);
+    }
+}
+
+impl<'a, T, S> Serialize<S> for Cow<'a, [T]>
+where
+    T: Serialize<S> + Clone,
+    S: ScratchSpace + Serializer + ?Sized,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self.as_ref(), serializer)
+    }
+}
+
+impl<'a, T, D> Deserialize<Cow<'a, [T]>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive + Clone,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+    D: Fallible + ?Sized,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<Cow<'a, [T]>, D::Error> {
+        Ok(Cow::Owned(self.deserialize(deserializer)?))
+    }
+}
+
+// Cow<str>
+
+impl<'a> Archive for Cow<'a, str> {
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedString::resolve_from_str(self.as_ref(), pos, resolver, out);
+    }
+}
+
+impl<'a, S: Fallible + ?Sized> Serialize<S> for Cow<'a, str>
+where
+    str: SerializeUnsized<S>,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(self.as_ref(), serializer)
+    }
+}
+
+impl<'a, D: Fallible + ?Sized> Deserialize<Cow<'a, str>, D> for ArchivedString
+where
+    str: DeserializeUnsized<str, D>,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<Cow<'a, str>, D::Error> {
+        Ok(Cow::Owned(self.deserialize(deserializer)?))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    collections::binary_heap::{ArchivedBinaryHeap, BinaryHeapResolver},
+    ser::{ScratchSpace, Serializer},
+    Archive, Deserialize, Fallible, Serialize,
+};
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BinaryHeap, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BinaryHeap;
+
+impl<T: Archive + Ord> Archive for BinaryHeap<T> {
+    type Archived = ArchivedBinaryHeap<T::Archived>;
+    type Resolver = BinaryHeapResolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedBinaryHeap::resolve_from_len(self.len(), pos, resolver, out);
+    }
+}
+
+impl<T, S> Serialize<S> for BinaryHeap<T>
+where
+    T: Serialize<S> + Ord,
+    S: ScratchSpace + Serializer + ?Sized,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        // `iter` visits the items in the order of the heap's backing vector, which is heap order
+        unsafe { ArchivedBinaryHeap::serialize_from_iter::<T, _, _, _>(self.iter(), serializer) }
+    }
+}
+
+impl<T, D> Deserialize<BinaryHeap<T>, D> for ArchivedBinaryHeap<T::Archived>
+where
+    T: Archive + Ord,
+    T::Archived: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<BinaryHeap<T>, D::Error> {
+        let mut result = Vec::with_capacity(self.len());
+        for item in self.iter() {
+            result.push(item.deserialize(deserializer)?);
+        }
+        // The items are already in heap order, so rebuilding the heap doesn't move any of them
+        Ok(BinaryHeap::from(result))
+    }
+}
+
+impl<T, AT: PartialEq<T>> PartialEq<BinaryHeap<T>> for ArchivedBinaryHeap<AT> {
+    #[inline]
+    fn eq(&self, other: &BinaryHeap<T>) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a.eq(b))
+    }
+}
+
+impl<T, AT: PartialEq<T>> PartialEq<ArchivedBinaryHeap<AT>> for BinaryHeap<T> {
+    #[inline]
+    fn eq(&self, other: &ArchivedBinaryHeap<AT>) -> bool {
+        other.eq(self)
+    }
+}
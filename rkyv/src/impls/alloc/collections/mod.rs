@@ -1,2 +1,3 @@
+mod binary_heap;
 mod btree_map;
 mod btree_set;
@@ -44,7 +44,11 @@ where
 {
     #[inline]
     fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
-        ArchivedRc::<T::Archived, RcFlavor>::serialize_from_ref(self.as_ref(), serializer)
+        ArchivedRc::<T::Archived, RcFlavor>::serialize_from_ref_with_strong_count(
+            self.as_ref(),
+            rc::Rc::strong_count(self),
+            serializer,
+        )
     }
 }
 
@@ -100,8 +104,11 @@ where
 {
     #[inline]
     fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
-        ArchivedRcWeak::<T::Archived, RcFlavor>::serialize_from_ref(
+        // Get the strong count first so it doesn't include the upgraded pointer
+        let strong_count = self.strong_count();
+        ArchivedRcWeak::<T::Archived, RcFlavor>::serialize_from_ref_with_strong_count(
             self.upgrade().as_ref().map(|v| v.as_ref()),
+            strong_count,
             serializer,
         )
     }
@@ -157,7 +164,11 @@ where
 {
     #[inline]
     fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
-        ArchivedRc::<T::Archived, ArcFlavor>::serialize_from_ref(self.as_ref(), serializer)
+        ArchivedRc::<T::Archived, ArcFlavor>::serialize_from_ref_with_strong_count(
+            self.as_ref(),
+            sync::Arc::strong_count(self),
+            serializer,
+        )
     }
 }
 
@@ -218,8 +229,11 @@ where
 {
     #[inline]
     fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
-        ArchivedRcWeak::<T::Archived, ArcFlavor>::serialize_from_ref(
+        // Get the strong count first so it doesn't include the upgraded pointer
+        let strong_count = self.strong_count();
+        ArchivedRcWeak::<T::Archived, ArcFlavor>::serialize_from_ref_with_strong_count(
             self.upgrade().as_ref().map(|v| v.as_ref()),
+            strong_count,
             serializer,
         )
     }
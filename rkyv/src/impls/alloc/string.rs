@@ -1,9 +1,11 @@
 use crate::{
     string::{ArchivedString, StringResolver},
-    Archive, Deserialize, DeserializeUnsized, Fallible, Serialize, SerializeUnsized,
+    Archive, Deserialize, DeserializeInto, DeserializeUnsized, Fallible, Serialize,
+    SerializeUnsized,
 };
 #[cfg(not(feature = "std"))]
 use ::alloc::string::{String, ToString};
+use core::cmp;
 
 impl Archive for String {
     type Archived = ArchivedString;
@@ -35,6 +37,25 @@ where
     }
 }
 
+impl<D: Fallible + ?Sized> DeserializeInto<String, D> for ArchivedString
+where
+    str: DeserializeUnsized<str, D>,
+{
+    #[inline]
+    fn deserialize_into(&self, target: &mut String, _: &mut D) -> Result<(), D::Error> {
+        target.clear();
+        target.push_str(self.as_str());
+        Ok(())
+    }
+}
+
+impl From<&ArchivedString> for String {
+    #[inline]
+    fn from(value: &ArchivedString) -> Self {
+        value.as_str().to_string()
+    }
+}
+
 impl PartialEq<String> for ArchivedString {
     #[inline]
     fn eq(&self, other: &String) -> bool {
@@ -48,3 +69,17 @@ impl PartialEq<ArchivedString> for String {
         PartialEq::eq(other.as_str(), self.as_str())
     }
 }
+
+impl PartialOrd<String> for ArchivedString {
+    #[inline]
+    fn partial_cmp(&self, other: &String) -> Option<cmp::Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl PartialOrd<ArchivedString> for String {
+    #[inline]
+    fn partial_cmp(&self, other: &ArchivedString) -> Option<cmp::Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     ser::{ScratchSpace, Serializer},
     vec::{ArchivedVec, VecResolver},
-    Archive, Deserialize, DeserializeUnsized, Fallible, Serialize,
+    Archive, Deserialize, DeserializeInto, DeserializeUnsized, Fallible, Infallible, Serialize,
 };
 #[cfg(not(feature = "std"))]
 use ::alloc::{alloc, boxed::Box, vec::Vec};
@@ -37,6 +37,23 @@ impl<T: PartialOrd> PartialOrd<ArchivedVec<T>> for Vec<T> {
     }
 }
 
+/// Converts an archived vec to a `Vec` by deserializing each element.
+///
+/// This is available for elements that can be deserialized without any deserializer capabilities,
+/// like integers, strings, and nested vecs of those.
+impl<T: Archive> From<&ArchivedVec<T::Archived>> for Vec<T>
+where
+    T::Archived: Deserialize<T, Infallible>,
+{
+    #[inline]
+    fn from(value: &ArchivedVec<T::Archived>) -> Self {
+        value
+            .iter()
+            .map(|element| element.deserialize(&mut Infallible).unwrap())
+            .collect()
+    }
+}
+
 impl<T: Archive> Archive for Vec<T> {
     type Archived = ArchivedVec<T::Archived>;
     type Resolver = VecResolver;
@@ -70,3 +87,13 @@ where
         }
     }
 }
+
+impl<T: Archive, D: Fallible + ?Sized> DeserializeInto<Vec<T>, D> for ArchivedVec<T::Archived>
+where
+    T::Archived: Deserialize<T, D>,
+{
+    #[inline]
+    fn deserialize_into(&self, target: &mut Vec<T>, deserializer: &mut D) -> Result<(), D::Error> {
+        self.deserialize_in_place(target, deserializer)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    ser::{ScratchSpace, Serializer},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Archived, Deserialize, Fallible, Serialize,
+};
+use arrayvec::{ArrayVec, CapacityError};
+
+impl<T: Archive, const CAP: usize> Archive for ArrayVec<T, CAP> {
+    type Archived = ArchivedVec<Archived<T>>;
+    type Resolver = VecResolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedVec::resolve_from_slice(self.as_slice(), pos, resolver, out);
+    }
+}
+
+impl<T, S, const CAP: usize> Serialize<S> for ArrayVec<T, CAP>
+where
+    T: Serialize<S>,
+    S: ScratchSpace + Serializer + ?Sized,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::serialize_from_slice(self.as_slice(), serializer)
+    }
+}
+
+// Any archived vec can be deserialized into an `ArrayVec`, so it may have more than `CAP` elements.
+// Deserializing it fails with a `CapacityError` instead of panicking like `ArrayVec::push`.
+impl<T, D, const CAP: usize> Deserialize<ArrayVec<T, CAP>, D> for ArchivedVec<Archived<T>>
+where
+    T: Archive,
+    Archived<T>: Deserialize<T, D>,
+    D: Fallible + ?Sized,
+    D::Error: From<CapacityError>,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<ArrayVec<T, CAP>, D::Error> {
+        if self.len() > CAP {
+            return Err(CapacityError::new(()).into());
+        }
+        let mut result = ArrayVec::new();
+        for item in self.as_slice() {
+            result.push(item.deserialize(deserializer)?);
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{archived_root, ser::Serializer, Deserialize, Fallible};
+    use arrayvec::{ArrayVec, CapacityError};
+
+    struct CapacityDeserializer;
+
+    impl Fallible for CapacityDeserializer {
+        type Error = CapacityError;
+    }
+
+    #[test]
+    fn array_vec() {
+        use crate::ser::serializers::CoreSerializer;
+
+        let value = [10i32, 20, 40, 80]
+            .iter()
+            .copied()
+            .collect::<ArrayVec<i32, 10>>();
+
+        let mut serializer = CoreSerializer::<256, 256>::default();
+        serializer.serialize_value(&value).unwrap();
+        let end = serializer.pos();
+        let result = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<ArrayVec<i32, 10>>(&result[0..end]) };
+        assert_eq!(archived.as_slice(), &[10, 20, 40, 80]);
+
+        let deserialized: ArrayVec<i32, 10> =
+            archived.deserialize(&mut CapacityDeserializer).unwrap();
+        assert_eq!(value, deserialized);
+
+        let result: Result<ArrayVec<i32, 3>, _> = archived.deserialize(&mut CapacityDeserializer);
+        assert!(result.is_err());
+    }
+}
@@ -2,8 +2,10 @@
 use crate::copy::ArchiveCopyOptimize;
 use crate::{
     ser::{ScratchSpace, Serializer},
+    string::{ArchivedString, StringResolver},
+    vec::{ArchivedVec, VecResolver},
     Archive, ArchivePointee, ArchiveUnsized, Archived, ArchivedMetadata, Deserialize,
-    DeserializeUnsized, Fallible, FixedUsize, Serialize, SerializeUnsized,
+    DeserializeUnsized, Fallible, FixedUsize, RelPtr, Serialize, SerializeUnsized,
 };
 use core::{alloc::Layout, ptr, str};
 use ptr_meta::Pointee;
@@ -58,7 +60,10 @@ where
         deserializer: &mut D,
         mut alloc: impl FnMut(Layout) -> *mut u8,
     ) -> Result<*mut (), D::Error> {
-        let deserialized = self.deserialize(deserializer)?;
+        deserializer.push_depth()?;
+        let result = self.deserialize(deserializer);
+        deserializer.pop_depth();
+        let deserialized = result?;
 
         let layout = Layout::new::<T>();
         if layout.size() == 0 {
@@ -122,8 +127,15 @@ macro_rules! impl_tuple {
     };
 }
 
+// Archived tuples are plain tuples, so the standard library only implements traits like `Debug`,
+// `PartialEq`, and `Hash` for archived tuples with up to 12 elements. Likewise, bytecheck only
+// implements `CheckBytes` for tuples with up to 12 elements, so larger tuples can be archived but
+// not validated.
 #[cfg(not(feature = "strict"))]
-impl_tuple! { T11 11, T10 10, T9 9, T8 8, T7 7, T6 6, T5 5, T4 4, T3 3, T2 2, T1 1, T0 0, }
+impl_tuple! {
+    T15 15, T14 14, T13 13, T12 12, T11 11, T10 10, T9 9, T8 8,
+    T7 7, T6 6, T5 5, T4 4, T3 3, T2 2, T1 1, T0 0,
+}
 
 impl<T: Archive, const N: usize> Archive for [T; N] {
     type Archived = [T::Archived; N];
@@ -189,6 +201,22 @@ impl<T: Archive> ArchiveUnsized for [T] {
     ) {
         out.write(to_archived!(ptr_meta::metadata(self) as FixedUsize));
     }
+
+    #[inline]
+    unsafe fn resolve_unsized(
+        &self,
+        from: usize,
+        to: usize,
+        resolver: Self::MetadataResolver,
+        out: *mut RelPtr<Self::Archived>,
+    ) {
+        debug_assert_eq!(
+            to % core::mem::align_of::<T::Archived>(),
+            0,
+            "the target position of a relative pointer is not aligned for the archived type"
+        );
+        RelPtr::resolve_emplace(from, to, self, resolver, out);
+    }
 }
 
 impl<T> ArchivePointee for [T] {
@@ -263,11 +291,19 @@ impl<T: Deserialize<U, D>, U, D: Fallible + ?Sized> DeserializeUnsized<[U], D> f
             if self.is_empty() || core::mem::size_of::<U>() == 0 {
                 Ok(ptr::NonNull::<U>::dangling().as_ptr().cast())
             } else {
+                deserializer.push_depth()?;
                 let result = alloc(Layout::array::<U>(self.len()).unwrap()).cast::<U>();
                 assert!(!result.is_null());
                 for (i, item) in self.iter().enumerate() {
-                    result.add(i).write(item.deserialize(deserializer)?);
+                    match item.deserialize(deserializer) {
+                        Ok(value) => result.add(i).write(value),
+                        Err(e) => {
+                            deserializer.pop_depth();
+                            return Err(e);
+                        }
+                    }
                 }
+                deserializer.pop_depth();
                 Ok(result.cast())
             }
         }
@@ -309,6 +345,28 @@ where
     }
 }
 
+// `&[T]`
+
+impl<T: Archive> Archive for &[T] {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedVec::resolve_from_slice(self, pos, resolver, out);
+    }
+}
+
+impl<T: Serialize<S>, S: Serializer + ?Sized> Serialize<S> for &[T]
+where
+    [T]: SerializeUnsized<S>,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self, serializer)
+    }
+}
+
 /// `str`
 
 impl ArchiveUnsized for str {
@@ -339,9 +397,7 @@ impl ArchivePointee for str {
 impl<S: Serializer + ?Sized> SerializeUnsized<S> for str {
     #[inline]
     fn serialize_unsized(&self, serializer: &mut S) -> Result<usize, S::Error> {
-        let result = serializer.pos();
-        serializer.write(self.as_bytes())?;
-        Ok(result)
+        serializer.serialize_str(self)
     }
 
     #[inline]
@@ -372,3 +428,25 @@ impl<D: Fallible + ?Sized> DeserializeUnsized<str, D> for <str as ArchiveUnsized
         Ok(ptr_meta::metadata(self))
     }
 }
+
+// `&str`
+
+impl Archive for &str {
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedString::resolve_from_str(self, pos, resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for &str
+where
+    str: SerializeUnsized<S>,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(self, serializer)
+    }
+}
@@ -21,13 +21,13 @@ impl<S: Fallible + ?Sized> Serialize<S> for Duration {
 impl<D: Fallible + ?Sized> Deserialize<Duration, D> for ArchivedDuration {
     #[inline]
     fn deserialize(&self, _: &mut D) -> Result<Duration, D::Error> {
-        Ok(Duration::new(self.as_secs(), self.subsec_nanos()))
+        Ok(self.to_duration())
     }
 }
 
 impl From<ArchivedDuration> for Duration {
     #[inline]
     fn from(duration: ArchivedDuration) -> Self {
-        Self::new(duration.as_secs(), duration.subsec_nanos())
+        duration.to_duration()
     }
 }
@@ -58,9 +58,11 @@ where
     S: BuildHasher,
 {
     fn eq(&self, other: &IndexMap<UK, UV, S>) -> bool {
-        self.iter()
-            .zip(other.iter())
-            .all(|((ak, av), (bk, bv))| ak == bk && av == bv)
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|((ak, av), (bk, bv))| ak == bk && av == bv)
     }
 }
 
@@ -98,6 +100,35 @@ mod tests {
         assert_eq!(value, deserialized);
     }
 
+    #[test]
+    fn index_map_preserves_order() {
+        let mut value = IndexMap::new();
+        for i in (0..100).rev() {
+            value.insert(i.to_string(), i);
+        }
+        value.swap_remove("50");
+
+        let mut serializer = AllocSerializer::<4096>::default();
+        serializer.serialize_value(&value).unwrap();
+        let result = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<IndexMap<String, i32>>(result.as_ref()) };
+
+        assert_eq!(value.len(), archived.len());
+        for (i, ((k, v), (ak, av))) in value.iter().zip(archived.iter()).enumerate() {
+            assert_eq!(k, ak);
+            assert_eq!(v, av);
+            assert_eq!(archived.get_index(i), Some((ak, av)));
+            assert_eq!(archived.get_index_of(k.as_str()), Some(i));
+            assert_eq!(archived.get(k.as_str()), Some(av));
+        }
+        assert!(archived.get_index(value.len()).is_none());
+        assert!(archived.get("50").is_none());
+        assert!(archived == &value);
+
+        let deserialized: IndexMap<String, i32> = archived.deserialize(&mut Infallible).unwrap();
+        assert!(value.iter().eq(deserialized.iter()));
+    }
+
     #[cfg(feature = "validation")]
     #[test]
     fn validate_index_map() {
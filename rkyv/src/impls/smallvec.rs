@@ -64,4 +64,25 @@ mod tests {
         let deserialized: SmallVec<[i32; 10]> = archived.deserialize(&mut Infallible).unwrap();
         assert_eq!(value, deserialized);
     }
+
+    #[test]
+    fn small_vec_inline_and_spilled() {
+        use crate::ser::serializers::CoreSerializer;
+
+        for len in [2u8, 8] {
+            let value = (0..len).collect::<SmallVec<[u8; 4]>>();
+            assert_eq!(value.spilled(), len > 4);
+
+            let mut serializer = CoreSerializer::<256, 256>::default();
+            serializer.serialize_value(&value).unwrap();
+            let end = serializer.pos();
+            let result = serializer.into_serializer().into_inner();
+            let archived = unsafe { archived_root::<SmallVec<[u8; 4]>>(&result[0..end]) };
+            assert_eq!(archived.as_slice(), value.as_slice());
+
+            let deserialized: SmallVec<[u8; 4]> = archived.deserialize(&mut Infallible).unwrap();
+            assert_eq!(deserialized, value);
+            assert_eq!(deserialized.spilled(), len > 4);
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
     collections::hash_map::{ArchivedHashMap, HashMapResolver},
     ser::{ScratchSpace, Serializer},
-    Archive, Deserialize, Fallible, Serialize,
+    Archive, Deserialize, DeserializeInto, Fallible, Serialize,
 };
 use core::{
     borrow::Borrow,
@@ -51,6 +51,27 @@ where
     }
 }
 
+impl<K: Archive + Hash + Eq, V: Archive, D: Fallible + ?Sized, S: BuildHasher>
+    DeserializeInto<HashMap<K, V, S>, D> for ArchivedHashMap<K::Archived, V::Archived>
+where
+    K::Archived: Deserialize<K, D> + Hash + Eq,
+    V::Archived: Deserialize<V, D>,
+{
+    #[inline]
+    fn deserialize_into(
+        &self,
+        target: &mut HashMap<K, V, S>,
+        deserializer: &mut D,
+    ) -> Result<(), D::Error> {
+        target.clear();
+        target.reserve(self.len());
+        for (k, v) in self.iter() {
+            target.insert(k.deserialize(deserializer)?, v.deserialize(deserializer)?);
+        }
+        Ok(())
+    }
+}
+
 impl<K: Hash + Eq + Borrow<AK>, V, AK: Hash + Eq, AV: PartialEq<V>, S: BuildHasher>
     PartialEq<HashMap<K, V, S>> for ArchivedHashMap<AK, AV>
 {
@@ -51,6 +51,56 @@ where
     }
 }
 
+impl<AK: Hash + Eq> ArchivedHashSet<AK> {
+    /// Returns whether all of the keys in the archived hash set are also in the given `HashSet`.
+    ///
+    /// The keys are compared through their common borrowed form `Q`, like `str` for an archived
+    /// set of `String`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rkyv::archived_root;
+    /// use std::collections::HashSet;
+    ///
+    /// let value = ["a", "b"].iter().map(|s| s.to_string()).collect::<HashSet<_>>();
+    /// let bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+    /// let archived = unsafe { archived_root::<HashSet<String>>(&bytes) };
+    ///
+    /// let other = ["a", "b", "c"].iter().map(|s| s.to_string()).collect::<HashSet<_>>();
+    /// assert!(archived.is_subset_of_hash_set::<str, _, _>(&other));
+    /// ```
+    #[inline]
+    pub fn is_subset_of_hash_set<Q, K, S>(&self, other: &HashSet<K, S>) -> bool
+    where
+        Q: Hash + Eq + ?Sized,
+        AK: Borrow<Q>,
+        K: Hash + Eq + Borrow<Q>,
+        S: BuildHasher,
+    {
+        self.len() <= other.len() && self.iter().all(|key| other.contains(key.borrow()))
+    }
+
+    /// Returns whether the archived hash set has no keys in common with the given `HashSet`.
+    ///
+    /// The keys are compared through their common borrowed form `Q`, like `str` for an archived
+    /// set of `String`s.
+    #[inline]
+    pub fn is_disjoint_from_hash_set<Q, K, S>(&self, other: &HashSet<K, S>) -> bool
+    where
+        Q: Hash + Eq + ?Sized,
+        AK: Borrow<Q>,
+        K: Hash + Eq + Borrow<Q>,
+        S: BuildHasher,
+    {
+        if self.len() <= other.len() {
+            self.iter().all(|key| !other.contains(key.borrow()))
+        } else {
+            other.iter().all(|key| !self.contains(key.borrow()))
+        }
+    }
+}
+
 impl<K: Hash + Eq + Borrow<AK>, AK: Hash + Eq, S: BuildHasher> PartialEq<HashSet<K, S>>
     for ArchivedHashSet<AK>
 {
@@ -7,7 +7,10 @@ use crate::{
 use core::{alloc::Layout, ptr};
 use ptr_meta::Pointee;
 use std::alloc;
-use std::ffi::{CStr, CString};
+use std::{
+    borrow::Cow,
+    ffi::{CStr, CString},
+};
 
 // CStr
 
@@ -119,3 +122,95 @@ where
         }
     }
 }
+
+// Cow<CStr>
+
+impl<'a> Archive for Cow<'a, CStr> {
+    type Archived = ArchivedCString;
+    type Resolver = CStringResolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedCString::resolve_from_c_str(self.as_ref(), pos, resolver, out);
+    }
+}
+
+impl<'a, S: Serializer + ?Sized> Serialize<S> for Cow<'a, CStr> {
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedCString::serialize_from_c_str(self.as_ref(), serializer)
+    }
+}
+
+impl<'a, D: Fallible + ?Sized> Deserialize<Cow<'a, CStr>, D> for ArchivedCString
+where
+    CStr: DeserializeUnsized<CStr, D>,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<Cow<'a, CStr>, D::Error> {
+        Ok(Cow::Owned(self.deserialize(deserializer)?))
+    }
+}
+
+// OsString
+
+#[cfg(feature = "os_str")]
+const _: () = {
+    use crate::ffi::{encode_os_str, ArchivedOsStr, ArchivedOsString, OsStringResolver};
+    use std::ffi::{OsStr, OsString};
+
+    impl ArchiveUnsized for OsStr {
+        type Archived = ArchivedOsStr;
+
+        // The length of the encoded OS string, which may differ from the length of the `OsStr`
+        type MetadataResolver = usize;
+
+        #[inline]
+        unsafe fn resolve_metadata(
+            &self,
+            _: usize,
+            resolver: Self::MetadataResolver,
+            out: *mut ArchivedMetadata<Self>,
+        ) {
+            out.write(to_archived!(resolver as FixedUsize))
+        }
+    }
+
+    impl<S: Serializer + ?Sized> SerializeUnsized<S> for OsStr {
+        #[inline]
+        fn serialize_unsized(&self, serializer: &mut S) -> Result<usize, S::Error> {
+            let result = serializer.pos();
+            serializer.write(&encode_os_str(self))?;
+            Ok(result)
+        }
+
+        #[inline]
+        fn serialize_metadata(&self, _: &mut S) -> Result<Self::MetadataResolver, S::Error> {
+            Ok(encode_os_str(self).len())
+        }
+    }
+
+    impl Archive for OsString {
+        type Archived = ArchivedOsString;
+        type Resolver = OsStringResolver;
+
+        #[inline]
+        unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+            ArchivedOsString::resolve_from_os_str(self.as_os_str(), pos, resolver, out);
+        }
+    }
+
+    impl<S: Serializer + ?Sized> Serialize<S> for OsString {
+        #[inline]
+        fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+            ArchivedOsString::serialize_from_os_str(self.as_os_str(), serializer)
+        }
+    }
+
+    impl<D: Fallible + ?Sized> Deserialize<OsString, D> for ArchivedOsString {
+        #[inline]
+        fn deserialize(&self, _: &mut D) -> Result<OsString, D::Error> {
+            Ok(self.to_os_str().into_owned())
+        }
+    }
+};
@@ -1,4 +1,6 @@
 mod collections;
 mod ffi;
 mod net;
+#[cfg(feature = "os_str")]
+mod path;
 mod time;
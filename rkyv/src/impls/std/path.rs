@@ -0,0 +1,44 @@
+use crate::{
+    path::{ArchivedPathBuf, PathBufResolver},
+    ser::Serializer,
+    Archive, Deserialize, Fallible, Serialize,
+};
+use std::path::PathBuf;
+
+impl PartialEq<PathBuf> for ArchivedPathBuf {
+    #[inline]
+    fn eq(&self, other: &PathBuf) -> bool {
+        self.eq(&other.as_path())
+    }
+}
+
+impl PartialEq<ArchivedPathBuf> for PathBuf {
+    #[inline]
+    fn eq(&self, other: &ArchivedPathBuf) -> bool {
+        other.eq(self)
+    }
+}
+
+impl Archive for PathBuf {
+    type Archived = ArchivedPathBuf;
+    type Resolver = PathBufResolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedPathBuf::resolve_from_path(self.as_path(), pos, resolver, out);
+    }
+}
+
+impl<S: Serializer + ?Sized> Serialize<S> for PathBuf {
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedPathBuf::serialize_from_path(self.as_path(), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<PathBuf, D> for ArchivedPathBuf {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<PathBuf, D::Error> {
+        Ok(self.to_path().into_owned())
+    }
+}
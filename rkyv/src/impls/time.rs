@@ -0,0 +1,184 @@
+use crate::{
+    time::{ArchivedDate, ArchivedOffsetDateTime, ArchivedTime},
+    Archive, Deserialize, Fallible, Serialize,
+};
+use ::time::{Date, OffsetDateTime, Time};
+
+impl Archive for Date {
+    type Archived = ArchivedDate;
+    type Resolver = ();
+
+    #[inline]
+    unsafe fn resolve(&self, _: usize, _: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedDate::emplace(self.year(), self.ordinal(), out);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Date {
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Date, D> for ArchivedDate {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<Date, D::Error> {
+        Ok(self.to_date())
+    }
+}
+
+impl Archive for Time {
+    type Archived = ArchivedTime;
+    type Resolver = ();
+
+    #[inline]
+    unsafe fn resolve(&self, _: usize, _: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedTime::emplace(crate::time::time_to_nanos(*self), out);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Time {
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Time, D> for ArchivedTime {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<Time, D::Error> {
+        Ok(self.to_time())
+    }
+}
+
+impl Archive for OffsetDateTime {
+    type Archived = ArchivedOffsetDateTime;
+    type Resolver = ();
+
+    #[inline]
+    unsafe fn resolve(&self, _: usize, _: Self::Resolver, out: *mut Self::Archived) {
+        ArchivedOffsetDateTime::emplace(self, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for OffsetDateTime {
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<OffsetDateTime, D> for ArchivedOffsetDateTime {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<OffsetDateTime, D::Error> {
+        Ok(self.to_offset_datetime())
+    }
+}
+
+#[cfg(test)]
+mod rkyv_tests {
+    use crate::{
+        archived_root,
+        ser::{serializers::AlignedSerializer, Serializer},
+        util::AlignedVec,
+        Archive, Deserialize, Infallible, Serialize,
+    };
+    use ::time::{Date, Month, OffsetDateTime, Time, UtcOffset};
+    use core::fmt::Debug;
+
+    fn round_trip<T>(value: &T)
+    where
+        T: Archive + Serialize<AlignedSerializer<AlignedVec>> + Debug + PartialEq,
+        T::Archived: Debug + PartialEq<T> + Deserialize<T, Infallible>,
+    {
+        let mut serializer = AlignedSerializer::new(AlignedVec::new());
+        serializer
+            .serialize_value(value)
+            .expect("failed to archive value");
+        let buf = serializer.into_inner();
+        let archived = unsafe { archived_root::<T>(buf.as_ref()) };
+
+        assert_eq!(archived, value);
+
+        let deserialized: T = archived
+            .deserialize(&mut Infallible)
+            .expect("failed to deserialize value");
+
+        assert_eq!(&deserialized, value);
+    }
+
+    #[test]
+    fn test_date() {
+        round_trip(&Date::from_calendar_date(2022, Month::March, 14).unwrap());
+        round_trip(&Date::from_calendar_date(1969, Month::December, 31).unwrap());
+        round_trip(&Date::from_calendar_date(2000, Month::February, 29).unwrap());
+        round_trip(&Date::from_calendar_date(-4713, Month::November, 24).unwrap());
+        round_trip(&Date::MIN);
+        round_trip(&Date::MAX);
+    }
+
+    #[test]
+    fn test_time() {
+        round_trip(&Time::MIDNIGHT);
+        round_trip(&Time::from_hms_nano(12, 34, 56, 789).unwrap());
+        // The time crate does not represent leap seconds, so this is the last instant of a day
+        round_trip(&Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap());
+    }
+
+    #[test]
+    fn test_offset_date_time() {
+        round_trip(&OffsetDateTime::UNIX_EPOCH);
+        round_trip(&OffsetDateTime::from_unix_timestamp(-14_182_940).unwrap());
+        round_trip(
+            &Date::from_calendar_date(-100, Month::July, 4)
+                .unwrap()
+                .with_hms_nano(23, 59, 59, 999_999_999)
+                .unwrap()
+                .assume_offset(UtcOffset::from_hms(-25, -59, -59).unwrap()),
+        );
+
+        let value = Date::from_calendar_date(1955, Month::November, 5)
+            .unwrap()
+            .with_hms(6, 15, 0)
+            .unwrap()
+            .assume_offset(UtcOffset::from_hms(5, 30, 0).unwrap());
+        let mut serializer = AlignedSerializer::new(AlignedVec::new());
+        serializer
+            .serialize_value(&value)
+            .expect("failed to archive value");
+        let buf = serializer.into_inner();
+        let archived = unsafe { archived_root::<OffsetDateTime>(buf.as_ref()) };
+
+        assert_eq!(archived.date().year(), 1955);
+        assert_eq!(archived.time().to_time(), value.time());
+        assert_eq!(archived.offset_seconds(), 5 * 3600 + 30 * 60);
+        assert_eq!(archived.to_offset_datetime().offset(), value.offset());
+        assert!(archived.to_offset_datetime().unix_timestamp() < 0);
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_validation() {
+        use crate::{check_archived_root, time::ArchivedDate};
+
+        let value = OffsetDateTime::from_unix_timestamp(-1).unwrap();
+        let mut serializer = AlignedSerializer::new(AlignedVec::new());
+        serializer
+            .serialize_value(&value)
+            .expect("failed to archive value");
+        let buf = serializer.into_inner();
+        check_archived_root::<OffsetDateTime>(buf.as_ref()).unwrap();
+
+        let mut date = AlignedVec::new();
+        date.extend_from_slice(&[0; core::mem::size_of::<ArchivedDate>()]);
+        unsafe {
+            ArchivedDate::emplace(2021, 366, date.as_mut_ptr().cast());
+        }
+        assert!(check_archived_root::<Date>(date.as_ref()).is_err());
+        unsafe {
+            ArchivedDate::emplace(2020, 366, date.as_mut_ptr().cast());
+        }
+        assert!(check_archived_root::<Date>(date.as_ref()).is_ok());
+    }
+}
@@ -41,7 +41,8 @@
 //! support custom contextual types.
 //!
 //! Finally, rkyv makes it possible to serialize trait objects and use them *as trait objects*
-//! without deserialization. See the `archive_dyn` crate for more details.
+//! without deserialization. See the `trait_object` module, which is enabled by the `archive_dyn`
+//! feature, for more details.
 //!
 //! ## Tradeoffs
 //!
@@ -52,6 +53,8 @@
 //!
 //! ## Features
 //!
+//! - `absolute_pointers`: Enables `AbsoluteRelPtr`, a pointer that can store an absolute address
+//!   to point outside of its archive. Archives containing absolute pointers are not relocatable.
 //! - `alloc`: Enables types that require the `alloc` crate. Enabled by default.
 //! - `arbitrary_enum_discriminant`: Enables the `arbitrary_enum_discriminant` feature for stable
 //!   multibyte enum discriminants using `archive_le` and `archive_be`. Requires nightly.
@@ -59,10 +62,29 @@
 //!   compatibility optimized for big-endian architectures.
 //! - `archive_le`: Forces archives into a little-endian format. This guarantees cross-endian
 //!   compatibility optimized for little-endian architectures.
+//! - `archive_dyn`: Enables archiving trait objects with `#[archive_dyn]` and the `AsDyn` wrapper.
+//!   Requires `std`.
 //! - `copy`: Enables copy optimizations for packed copyable data types. Requires nightly.
 //! - `copy_unsafe`: Automatically opts all potentially copyable types into copy optimization. This
 //!   broadly improves performance but may cause uninitialized bytes to be copied to the output.
 //!   Requires nightly.
+//! - `debug_rel_ptr`: Stores a tag in every relative pointer that is checked in debug builds when
+//!   the pointer is resolved. This helps catch use-after-free of archive buffers, but changes the
+//!   archive format and should only be used during development.
+//! - `debug_serialize`: Records the field being resolved by derived implementations so that offset
+//!   overflow panics name the offending field and its type. Also enables `ProfilingSerializer`,
+//!   which records the position and size of every value written to an archive. Requires `std`.
+//! - `disk_scratch`: Enables `DiskScratch`, a scratch space that spills allocations over an
+//!   in-memory cap to a memory-mapped temporary file. Requires `std`.
+//! - `hex_context`: Includes a hex dump of the bytes surrounding the position of validation errors
+//!   in their messages. Requires `validation`.
+//! - `os_str`: Enables archiving `OsStr`, `OsString` and `PathBuf` using their platform-specific
+//!   encoding. Archives containing these types are not portable across platforms. Requires `std`.
+//! - `rayon`: Enables `check_archived_root_parallel`, which validates the elements of an archived
+//!   root `Vec` across the rayon thread pool. Only available with `std` and `validation`.
+//! - `rc_strong_count`: Stores the strong count of shared pointers alongside each archived `Rc`
+//!   and `Arc` so it can be inspected with `ArchivedRc::archived_strong_count`. This changes the
+//!   archive format.
 //! - `size_16`: Archives integral `*size` types as 16-bit integers. This is intended to be used
 //!   only for small archives and may not handle large, more general data.
 //! - `size_32`: Archives integral `*size` types as 32-bit integers. Enabled by default.
@@ -77,6 +99,8 @@
 //!   do not have a C type layout. Making a generic `Tuple<T1, T2>` and deriving [`Archive`] for it
 //!   should provide similar functionality.
 //! - `validation`: Enables validation support through `bytecheck`.
+//! - `zstd`: Enables `ZstdSerializer`, which compresses archives as they are written, and
+//!   `zstd_decompress_to_aligned` to decompress them for access. Only available with `std`.
 //!
 //! ## Crate support
 //!
@@ -87,9 +111,14 @@
 //!
 //! Crates supported by rkyv:
 //!
+//! - [`arrayvec`](https://docs.rs/arrayvec)
+//! - [`bitflags`](https://docs.rs/bitflags) *Flags types are supported through the
+//!   `archive_bitflags!` macro.*
 //! - [`indexmap`](https://docs.rs/indexmap)
 //! - [`rend`](https://docs.rs/rend) *Enabled automatically when using endian-specific archive
 //!   features.*
+//! - [`smallvec`](https://docs.rs/smallvec)
+//! - [`time`](https://docs.rs/time) *`Date`, `Time`, and `OffsetDateTime` are supported.*
 //! - [`tinyvec`](https://docs.rs/tinyvec)
 //! - [`uuid`](https://docs.rs/uuid)
 //!
@@ -141,9 +170,12 @@ extern crate std;
 #[macro_use]
 pub mod macros;
 
+#[cfg(feature = "bitflags")]
+pub mod bitflags;
 #[cfg(feature = "bitvec")]
 pub mod bitvec;
 pub mod boxed;
+pub mod checksum;
 pub mod collections;
 #[cfg(feature = "copy")]
 pub mod copy;
@@ -157,12 +189,17 @@ pub mod net;
 pub mod niche;
 pub mod ops;
 pub mod option;
+#[cfg(feature = "os_str")]
+pub mod path;
 pub mod rc;
 pub mod rel_ptr;
 pub mod result;
 pub mod ser;
 pub mod string;
 pub mod time;
+#[cfg(feature = "archive_dyn")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "archive_dyn")))]
+pub mod trait_object;
 pub mod util;
 #[cfg(feature = "validation")]
 pub mod validation;
@@ -174,13 +211,26 @@ pub use rend;
 
 use core::alloc::Layout;
 use ptr_meta::Pointee;
+#[cfg(feature = "archive_dyn")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "archive_dyn")))]
+pub use rkyv_derive::archive_dyn;
 pub use rkyv_derive::{Archive, Deserialize, Serialize};
 pub use util::*;
+#[cfg(all(feature = "rayon", feature = "std", feature = "validation"))]
+#[cfg_attr(
+    doc_cfg,
+    doc(cfg(all(feature = "rayon", feature = "std", feature = "validation")))
+)]
+pub use validation::validators::check_archived_root_parallel;
 #[cfg(feature = "validation")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "validation")))]
 pub use validation::{
+    check_archived_field_with_context, check_archived_prefix_with_context,
     check_archived_root_with_context, check_archived_value_with_context,
-    validators::{check_archived_root, check_archived_value, from_bytes},
+    validators::{
+        check_archived_field, check_archived_prefix, check_archived_root,
+        check_archived_root_unaligned, check_archived_value, from_bytes,
+    },
 };
 
 /// A type that can produce an error.
@@ -194,6 +244,22 @@ pub use validation::{
 pub trait Fallible {
     /// The error produced by any failing methods.
     type Error: 'static;
+
+    /// Notifies a deserializer that it is about to deserialize a value behind a pointer.
+    ///
+    /// Every successful call is matched by a call to [`pop_depth`](Fallible::pop_depth) once the
+    /// value has been deserialized. Deserializers can return an error to stop deserializing
+    /// structures that are nested too deeply. By default, this does nothing.
+    #[inline]
+    fn push_depth(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Notifies a deserializer that it has finished deserializing a value behind a pointer.
+    ///
+    /// By default, this does nothing.
+    #[inline]
+    fn pop_depth(&mut self) {}
 }
 
 /// A fallible type that cannot produce errors.
@@ -444,6 +510,19 @@ pub trait Deserialize<T, D: Fallible + ?Sized> {
     fn deserialize(&self, deserializer: &mut D) -> Result<T, D::Error>;
 }
 
+/// Converts a type back from its archived form into an existing value.
+///
+/// This is like [`Deserialize`], but reuses the resources owned by the target value instead of
+/// creating a new one. For example, deserializing an archived `Vec` into an existing `Vec` clears it
+/// and reuses its allocation if it has enough capacity. This can reduce allocator pressure when
+/// deserializing many archives of a similar shape.
+///
+/// On error, the target is left in a valid but unspecified state.
+pub trait DeserializeInto<T, D: Fallible + ?Sized> {
+    /// Deserializes into the given target using the given deserializer
+    fn deserialize_into(&self, target: &mut T, deserializer: &mut D) -> Result<(), D::Error>;
+}
+
 /// A counterpart of [`Archive`] that's suitable for unsized types.
 ///
 /// Unlike `Archive`, types that implement `ArchiveUnsized` must be serialized separately from their
@@ -97,6 +97,248 @@ macro_rules! to_archived {
     }};
 }
 
+/// Defines an offset type that is stored in a fixed number of bytes.
+///
+/// This generates a `[u8; N]`-backed type that implements [`Offset`](crate::rel_ptr::Offset) for
+/// offsets of `N` bytes, where `N` is between 1 and 8. Signed offsets are sign-extended when
+/// they're read, and unsigned offsets can only point forward. Offsets are stored in the archive
+/// endianness, and [`between`](crate::rel_ptr::Offset::between) returns
+/// [`ExceedsStorageRange`](crate::rel_ptr::OffsetError::ExceedsStorageRange) for offsets that
+/// can't be stored in `N` bytes. With the `validation` feature, the offset type also implements
+/// `CheckBytes`.
+///
+/// A [`RawRelPtr`](crate::rel_ptr::RawRelPtr) alias that uses the offset type is defined alongside
+/// it.
+///
+/// The generated type has:
+/// - `MIN` and `MAX` constants with the range of values that can be stored, as an `i64` for signed
+///   offsets or a `u64` for unsigned offsets.
+/// - `new` and `get` methods to convert from and to those values.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::{define_offset, rel_ptr::{Offset, OffsetError}};
+///
+/// define_offset! {
+///     /// A signed 48-bit offset.
+///     pub struct I48([u8; 6], signed);
+///     /// A raw relative pointer that uses an [`I48`] as the underlying offset.
+///     pub type RawRelPtrI48;
+/// }
+///
+/// assert_eq!(I48::MIN, -(1 << 47));
+/// assert_eq!(I48::MAX, (1 << 47) - 1);
+/// assert_eq!(core::mem::size_of::<I48>(), 6);
+/// # #[cfg(not(feature = "debug_rel_ptr"))]
+/// assert_eq!(core::mem::size_of::<RawRelPtrI48>(), 6);
+///
+/// // Values round-trip at the extremes of the range
+/// for &value in [I48::MIN, I48::MIN + 1, -1, 0, 1, I48::MAX - 1, I48::MAX].iter() {
+///     assert_eq!(I48::new(value).unwrap().get(), value);
+/// }
+/// assert_eq!(I48::new(I48::MIN - 1), None);
+/// assert_eq!(I48::new(I48::MAX + 1), None);
+///
+/// # #[cfg(target_pointer_width = "64")]
+/// # {
+/// let max = I48::MAX as usize;
+/// assert_eq!(I48::between(0, max).unwrap().to_isize(), I48::MAX as isize);
+/// assert_eq!(I48::between(max + 1, 0).unwrap().to_isize(), I48::MIN as isize);
+/// assert_eq!(I48::between(0, max + 1), Err(OffsetError::ExceedsStorageRange));
+/// assert_eq!(I48::between(max + 2, 0), Err(OffsetError::ExceedsStorageRange));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! define_offset {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident([u8; $n:tt], signed);
+        $(#[$ptr_attr:meta])*
+        $ptr_vis:vis type $ptr:ident;
+    ) => {
+        $crate::__define_offset_check_size!($n);
+        $crate::define_offset! {
+            @impl $(#[$attr])* $vis $name, $n, i64;
+            $(#[$ptr_attr])* $ptr_vis $ptr
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident([u8; $n:tt], unsigned);
+        $(#[$ptr_attr:meta])*
+        $ptr_vis:vis type $ptr:ident;
+    ) => {
+        $crate::__define_offset_check_size!($n);
+        $crate::define_offset! {
+            @impl $(#[$attr])* $vis $name, $n, u64;
+            $(#[$ptr_attr])* $ptr_vis $ptr
+        }
+    };
+    (
+        @impl $(#[$attr:meta])* $vis:vis $name:ident, $n:literal, $ty:ty;
+        $(#[$ptr_attr:meta])* $ptr_vis:vis $ptr:ident
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #[repr(transparent)]
+        $vis struct $name([u8; $n]);
+
+        impl $name {
+            // The number of unused high bits when the offset is stored in 64 bits
+            const UNUSED_BITS: u32 = 64 - 8 * $n;
+
+            /// The minimum value that can be stored.
+            pub const MIN: $ty = <$ty>::MIN >> Self::UNUSED_BITS;
+            /// The maximum value that can be stored.
+            pub const MAX: $ty = <$ty>::MAX >> Self::UNUSED_BITS;
+
+            /// Creates a new value, returning `None` if it is out of range.
+            #[inline]
+            pub fn new(value: $ty) -> Option<Self> {
+                if (Self::MIN..=Self::MAX).contains(&value) {
+                    Some(Self($crate::macros::offset_to_bytes(value as u64)))
+                } else {
+                    None
+                }
+            }
+
+            /// Gets the stored value.
+            #[inline]
+            pub fn get(self) -> $ty {
+                // Shifting the value into the top of the integer and back sign-extends signed
+                // values
+                (($crate::macros::offset_from_bytes(self.0) << Self::UNUSED_BITS) as $ty)
+                    >> Self::UNUSED_BITS
+            }
+        }
+
+        impl $crate::rel_ptr::Offset for $name {
+            const MIN_OFFSET: isize = if (Self::MIN as i128) < isize::MIN as i128 {
+                isize::MIN
+            } else {
+                Self::MIN as isize
+            };
+            const MAX_OFFSET: isize = if (Self::MAX as i128) > isize::MAX as i128 {
+                isize::MAX
+            } else {
+                Self::MAX as isize
+            };
+
+            #[inline]
+            fn between(
+                from: usize,
+                to: usize,
+            ) -> ::core::result::Result<Self, $crate::rel_ptr::OffsetError> {
+                <$ty as ::core::convert::TryFrom<isize>>::try_from(
+                    $crate::rel_ptr::signed_offset(from, to)?,
+                )
+                .ok()
+                .and_then(Self::new)
+                .ok_or($crate::rel_ptr::OffsetError::ExceedsStorageRange)
+            }
+
+            #[inline]
+            fn to_isize(&self) -> isize {
+                self.get() as isize
+            }
+        }
+
+        $crate::__define_offset_check_bytes!($name);
+
+        $(#[$ptr_attr])*
+        $ptr_vis type $ptr = $crate::rel_ptr::RawRelPtr<$name>;
+    };
+}
+
+// Only matches the sizes that can be stored in a `u64`. The size is matched as a token tree because
+// forwarded literal fragments can't be matched against literal tokens.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_offset_check_size {
+    (1) => {};
+    (2) => {};
+    (3) => {};
+    (4) => {};
+    (5) => {};
+    (6) => {};
+    (7) => {};
+    (8) => {};
+    ($n:tt) => {
+        ::core::compile_error!("offsets must be between 1 and 8 bytes");
+    };
+}
+
+#[cfg(feature = "validation")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_offset_check_bytes {
+    ($name:ident) => {
+        impl<C: $crate::Fallible + ?Sized> $crate::macros::bytecheck::CheckBytes<C> for $name {
+            type Error = ::core::convert::Infallible;
+
+            #[inline]
+            unsafe fn check_bytes<'a>(
+                value: *const Self,
+                _: &mut C,
+            ) -> ::core::result::Result<&'a Self, Self::Error> {
+                // All bit patterns are valid
+                Ok(&*value)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "validation"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_offset_check_bytes {
+    ($name:ident) => {};
+}
+
+#[cfg(feature = "validation")]
+pub use bytecheck;
+
+/// Encodes the low `N` bytes of an offset in the archive endianness.
+#[inline]
+pub fn offset_to_bytes<const N: usize>(value: u64) -> [u8; N] {
+    let mut bytes = [0; N];
+    #[cfg(any(
+        feature = "archive_be",
+        all(not(feature = "archive_le"), target_endian = "big")
+    ))]
+    bytes.copy_from_slice(&value.to_be_bytes()[8 - N..]);
+    #[cfg(not(any(
+        feature = "archive_be",
+        all(not(feature = "archive_le"), target_endian = "big")
+    )))]
+    bytes.copy_from_slice(&value.to_le_bytes()[..N]);
+    bytes
+}
+
+/// Decodes an offset stored in `N` bytes in the archive endianness. The unused high bytes are
+/// zeroed.
+#[inline]
+pub fn offset_from_bytes<const N: usize>(bytes: [u8; N]) -> u64 {
+    let mut value = [0; 8];
+    #[cfg(any(
+        feature = "archive_be",
+        all(not(feature = "archive_le"), target_endian = "big")
+    ))]
+    {
+        value[8 - N..].copy_from_slice(&bytes);
+        u64::from_be_bytes(value)
+    }
+    #[cfg(not(any(
+        feature = "archive_be",
+        all(not(feature = "archive_le"), target_endian = "big")
+    )))]
+    {
+        value[..N].copy_from_slice(&bytes);
+        u64::from_le_bytes(value)
+    }
+}
+
 #[cfg(not(any(feature = "size_16", feature = "size_32", feature = "size_64")))]
 core::compile_error!(r#"one of ["size_16", "size_32", or "size_64"] features must be enabled"#);
 
@@ -12,7 +12,7 @@ use core::{
 pub struct ArchivedRange<T> {
     /// The lower bound of the range (inclusive).
     pub start: T,
-    /// The upper bound of the range (inclusive).
+    /// The upper bound of the range (exclusive).
     pub end: T,
 }
 
@@ -61,6 +61,10 @@ impl<T> RangeBounds<T> for ArchivedRange<T> {
 // RangeInclusive
 
 /// An archived [`RangeInclusive`](::core::ops::RangeInclusive).
+///
+/// Only the start and end of the range are archived. A `RangeInclusive` that has been exhausted by
+/// iteration is not empty once it has been archived, and deserializes to a fresh range with the
+/// same bounds.
 #[derive(Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "validation", derive(bytecheck::CheckBytes))]
 #[cfg_attr(feature = "strict", repr(C))]
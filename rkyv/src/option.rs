@@ -75,6 +75,34 @@ impl<T> ArchivedOption<T> {
         }
     }
 
+    /// Maps an `&ArchivedOption<T>` to an `Option<U>` by applying a function to a reference to the
+    /// contained value.
+    ///
+    /// This works on the archived value in place, so `f` can return references into the archive.
+    #[inline]
+    pub fn map<'a, U, F: FnOnce(&'a T) -> U>(&'a self, f: F) -> Option<U> {
+        self.as_ref().map(f)
+    }
+
+    /// Returns `None` if the option is `None`, otherwise calls `f` with a reference to the contained
+    /// value and returns the result.
+    ///
+    /// This is useful for navigating nested optional fields in an archive without deserializing
+    /// them, for example `outer.and_then(|o| o.inner.as_ref())`.
+    #[inline]
+    pub fn and_then<'a, U, F: FnOnce(&'a T) -> Option<U>>(&'a self, f: F) -> Option<U> {
+        self.as_ref().and_then(f)
+    }
+
+    /// Returns a reference to the contained value, or `default` if the option is `None`.
+    #[inline]
+    pub fn get_or<'a>(&'a self, default: &'a T) -> &'a T {
+        match self {
+            ArchivedOption::None => default,
+            ArchivedOption::Some(value) => value,
+        }
+    }
+
     /// Returns an iterator over the possibly contained value.
     #[inline]
     pub const fn iter(&self) -> Iter<'_, T> {
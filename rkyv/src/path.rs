@@ -0,0 +1,164 @@
+//! Archived versions of path types.
+
+use crate::{
+    ffi::{ArchivedOsString, OsStringResolver},
+    ser::Serializer,
+};
+use core::{cmp, fmt, hash};
+use std::{borrow::Cow, path::Path};
+
+/// An archived [`PathBuf`](std::path::PathBuf).
+///
+/// This is stored as an [`ArchivedOsString`], and has the same portability caveats. An archived
+/// path can only be borrowed as a [`Path`] without allocating on Unix platforms.
+#[repr(transparent)]
+pub struct ArchivedPathBuf(ArchivedOsString);
+
+impl ArchivedPathBuf {
+    /// Returns the archived OS string containing the path.
+    #[inline]
+    pub fn as_archived_os_string(&self) -> &ArchivedOsString {
+        &self.0
+    }
+
+    /// Extracts a `Path` slice containing the entire path.
+    #[cfg(unix)]
+    #[inline]
+    pub fn as_path(&self) -> &Path {
+        Path::new(self.0.as_os_str())
+    }
+
+    /// Converts this archived path to a `Cow<Path>`.
+    ///
+    /// See [`ArchivedOsStr`](crate::ffi::ArchivedOsStr) for how the path is decoded on each platform.
+    #[inline]
+    pub fn to_path(&self) -> Cow<'_, Path> {
+        match self.0.to_os_str() {
+            Cow::Borrowed(s) => Cow::Borrowed(Path::new(s)),
+            Cow::Owned(s) => Cow::Owned(s.into()),
+        }
+    }
+
+    /// Resolves an archived path from the given path and parameters.
+    ///
+    /// # Safety
+    ///
+    /// - `pos` must be the position of `out` within the archive
+    /// - `resolver` must be the result of serializing a path
+    #[inline]
+    pub unsafe fn resolve_from_path(
+        path: &Path,
+        pos: usize,
+        resolver: PathBufResolver,
+        out: *mut Self,
+    ) {
+        let (fp, fo) = out_field!(out.0);
+        ArchivedOsString::resolve_from_os_str(path.as_os_str(), pos + fp, resolver.0, fo);
+    }
+
+    /// Serializes a path.
+    #[inline]
+    pub fn serialize_from_path<S: Serializer + ?Sized>(
+        path: &Path,
+        serializer: &mut S,
+    ) -> Result<PathBufResolver, S::Error> {
+        Ok(PathBufResolver(ArchivedOsString::serialize_from_os_str(
+            path.as_os_str(),
+            serializer,
+        )?))
+    }
+}
+
+#[cfg(unix)]
+impl AsRef<Path> for ArchivedPathBuf {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl fmt::Debug for ArchivedPathBuf {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_path().fmt(f)
+    }
+}
+
+#[cfg(unix)]
+impl core::ops::Deref for ArchivedPathBuf {
+    type Target = Path;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_path()
+    }
+}
+
+impl Eq for ArchivedPathBuf {}
+
+impl hash::Hash for ArchivedPathBuf {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl Ord for ArchivedPathBuf {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialEq for ArchivedPathBuf {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl PartialEq<&Path> for ArchivedPathBuf {
+    #[inline]
+    fn eq(&self, other: &&Path) -> bool {
+        self.0 == other.as_os_str()
+    }
+}
+
+impl PartialEq<ArchivedPathBuf> for &Path {
+    #[inline]
+    fn eq(&self, other: &ArchivedPathBuf) -> bool {
+        other.eq(self)
+    }
+}
+
+impl PartialOrd for ArchivedPathBuf {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The resolver for `PathBuf`.
+pub struct PathBufResolver(OsStringResolver);
+
+#[cfg(feature = "validation")]
+const _: () = {
+    use crate::validation::ArchiveContext;
+    use bytecheck::{CheckBytes, Error};
+
+    impl<C: ArchiveContext + ?Sized> CheckBytes<C> for ArchivedPathBuf
+    where
+        C::Error: Error,
+    {
+        type Error = <ArchivedOsString as CheckBytes<C>>::Error;
+
+        #[inline]
+        unsafe fn check_bytes<'a>(
+            value: *const Self,
+            context: &mut C,
+        ) -> Result<&'a Self, Self::Error> {
+            ArchivedOsString::check_bytes(value.cast(), context)?;
+            Ok(&*value)
+        }
+    }
+};
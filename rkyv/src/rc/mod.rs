@@ -3,10 +3,14 @@
 #[cfg(feature = "validation")]
 pub mod validation;
 
+#[cfg(feature = "rc_strong_count")]
+use crate::Archived;
 use crate::{
     ser::{Serializer, SharedSerializeRegistry},
     ArchivePointee, ArchiveUnsized, MetadataResolver, RelPtr, SerializeUnsized,
 };
+#[cfg(feature = "rc_strong_count")]
+use core::convert::TryFrom;
 use core::{borrow::Borrow, cmp, fmt, hash, marker::PhantomData, ops::Deref, pin::Pin, ptr};
 
 /// An archived `Rc`.
@@ -14,8 +18,16 @@ use core::{borrow::Borrow, cmp, fmt, hash, marker::PhantomData, ops::Deref, pin:
 /// This is a thin wrapper around a [`RelPtr`] to the archived type paired with a "flavor" type.
 /// Because there may be many varieties of shared pointers and they may not be used together, the
 /// flavor helps check that memory is not being shared incorrectly during validation.
-#[repr(transparent)]
-pub struct ArchivedRc<T: ArchivePointee + ?Sized, F>(RelPtr<T>, PhantomData<F>);
+///
+/// With the `rc_strong_count` feature, each archived pointer also records the strong count of the
+/// shared pointer it was serialized from. This is available through `archived_strong_count`.
+#[cfg_attr(not(feature = "rc_strong_count"), repr(transparent))]
+#[cfg_attr(feature = "rc_strong_count", repr(C))]
+pub struct ArchivedRc<T: ArchivePointee + ?Sized, F>(
+    RelPtr<T>,
+    PhantomData<F>,
+    #[cfg(feature = "rc_strong_count")] Archived<u32>,
+);
 
 impl<T: ArchivePointee + ?Sized, F> ArchivedRc<T, F> {
     /// Gets the value of the `ArchivedRc`.
@@ -35,6 +47,17 @@ impl<T: ArchivePointee + ?Sized, F> ArchivedRc<T, F> {
         self.map_unchecked_mut(|s| &mut *s.0.as_mut_ptr())
     }
 
+    /// Gets the strong count of the shared pointer this was serialized from.
+    ///
+    /// This is the number of strong references to the shared value at the time it was serialized,
+    /// including those that were not part of the archive. Archived pointers to the same value that
+    /// were serialized at the same time report the same count.
+    #[cfg(feature = "rc_strong_count")]
+    #[inline]
+    pub fn archived_strong_count(&self) -> u32 {
+        from_archived!(self.2)
+    }
+
     /// Resolves an archived `Rc` from a given reference.
     ///
     /// # Safety
@@ -50,9 +73,18 @@ impl<T: ArchivePointee + ?Sized, F> ArchivedRc<T, F> {
     ) {
         let (fp, fo) = out_field!(out.0);
         value.resolve_unsized(pos + fp, resolver.pos, resolver.metadata_resolver, fo);
+        #[cfg(feature = "rc_strong_count")]
+        {
+            let (_, fo) = out_field!(out.2);
+            fo.write(to_archived!(resolver.strong_count));
+        }
     }
 
     /// Serializes an archived `Rc` from a given reference.
+    ///
+    /// The archived pointer records a strong count of 1. Use
+    /// [`serialize_from_ref_with_strong_count`](ArchivedRc::serialize_from_ref_with_strong_count)
+    /// to record the actual strong count of a shared pointer.
     #[inline]
     pub fn serialize_from_ref<
         U: SerializeUnsized<S> + ?Sized,
@@ -61,9 +93,30 @@ impl<T: ArchivePointee + ?Sized, F> ArchivedRc<T, F> {
         value: &U,
         serializer: &mut S,
     ) -> Result<RcResolver<MetadataResolver<U>>, S::Error> {
+        Self::serialize_from_ref_with_strong_count(value, 1, serializer)
+    }
+
+    /// Serializes an archived `Rc` from a given reference and the strong count of the shared
+    /// pointer it belongs to.
+    ///
+    /// The strong count is only stored when the `rc_strong_count` feature is enabled. Counts that
+    /// do not fit in a `u32` are saturated.
+    #[inline]
+    pub fn serialize_from_ref_with_strong_count<
+        U: SerializeUnsized<S> + ?Sized,
+        S: Serializer + SharedSerializeRegistry + ?Sized,
+    >(
+        value: &U,
+        strong_count: usize,
+        serializer: &mut S,
+    ) -> Result<RcResolver<MetadataResolver<U>>, S::Error> {
+        #[cfg(not(feature = "rc_strong_count"))]
+        let _ = strong_count;
         Ok(RcResolver {
             pos: serializer.serialize_shared(value)?,
             metadata_resolver: value.serialize_metadata(serializer)?,
+            #[cfg(feature = "rc_strong_count")]
+            strong_count: u32::try_from(strong_count).unwrap_or(u32::MAX),
         })
     }
 }
@@ -149,6 +202,8 @@ impl<T, F> fmt::Pointer for ArchivedRc<T, F> {
 pub struct RcResolver<T> {
     pos: usize,
     metadata_resolver: T,
+    #[cfg(feature = "rc_strong_count")]
+    strong_count: u32,
 }
 
 /// An archived `rc::Weak`.
@@ -219,13 +274,36 @@ impl<T: ArchivePointee + ?Sized, F> ArchivedRcWeak<T, F> {
         value: Option<&U>,
         serializer: &mut S,
     ) -> Result<RcWeakResolver<MetadataResolver<U>>, S::Error>
+    where
+        U: SerializeUnsized<S, Archived = T> + ?Sized,
+        S: Serializer + SharedSerializeRegistry + ?Sized,
+    {
+        Self::serialize_from_ref_with_strong_count(value, 1, serializer)
+    }
+
+    /// Serializes an archived `Weak` from a given optional reference and the strong count of the
+    /// shared pointer it belongs to.
+    ///
+    /// The strong count is only stored when the `rc_strong_count` feature is enabled.
+    #[inline]
+    pub fn serialize_from_ref_with_strong_count<U, S>(
+        value: Option<&U>,
+        strong_count: usize,
+        serializer: &mut S,
+    ) -> Result<RcWeakResolver<MetadataResolver<U>>, S::Error>
     where
         U: SerializeUnsized<S, Archived = T> + ?Sized,
         S: Serializer + SharedSerializeRegistry + ?Sized,
     {
         Ok(match value {
             None => RcWeakResolver::None,
-            Some(r) => RcWeakResolver::Some(ArchivedRc::<T, F>::serialize_from_ref(r, serializer)?),
+            Some(r) => {
+                RcWeakResolver::Some(ArchivedRc::<T, F>::serialize_from_ref_with_strong_count(
+                    r,
+                    strong_count,
+                    serializer,
+                )?)
+            }
         })
     }
 }
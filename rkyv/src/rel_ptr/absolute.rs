@@ -0,0 +1,236 @@
+use super::{signed_offset, OffsetError};
+use crate::ArchivePointee;
+use core::{
+    fmt,
+    marker::{PhantomData, PhantomPinned},
+    mem::size_of,
+    ptr,
+};
+
+/// A pointer which resolves relative to its position in memory, or to an absolute address.
+///
+/// Relative addressing can only reach memory in the same address space as the pointer, which rules
+/// out composing multiple archives that are loaded separately. An `AbsoluteRelPtr` can fall back
+/// to storing the absolute address of its target, which lets it point into a different buffer.
+///
+/// # Warning
+///
+/// Absolute pointers break the relocatability guarantees of rkyv. An archive containing an absolute
+/// pointer is only valid as long as its target stays at the same address in the same process, so it
+/// can't be written to disk, sent to another process, or moved to a different buffer. Absolute
+/// pointers are also stored in the native endianness and pointer width. Because absolute pointers
+/// can't be bounds checked against the archive that contains them, `AbsoluteRelPtr` does not
+/// support validation.
+///
+/// # Examples
+///
+/// ```
+/// use core::mem::MaybeUninit;
+/// use rkyv::rel_ptr::AbsoluteRelPtr;
+///
+/// let target = Box::new(42u32);
+///
+/// let mut out = MaybeUninit::<AbsoluteRelPtr<u32>>::uninit();
+/// unsafe {
+///     AbsoluteRelPtr::emplace_absolute(&*target as *const u32, (), out.as_mut_ptr());
+/// }
+/// let ptr = unsafe { out.assume_init() };
+/// assert!(ptr.is_absolute());
+/// assert_eq!(unsafe { *ptr.as_ptr() }, 42);
+/// ```
+#[repr(C)]
+pub struct AbsoluteRelPtr<T: ArchivePointee + ?Sized> {
+    value: isize,
+    is_absolute: bool,
+    metadata: T::ArchivedMetadata,
+    _phantom: PhantomData<T>,
+    _pinned: PhantomPinned,
+}
+
+impl<T: ArchivePointee + ?Sized> AbsoluteRelPtr<T> {
+    /// Attempts to create a relative pointer from one position to another.
+    ///
+    /// # Safety
+    ///
+    /// - `from` must be the position of `out` within the archive
+    /// - `to` must be the position of some valid `T` within the archive
+    /// - `metadata` must be the archived metadata for the `T` at `to`
+    #[inline]
+    pub unsafe fn try_emplace_relative(
+        from: usize,
+        to: usize,
+        metadata: T::ArchivedMetadata,
+        out: *mut Self,
+    ) -> Result<(), OffsetError> {
+        let offset = signed_offset(from, to)?;
+        Self::emplace_raw(offset, false, metadata, out);
+        Ok(())
+    }
+
+    /// Creates a pointer to the given absolute address.
+    ///
+    /// The created pointer is only valid as long as the target is not moved or freed. See the
+    /// [type-level documentation](AbsoluteRelPtr) for more details.
+    ///
+    /// # Safety
+    ///
+    /// - `target` must point to some valid `T`
+    /// - `metadata` must be the archived metadata for the `T` at `target`
+    #[inline]
+    pub unsafe fn emplace_absolute(
+        target: *const T,
+        metadata: T::ArchivedMetadata,
+        out: *mut Self,
+    ) {
+        Self::emplace_raw(target.cast::<()>() as isize, true, metadata, out);
+    }
+
+    #[inline]
+    unsafe fn emplace_raw(
+        value: isize,
+        is_absolute: bool,
+        metadata: T::ArchivedMetadata,
+        out: *mut Self,
+    ) {
+        ptr::addr_of_mut!((*out).value).write(value);
+        ptr::addr_of_mut!((*out).is_absolute).write(is_absolute);
+        ptr::addr_of_mut!((*out).metadata).write(metadata);
+    }
+
+    /// Gets the base pointer for the relative pointer.
+    #[inline]
+    pub fn base(&self) -> *const u8 {
+        (self as *const Self).cast::<u8>()
+    }
+
+    /// Gets whether the pointer stores an absolute address instead of a relative offset.
+    #[inline]
+    pub fn is_absolute(&self) -> bool {
+        self.is_absolute
+    }
+
+    /// Moves the target of an absolute pointer that points into the memory at `old_base` with
+    /// length `len` to the same position in the memory at `new_base`.
+    ///
+    /// Relative pointers and absolute pointers to addresses outside of that memory are left
+    /// unchanged.
+    #[inline]
+    pub fn rebase(&mut self, old_base: usize, len: usize, new_base: usize) {
+        rebase_raw(&mut self.value, self.is_absolute, old_base, len, new_base);
+    }
+
+    /// Gets the metadata of the pointer.
+    #[inline]
+    pub fn metadata(&self) -> &T::ArchivedMetadata {
+        &self.metadata
+    }
+
+    /// Calculates the memory address being pointed to by this pointer.
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        let data_address = if self.is_absolute {
+            self.value as *const u8
+        } else {
+            self.base().wrapping_offset(self.value)
+        };
+        ptr_meta::from_raw_parts(data_address.cast(), T::pointer_metadata(&self.metadata))
+    }
+
+    /// Returns an unsafe mutable pointer to the memory address being pointed to by this pointer.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.as_ptr() as *mut T
+    }
+}
+
+impl<T: ArchivePointee + ?Sized> fmt::Debug for AbsoluteRelPtr<T>
+where
+    T::ArchivedMetadata: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AbsoluteRelPtr")
+            .field("value", &self.value)
+            .field("is_absolute", &self.is_absolute)
+            .field("metadata", &self.metadata)
+            .finish()
+    }
+}
+
+impl<T: ArchivePointee + ?Sized> fmt::Pointer for AbsoluteRelPtr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.as_ptr(), f)
+    }
+}
+
+#[inline]
+fn rebase_raw(value: &mut isize, is_absolute: bool, old_base: usize, len: usize, new_base: usize) {
+    let address = *value as usize;
+    if is_absolute && address >= old_base && address - old_base <= len {
+        *value = (address - old_base).wrapping_add(new_base) as isize;
+    }
+}
+
+/// Fixes up the absolute pointers in an archive that has been copied from `old_base` to
+/// `new_base`.
+///
+/// Relative pointers are position-independent, so they stay valid when an archive is copied and
+/// are never modified. An archive which only contains relative pointers doesn't need to be rebased
+/// at all, and rebasing it is a no-op.
+///
+/// Absolute pointers can't be told apart from other bytes in an archive, so `absolute_ptrs` must
+/// list the positions of every [`AbsoluteRelPtr`] in `bytes`. Each absolute pointer which points
+/// into the old copy of the archive is moved to the same position in the new copy. Pointers that
+/// store relative offsets or point outside of the archive are left unchanged.
+///
+/// # Panics
+///
+/// Panics if any of the positions in `absolute_ptrs` are out of bounds for `bytes`.
+///
+/// # Examples
+///
+/// ```
+/// use core::mem::size_of;
+/// use rkyv::{
+///     rel_ptr::{rebase, AbsoluteRelPtr},
+///     AlignedVec, Archived,
+/// };
+///
+/// #[repr(C)]
+/// struct Root {
+///     value: Archived<u32>,
+///     ptr: AbsoluteRelPtr<Archived<u32>>,
+/// }
+///
+/// let mut old = AlignedVec::new();
+/// old.resize(size_of::<Root>(), 0);
+/// let root = old.as_mut_ptr().cast::<Root>();
+/// unsafe {
+///     (*root).value = 42;
+///     AbsoluteRelPtr::emplace_absolute(&(*root).value, (), &mut (*root).ptr);
+/// }
+///
+/// let mut new = AlignedVec::new();
+/// new.extend_from_slice(&old);
+/// let ptr_pos = size_of::<Root>() - size_of::<AbsoluteRelPtr<Archived<u32>>>();
+/// let (old_base, new_base) = (old.as_ptr() as usize, new.as_ptr() as usize);
+/// rebase(&mut new, old_base, new_base, &[ptr_pos]);
+/// drop(old);
+///
+/// let root = unsafe { &*new.as_ptr().cast::<Root>() };
+/// assert!(core::ptr::eq(root.ptr.as_ptr(), &root.value));
+/// assert_eq!(unsafe { *root.ptr.as_ptr() }, 42);
+/// ```
+pub fn rebase(bytes: &mut [u8], old_base: usize, new_base: usize, absolute_ptrs: &[usize]) {
+    const VALUE_SIZE: usize = size_of::<isize>();
+
+    let len = bytes.len();
+    for &pos in absolute_ptrs {
+        let value_bytes = &mut bytes[pos..pos + VALUE_SIZE + 1];
+        let is_absolute = value_bytes[VALUE_SIZE] != 0;
+        let mut value = [0; VALUE_SIZE];
+        value.copy_from_slice(&value_bytes[..VALUE_SIZE]);
+        let mut value = isize::from_ne_bytes(value);
+        rebase_raw(&mut value, is_absolute, old_base, len, new_base);
+        value_bytes[..VALUE_SIZE].copy_from_slice(&value.to_ne_bytes());
+    }
+}
@@ -1,13 +1,27 @@
 //! Relative pointer implementations and options.
 
+#[cfg(feature = "absolute_pointers")]
+mod absolute;
 #[cfg(feature = "validation")]
 mod validation;
 
+#[cfg(feature = "absolute_pointers")]
+pub use self::absolute::{rebase, AbsoluteRelPtr};
+#[cfg(feature = "validation")]
+pub use self::validation::{RelPtrCheckError, ScaledOffsetError, VarintOffsetError};
+
 use crate::{ArchivePointee, ArchiveUnsized, Archived};
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+use core::num::{NonZeroI32, NonZeroU32};
+#[cfg(target_pointer_width = "64")]
+use core::num::{NonZeroI64, NonZeroU64};
 use core::{
     convert::TryFrom,
     fmt,
     marker::{PhantomData, PhantomPinned},
+    mem::{align_of, size_of, MaybeUninit},
+    num::{NonZeroI16, NonZeroI8, NonZeroU16, NonZeroU8},
+    ops::Range,
     ptr,
 };
 
@@ -18,6 +32,8 @@ pub enum OffsetError {
     IsizeOverflow,
     /// The offset is too far for the offset type of the relative pointer
     ExceedsStorageRange,
+    /// The target position is not aligned for the pointee type
+    Misaligned,
 }
 
 impl fmt::Display for OffsetError {
@@ -28,6 +44,9 @@ impl fmt::Display for OffsetError {
                 f,
                 "the offset is too far for the offset type of the relative pointer"
             ),
+            OffsetError::Misaligned => {
+                write!(f, "the target position is not aligned for the pointee type")
+            }
         }
     }
 }
@@ -53,9 +72,16 @@ impl std::error::Error for OffsetError {}
 /// assert_eq!(signed_offset(isize::MAX as usize + 1, 0), Ok(isize::MIN));
 /// assert_eq!(signed_offset(0, isize::MAX as usize + 2), Err(OffsetError::IsizeOverflow));
 /// assert_eq!(signed_offset(isize::MAX as usize + 2, 0), Err(OffsetError::IsizeOverflow));
+///
+/// // signed_offset can also be evaluated at compile time
+/// const OFFSET: isize = match signed_offset(0, 10) {
+///     Ok(offset) => offset,
+///     Err(_) => 0,
+/// };
+/// assert_eq!(OFFSET, 10);
 /// ```
 #[inline]
-pub fn signed_offset(from: usize, to: usize) -> Result<isize, OffsetError> {
+pub const fn signed_offset(from: usize, to: usize) -> Result<isize, OffsetError> {
     let (result, overflow) = to.overflowing_sub(from);
     if (!overflow && result <= (isize::MAX as usize))
         || (overflow && result >= (isize::MIN as usize))
@@ -66,50 +92,251 @@ pub fn signed_offset(from: usize, to: usize) -> Result<isize, OffsetError> {
     }
 }
 
+/// Calculates the position targeted by an offset from a base position.
+///
+/// This is the inverse of [`signed_offset`]. It returns `None` if the resulting position would
+/// overflow a `usize` or be negative.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::rel_ptr::{signed_offset, target_position};
+///
+/// assert_eq!(target_position(0, 1), Some(1));
+/// assert_eq!(target_position(1, -1), Some(0));
+/// assert_eq!(target_position(0, -1), None);
+/// assert_eq!(target_position(usize::MAX, 1), None);
+///
+/// let max = isize::MAX as usize;
+/// let positions = [0, 1, 2, 100, max - 1, max, max + 1, max + 2, usize::MAX];
+/// for &from in positions.iter() {
+///     for &to in positions.iter() {
+///         if let Ok(offset) = signed_offset(from, to) {
+///             assert_eq!(target_position(from, offset), Some(to));
+///         }
+///     }
+/// }
+/// ```
+#[inline]
+pub const fn target_position(from: usize, offset: isize) -> Option<usize> {
+    if offset >= 0 {
+        from.checked_add(offset as usize)
+    } else {
+        from.checked_sub(offset.unsigned_abs())
+    }
+}
+
+/// Clamps an `i128` to the range of an `isize`.
+#[inline]
+const fn saturating_isize(value: i128) -> isize {
+    if value > isize::MAX as i128 {
+        isize::MAX
+    } else if value < isize::MIN as i128 {
+        isize::MIN
+    } else {
+        value as isize
+    }
+}
+
 /// A offset that can be used with [`RawRelPtr`].
 pub trait Offset: Copy {
+    /// The minimum offset that can be represented, clamped to the range of an `isize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rkyv::{rel_ptr::Offset, Archived};
+    ///
+    /// // Relative pointers usually point backwards, so signed offsets are most common
+    /// fn pick_offset_type(archive_len: usize) -> &'static str {
+    ///     let distance = -(archive_len as isize);
+    ///     if distance >= Archived::<i16>::MIN_OFFSET {
+    ///         "i16"
+    ///     } else if distance >= Archived::<i32>::MIN_OFFSET {
+    ///         "i32"
+    ///     } else {
+    ///         "i64"
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Archived::<i16>::MIN_OFFSET, -32768);
+    /// assert_eq!(pick_offset_type(1_000), "i16");
+    /// assert_eq!(pick_offset_type(100_000), "i32");
+    /// ```
+    const MIN_OFFSET: isize;
+
+    /// The maximum offset that can be represented, clamped to the range of an `isize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rkyv::{rel_ptr::Offset, Archived};
+    ///
+    /// fn pick_offset_type(archive_len: usize) -> &'static str {
+    ///     if archive_len as isize <= Archived::<u16>::MAX_OFFSET {
+    ///         "u16"
+    ///     } else {
+    ///         "u32"
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Archived::<u16>::MAX_OFFSET, 65535);
+    /// assert_eq!(Archived::<u32>::MIN_OFFSET, 0);
+    /// assert_eq!(pick_offset_type(60_000), "u16");
+    /// assert_eq!(pick_offset_type(70_000), "u32");
+    /// ```
+    const MAX_OFFSET: isize;
+
     /// Creates a new offset between a `from` position and a `to` position.
     fn between(from: usize, to: usize) -> Result<Self, OffsetError>;
 
+    /// Creates a new offset between a `from` position and a `to` position, verifying that `to` is
+    /// a multiple of `align`.
+    ///
+    /// Returns [`OffsetError::Misaligned`] if `to` is not aligned. `align` must be a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rkyv::rel_ptr::{Offset, OffsetError};
+    ///
+    /// assert_eq!(i32::between_aligned(0, 16, 16).map(|o| o.to_isize()), Ok(16));
+    /// assert_eq!(i32::between_aligned(0, 12, 16), Err(OffsetError::Misaligned));
+    /// ```
+    #[inline]
+    fn between_aligned(from: usize, to: usize, align: usize) -> Result<Self, OffsetError> {
+        debug_assert!(align.is_power_of_two());
+        if to & (align - 1) != 0 {
+            Err(OffsetError::Misaligned)
+        } else {
+            Self::between(from, to)
+        }
+    }
+
     /// Gets the offset as an `isize`.
     fn to_isize(&self) -> isize;
 }
 
+/// A primitive type that can be used to store an [`Offset`].
+///
+/// [`Offset`] is implemented for every type that implements `OffsetPrimitive`. This is implemented
+/// for the archived integer types, and can be implemented for custom integer-like types so that
+/// they can be used as the offset of a relative pointer.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::rel_ptr::{Offset, OffsetPrimitive};
+///
+/// // An offset that only points forward, stored in a `u8` as a count of 4-byte words
+/// #[derive(Clone, Copy)]
+/// struct WordOffset(u8);
+///
+/// impl OffsetPrimitive for WordOffset {
+///     const MIN_ISIZE: isize = 0;
+///     const MAX_ISIZE: isize = u8::MAX as isize * 4;
+///
+///     fn try_from_isize(value: isize) -> Option<Self> {
+///         if value % 4 == 0 && (0..=Self::MAX_ISIZE).contains(&value) {
+///             Some(Self((value / 4) as u8))
+///         } else {
+///             None
+///         }
+///     }
+///
+///     fn into_isize(self) -> isize {
+///         self.0 as isize * 4
+///     }
+/// }
+///
+/// assert_eq!(WordOffset::between(0, 12).unwrap().to_isize(), 12);
+/// assert!(WordOffset::between(0, 13).is_err());
+/// ```
+pub trait OffsetPrimitive: Copy {
+    /// The minimum value that can be stored, clamped to the range of an `isize`.
+    const MIN_ISIZE: isize;
+    /// The maximum value that can be stored, clamped to the range of an `isize`.
+    const MAX_ISIZE: isize;
+
+    /// Converts an `isize` to this type, returning `None` if it can't be represented.
+    fn try_from_isize(value: isize) -> Option<Self>;
+
+    /// Converts this value to an `isize`.
+    fn into_isize(self) -> isize;
+}
+
+impl<P: OffsetPrimitive> Offset for P {
+    const MIN_OFFSET: isize = P::MIN_ISIZE;
+    const MAX_OFFSET: isize = P::MAX_ISIZE;
+
+    #[inline]
+    fn between(from: usize, to: usize) -> Result<Self, OffsetError> {
+        // pointer::add and pointer::offset require that the computed offsets cannot overflow an
+        // isize, which is why we're using signed_offset instead of checked_sub for unsized types
+        P::try_from_isize(signed_offset(from, to)?).ok_or(OffsetError::ExceedsStorageRange)
+    }
+
+    #[inline]
+    fn to_isize(&self) -> isize {
+        self.into_isize()
+    }
+}
+
+// Coherence can't see through `Archived<T>` projections, so the blanket `Offset` impl would
+// conflict with any downstream `OffsetPrimitive` impl if these used `Archived<$ty>` directly.
+#[cfg(not(any(feature = "archive_le", feature = "archive_be")))]
+macro_rules! archived_primitive {
+    ($ty:ty) => {
+        $ty
+    };
+}
+
+#[cfg(feature = "archive_le")]
+macro_rules! archived_primitive {
+    ($ty:ty) => {
+        crate::rend::LittleEndian<$ty>
+    };
+}
+
+#[cfg(feature = "archive_be")]
+macro_rules! archived_primitive {
+    ($ty:ty) => {
+        crate::rend::BigEndian<$ty>
+    };
+}
+
 macro_rules! impl_offset {
     ($ty:ty) => {
-        impl Offset for $ty {
+        impl OffsetPrimitive for $ty {
+            const MIN_ISIZE: isize = saturating_isize(<$ty>::MIN as i128);
+            const MAX_ISIZE: isize = saturating_isize(<$ty>::MAX as i128);
+
             #[inline]
-            fn between(from: usize, to: usize) -> Result<Self, OffsetError> {
-                // pointer::add and pointer::offset require that the computed offsets cannot
-                // overflow an isize, which is why we're using signed_offset instead of checked_sub
-                // for unsized types
-                Self::try_from(signed_offset(from, to)?)
-                    .map_err(|_| OffsetError::ExceedsStorageRange)
+            fn try_from_isize(value: isize) -> Option<Self> {
+                Self::try_from(value).ok()
             }
 
             #[inline]
-            fn to_isize(&self) -> isize {
+            fn into_isize(self) -> isize {
                 // We're guaranteed that our offset will not exceed the the capacity of an `isize`
-                *self as isize
+                self as isize
             }
         }
     };
     (@endian $ty:ty) => {
-        impl Offset for Archived<$ty> {
+        impl OffsetPrimitive for archived_primitive!($ty) {
+            const MIN_ISIZE: isize = saturating_isize(<$ty>::MIN as i128);
+            const MAX_ISIZE: isize = saturating_isize(<$ty>::MAX as i128);
+
             #[inline]
-            fn between(from: usize, to: usize) -> Result<Self, OffsetError> {
-                // pointer::add and pointer::offset require that the computed offsets cannot
-                // overflow an isize, which is why we're using signed_offset instead of checked_sub
-                // for unsized types
-                <$ty>::try_from(signed_offset(from, to)?)
-                    .map(|x| to_archived!(x))
-                    .map_err(|_| OffsetError::ExceedsStorageRange)
+            fn try_from_isize(value: isize) -> Option<Self> {
+                <$ty>::try_from(value).ok().map(|x| to_archived!(x))
             }
 
             #[inline]
-            fn to_isize(&self) -> isize {
+            fn into_isize(self) -> isize {
                 // We're guaranteed that our offset will not exceed the the capacity of an `isize`
-                from_archived!(*self) as isize
+                from_archived!(self) as isize
             }
         }
     };
@@ -128,14 +355,473 @@ impl_offset!(@endian u32);
 #[cfg(target_pointer_width = "64")]
 impl_offset!(@endian u64);
 
+macro_rules! impl_offset_nonzero {
+    ($ty:ty, $underlying:ty) => {
+        impl OffsetPrimitive for $ty {
+            const MIN_ISIZE: isize = saturating_isize(<$underlying>::MIN as i128);
+            const MAX_ISIZE: isize = saturating_isize(<$underlying>::MAX as i128);
+
+            #[inline]
+            fn try_from_isize(value: isize) -> Option<Self> {
+                // A zero offset can't be represented by a nonzero type, so it's treated as out of
+                // range for the offset storage
+                <$underlying>::try_from(value).ok().and_then(<$ty>::new)
+            }
+
+            #[inline]
+            fn into_isize(self) -> isize {
+                // We're guaranteed that our offset will not exceed the the capacity of an `isize`
+                self.get() as isize
+            }
+        }
+    };
+    (@endian $ty:ty, $underlying:ty) => {
+        impl OffsetPrimitive for archived_primitive!($ty) {
+            const MIN_ISIZE: isize = saturating_isize(<$underlying>::MIN as i128);
+            const MAX_ISIZE: isize = saturating_isize(<$underlying>::MAX as i128);
+
+            #[inline]
+            fn try_from_isize(value: isize) -> Option<Self> {
+                // A zero offset can't be represented by a nonzero type, so it's treated as out of
+                // range for the offset storage
+                <$underlying>::try_from(value)
+                    .ok()
+                    .and_then(<$ty>::new)
+                    .map(|x| to_archived!(x))
+            }
+
+            #[inline]
+            fn into_isize(self) -> isize {
+                // We're guaranteed that our offset will not exceed the the capacity of an `isize`
+                from_archived!(self).get() as isize
+            }
+        }
+    };
+}
+
+impl_offset_nonzero!(NonZeroI8, i8);
+impl_offset_nonzero!(@endian NonZeroI16, i16);
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_offset_nonzero!(@endian NonZeroI32, i32);
+#[cfg(target_pointer_width = "64")]
+impl_offset_nonzero!(@endian NonZeroI64, i64);
+impl_offset_nonzero!(NonZeroU8, u8);
+impl_offset_nonzero!(@endian NonZeroU16, u16);
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_offset_nonzero!(@endian NonZeroU32, u32);
+#[cfg(target_pointer_width = "64")]
+impl_offset_nonzero!(@endian NonZeroU64, u64);
+
+/// An offset stored as a signed LEB128 varint in a fixed-size buffer of `N` bytes.
+///
+/// Each byte stores seven bits of the offset, and the high bit of each byte indicates whether more
+/// bytes follow. Short offsets only use the leading bytes of the buffer, and any unused trailing
+/// bytes are zeroed. A buffer of `N` bytes can store offsets in the range
+/// `-(2^(7N - 1))..2^(7N - 1)`.
+///
+/// `VarintOffset` has an alignment of 1, which makes it a good fit for compact archives where most
+/// relative pointers only point a short distance away.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::rel_ptr::{Offset, OffsetError, VarintOffset};
+///
+/// assert_eq!(VarintOffset::<1>::between(0, 63).unwrap().to_isize(), 63);
+/// assert_eq!(VarintOffset::<1>::between(64, 0).unwrap().to_isize(), -64);
+/// assert_eq!(VarintOffset::<1>::between(0, 64), Err(OffsetError::ExceedsStorageRange));
+/// assert_eq!(VarintOffset::<2>::between(0, 64).unwrap().to_isize(), 64);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct VarintOffset<const N: usize> {
+    bytes: [u8; N],
+}
+
+impl<const N: usize> VarintOffset<N> {
+    /// Encodes the given offset, returning `None` if it doesn't fit in `N` bytes.
+    #[inline]
+    pub fn encode(offset: isize) -> Option<Self> {
+        let mut bytes = [0; N];
+        let mut value = offset;
+        for byte in bytes.iter_mut() {
+            let low = (value & 0x7f) as u8;
+            value >>= 7;
+            let done = (value == 0 && low & 0x40 == 0) || (value == -1 && low & 0x40 != 0);
+            if done {
+                *byte = low;
+                return Some(Self { bytes });
+            } else {
+                *byte = low | 0x80;
+            }
+        }
+        None
+    }
+
+    /// Decodes the offset, returning `None` if the bytes are not a valid varint or the offset
+    /// doesn't fit in an `isize`.
+    #[inline]
+    pub fn decode(&self) -> Option<isize> {
+        let mut result = 0i128;
+        let mut shift = 0;
+        for &byte in self.bytes.iter() {
+            if shift >= 128 {
+                return None;
+            }
+            result |= ((byte & 0x7f) as i128) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                if shift < 128 && byte & 0x40 != 0 {
+                    result |= !0 << shift;
+                }
+                return isize::try_from(result).ok();
+            }
+        }
+        None
+    }
+
+    /// Decodes the offset without checking that the bytes are a valid varint.
+    ///
+    /// Unterminated varints and offsets that overflow an `isize` are decoded to an unspecified
+    /// value instead of failing.
+    #[inline]
+    fn decode_wrapping(&self) -> isize {
+        let mut result = 0isize;
+        let mut shift = 0u32;
+        for &byte in self.bytes.iter() {
+            result |= ((byte & 0x7f) as isize).wrapping_shl(shift);
+            shift = shift.saturating_add(7);
+            if byte & 0x80 == 0 {
+                if shift < isize::BITS && byte & 0x40 != 0 {
+                    result |= !0 << shift;
+                }
+                break;
+            }
+        }
+        result
+    }
+
+    /// Gets the encoded bytes of the offset.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.bytes
+    }
+}
+
+impl<const N: usize> Offset for VarintOffset<N> {
+    const MIN_OFFSET: isize = if 7 * N >= isize::BITS as usize {
+        isize::MIN
+    } else {
+        -(1 << (7 * N - 1))
+    };
+    const MAX_OFFSET: isize = if 7 * N >= isize::BITS as usize {
+        isize::MAX
+    } else {
+        (1 << (7 * N - 1)) - 1
+    };
+
+    #[inline]
+    fn between(from: usize, to: usize) -> Result<Self, OffsetError> {
+        Self::encode(signed_offset(from, to)?).ok_or(OffsetError::ExceedsStorageRange)
+    }
+
+    /// Decodes the offset.
+    ///
+    /// Varint offsets are assumed to have been validated. If the bytes are not a valid varint, the
+    /// returned offset is unspecified.
+    #[inline]
+    fn to_isize(&self) -> isize {
+        self.decode_wrapping()
+    }
+}
+
+/// An offset that is always stored in little-endian byte order, regardless of the endianness of
+/// the host or the archive format.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::rel_ptr::{LittleEndianOffset, Offset};
+///
+/// let offset = LittleEndianOffset::<u32>::between(0, 0x01020304).unwrap();
+/// assert_eq!(offset.to_bytes(), [0x04, 0x03, 0x02, 0x01]);
+/// assert_eq!(offset.to_isize(), 0x01020304);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct LittleEndianOffset<I>(I);
+
+/// An offset that is always stored in big-endian byte order, regardless of the endianness of the
+/// host or the archive format.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::rel_ptr::{BigEndianOffset, Offset};
+///
+/// let offset = BigEndianOffset::<u32>::between(0, 0x01020304).unwrap();
+/// assert_eq!(offset.to_bytes(), [0x01, 0x02, 0x03, 0x04]);
+/// assert_eq!(offset.to_isize(), 0x01020304);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct BigEndianOffset<I>(I);
+
+macro_rules! impl_endian_offset {
+    ($ty:ty) => {
+        impl_endian_offset!(@impl LittleEndianOffset, $ty, to_le, from_le);
+        impl_endian_offset!(@impl BigEndianOffset, $ty, to_be, from_be);
+    };
+    (@impl $name:ident, $ty:ty, $to:ident, $from:ident) => {
+        impl $name<$ty> {
+            /// Creates an offset from its stored bytes.
+            #[inline]
+            pub const fn from_bytes(bytes: [u8; size_of::<$ty>()]) -> Self {
+                Self(<$ty>::from_ne_bytes(bytes))
+            }
+
+            /// Gets the stored bytes of the offset.
+            #[inline]
+            pub const fn to_bytes(self) -> [u8; size_of::<$ty>()] {
+                self.0.to_ne_bytes()
+            }
+        }
+
+        impl Offset for $name<$ty> {
+            const MIN_OFFSET: isize = saturating_isize(<$ty>::MIN as i128);
+            const MAX_OFFSET: isize = saturating_isize(<$ty>::MAX as i128);
+
+            #[inline]
+            fn between(from: usize, to: usize) -> Result<Self, OffsetError> {
+                <$ty>::try_from(signed_offset(from, to)?)
+                    .map(|x| Self(x.$to()))
+                    .map_err(|_| OffsetError::ExceedsStorageRange)
+            }
+
+            #[inline]
+            fn to_isize(&self) -> isize {
+                // We're guaranteed that our offset will not exceed the the capacity of an `isize`
+                <$ty>::$from(self.0) as isize
+            }
+        }
+    };
+}
+
+impl_endian_offset!(i16);
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_endian_offset!(i32);
+#[cfg(target_pointer_width = "64")]
+impl_endian_offset!(i64);
+impl_endian_offset!(u16);
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_endian_offset!(u32);
+#[cfg(target_pointer_width = "64")]
+impl_endian_offset!(u64);
+
+macro_rules! impl_offset_24 {
+    ($name:ident, $ty:ty, $doc:literal, $min:expr, $max:expr) => {
+        #[doc = $doc]
+        ///
+        /// The offset is stored as three bytes using the archive endianness, and sign-extended
+        /// when converted to an `isize`.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #[repr(transparent)]
+        pub struct $name([u8; 3]);
+
+        impl $name {
+            /// The minimum value that can be stored.
+            pub const MIN: $ty = $min;
+            /// The maximum value that can be stored.
+            pub const MAX: $ty = $max;
+
+            /// Creates a new value, returning `None` if it is out of range.
+            #[inline]
+            pub fn new(value: $ty) -> Option<Self> {
+                if (Self::MIN..=Self::MAX).contains(&value) {
+                    Some(Self(Self::encode(value as u32)))
+                } else {
+                    None
+                }
+            }
+
+            /// Gets the stored value.
+            #[inline]
+            pub fn get(self) -> $ty {
+                // Shifting the value into the top of the integer and back sign-extends signed
+                // values
+                ((Self::decode(self.0) << 8) as $ty) >> 8
+            }
+
+            #[inline]
+            fn encode(value: u32) -> [u8; 3] {
+                #[cfg(any(
+                    feature = "archive_be",
+                    all(not(feature = "archive_le"), target_endian = "big")
+                ))]
+                {
+                    let [_, a, b, c] = value.to_be_bytes();
+                    [a, b, c]
+                }
+                #[cfg(not(any(
+                    feature = "archive_be",
+                    all(not(feature = "archive_le"), target_endian = "big")
+                )))]
+                {
+                    let [a, b, c, _] = value.to_le_bytes();
+                    [a, b, c]
+                }
+            }
+
+            #[inline]
+            fn decode(bytes: [u8; 3]) -> u32 {
+                let [a, b, c] = bytes;
+                #[cfg(any(
+                    feature = "archive_be",
+                    all(not(feature = "archive_le"), target_endian = "big")
+                ))]
+                {
+                    u32::from_be_bytes([0, a, b, c])
+                }
+                #[cfg(not(any(
+                    feature = "archive_be",
+                    all(not(feature = "archive_le"), target_endian = "big")
+                )))]
+                {
+                    u32::from_le_bytes([a, b, c, 0])
+                }
+            }
+        }
+
+        impl Offset for $name {
+            const MIN_OFFSET: isize = Self::MIN as isize;
+            const MAX_OFFSET: isize = Self::MAX as isize;
+
+            #[inline]
+            fn between(from: usize, to: usize) -> Result<Self, OffsetError> {
+                <$ty>::try_from(signed_offset(from, to)?)
+                    .ok()
+                    .and_then(Self::new)
+                    .ok_or(OffsetError::ExceedsStorageRange)
+            }
+
+            #[inline]
+            fn to_isize(&self) -> isize {
+                self.get() as isize
+            }
+        }
+    };
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_offset_24!(
+    I24,
+    i32,
+    "A signed 24-bit integer that can be used as an offset.",
+    -(1 << 23),
+    (1 << 23) - 1
+);
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_offset_24!(
+    U24,
+    u32,
+    "An unsigned 24-bit integer that can be used as an offset.",
+    0,
+    (1 << 24) - 1
+);
+
+/// An offset that is measured in multiples of `SCALE` bytes rather than single bytes.
+///
+/// This extends the range of the underlying offset type `O` by a factor of `SCALE`, at the cost of
+/// only being able to point to positions that are a multiple of `SCALE` bytes away. For example, a
+/// `ScaledOffset<i16, 8>` can reach 256KB in either direction when pointing to 8-byte aligned
+/// values. `SCALE` must not be zero.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::{rel_ptr::{Offset, OffsetError, ScaledOffset}, Archived};
+///
+/// type Scaled = ScaledOffset<Archived<i16>, 8>;
+///
+/// assert_eq!(Scaled::between(0, 80_000).unwrap().to_isize(), 80_000);
+/// assert_eq!(Scaled::between(0, 80_004), Err(OffsetError::ExceedsStorageRange));
+/// assert_eq!(Scaled::MAX_OFFSET, i16::MAX as isize * 8);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ScaledOffset<O, const SCALE: usize>(O);
+
+impl<O: Offset, const SCALE: usize> ScaledOffset<O, SCALE> {
+    /// Gets the underlying unscaled offset.
+    #[inline]
+    pub fn inner(&self) -> &O {
+        &self.0
+    }
+}
+
+impl<O: Offset, const SCALE: usize> Offset for ScaledOffset<O, SCALE> {
+    const MIN_OFFSET: isize = O::MIN_OFFSET.saturating_mul(SCALE as isize);
+    const MAX_OFFSET: isize = O::MAX_OFFSET.saturating_mul(SCALE as isize);
+
+    #[inline]
+    fn between(from: usize, to: usize) -> Result<Self, OffsetError> {
+        let offset = signed_offset(from, to)?;
+        if offset % SCALE as isize != 0 {
+            return Err(OffsetError::ExceedsStorageRange);
+        }
+        let scaled = offset / SCALE as isize;
+        // Build the underlying offset from a pair of positions that are `scaled` bytes apart
+        let inner = if scaled >= 0 {
+            O::between(0, scaled as usize)?
+        } else {
+            O::between(scaled.unsigned_abs(), 0)?
+        };
+        Ok(Self(inner))
+    }
+
+    #[inline]
+    fn to_isize(&self) -> isize {
+        let offset = self.0.to_isize();
+        debug_assert!(
+            offset.checked_mul(SCALE as isize).is_some(),
+            "scaled offset overflowed an `isize`"
+        );
+        offset.wrapping_mul(SCALE as isize)
+    }
+}
+
 /// Errors that can occur while creating raw relative pointers.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RelPtrError {
     /// The given `from` and `to` positions for the relative pointer failed to form a valid offset.
     ///
-    /// This is probably because the distance between them could not be represented by the offset
-    /// type.
-    OffsetError,
+    /// The contained [`OffsetError`] indicates whether the distance between them overflowed an
+    /// `isize` or could not be represented by the offset type.
+    OffsetError(OffsetError),
+}
+
+impl From<OffsetError> for RelPtrError {
+    #[inline]
+    fn from(e: OffsetError) -> Self {
+        RelPtrError::OffsetError(e)
+    }
+}
+
+impl fmt::Display for RelPtrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RelPtrError::OffsetError(e) => write!(f, "failed to create relative pointer: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RelPtrError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RelPtrError::OffsetError(e) => Some(e),
+        }
+    }
 }
 
 /// An untyped pointer which resolves relative to its position in memory.
@@ -150,12 +836,23 @@ pub enum RelPtrError {
 /// Relative pointers are *relative*, meaning that the pointee can be moved with the target without
 /// invalidating the pointer. However, if either the pointee or the target move independently, the
 /// pointer will be invalidated.
-#[repr(transparent)]
+///
+/// When the `debug_rel_ptr` feature is enabled, raw relative pointers also store a tag alongside
+/// their offset which is checked in debug builds whenever the pointer is resolved. This helps catch
+/// relative pointers that are accessed after their backing buffer has been freed or overwritten.
+#[cfg_attr(not(feature = "debug_rel_ptr"), repr(transparent))]
+#[cfg_attr(feature = "debug_rel_ptr", repr(C))]
 pub struct RawRelPtr<O> {
     offset: O,
+    #[cfg(feature = "debug_rel_ptr")]
+    tag: u32,
     _phantom: PhantomPinned,
 }
 
+/// The tag stored in raw relative pointers when the `debug_rel_ptr` feature is enabled.
+#[cfg(feature = "debug_rel_ptr")]
+const DEBUG_TAG: u32 = 0x7276_6b79;
+
 impl<O: Offset> RawRelPtr<O> {
     /// Attempts to create a new `RawRelPtr` in-place between the given `from` and `to` positions.
     ///
@@ -166,7 +863,77 @@ impl<O: Offset> RawRelPtr<O> {
     #[inline]
     pub unsafe fn try_emplace(from: usize, to: usize, out: *mut Self) -> Result<(), OffsetError> {
         let offset = O::between(from, to)?;
+        Self::emplace_offset(offset, out);
+        Ok(())
+    }
+
+    /// Attempts to create a new `RawRelPtr` in-place between the given `from` and `to` positions,
+    /// verifying that `to` is a multiple of `align`.
+    ///
+    /// # Safety
+    ///
+    /// - `out` must be located at position `from`
+    /// - `to` must be a position within the archive
+    #[inline]
+    pub unsafe fn try_emplace_aligned(
+        from: usize,
+        to: usize,
+        align: usize,
+        out: *mut Self,
+    ) -> Result<(), OffsetError> {
+        let offset = O::between_aligned(from, to, align)?;
+        Self::emplace_offset(offset, out);
+        Ok(())
+    }
+
+    #[inline]
+    unsafe fn emplace_offset(offset: O, out: *mut Self) {
         ptr::addr_of_mut!((*out).offset).write(offset);
+        #[cfg(feature = "debug_rel_ptr")]
+        ptr::addr_of_mut!((*out).tag).write(DEBUG_TAG);
+    }
+
+    /// Returns whether the tag of the relative pointer is intact.
+    #[cfg(feature = "debug_rel_ptr")]
+    #[inline]
+    pub fn is_tag_valid(&self) -> bool {
+        self.tag == DEBUG_TAG
+    }
+
+    #[inline]
+    fn debug_check_tag(&self) {
+        #[cfg(feature = "debug_rel_ptr")]
+        debug_assert_eq!(
+            self.tag, DEBUG_TAG,
+            "relative pointer tag is corrupted, its backing buffer may have been freed"
+        );
+    }
+
+    /// Creates a new null `RawRelPtr` in-place.
+    ///
+    /// A null relative pointer has an offset of 0, which means that it points to itself. Returns an
+    /// error if the offset type can't represent an offset of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use rkyv::{rel_ptr::RawRelPtrNonZeroI32, RawRelPtr};
+    ///
+    /// let mut out = MaybeUninit::<RawRelPtr>::uninit();
+    /// RawRelPtr::emplace_null(&mut out).unwrap();
+    /// let ptr = unsafe { out.assume_init() };
+    /// assert!(ptr.is_null());
+    ///
+    /// let mut out = MaybeUninit::<RawRelPtrNonZeroI32>::uninit();
+    /// assert!(RawRelPtrNonZeroI32::emplace_null(&mut out).is_err());
+    /// ```
+    #[inline]
+    pub fn emplace_null(out: &mut MaybeUninit<Self>) -> Result<(), OffsetError> {
+        let offset = O::between(0, 0)?;
+        unsafe {
+            Self::emplace_offset(offset, out.as_mut_ptr());
+        }
         Ok(())
     }
 
@@ -202,6 +969,8 @@ impl<O: Offset> RawRelPtr<O> {
     }
 
     /// Gets whether the offset of the relative pointer is 0.
+    ///
+    /// This is the null representation written by [`emplace_null`](RawRelPtr::emplace_null).
     #[inline]
     pub fn is_null(&self) -> bool {
         self.offset() == 0
@@ -210,6 +979,7 @@ impl<O: Offset> RawRelPtr<O> {
     /// Calculates the memory address being pointed to by this relative pointer.
     #[inline]
     pub fn as_ptr(&self) -> *const () {
+        self.debug_check_tag();
         unsafe { self.base().offset(self.offset()).cast() }
     }
 
@@ -217,8 +987,54 @@ impl<O: Offset> RawRelPtr<O> {
     /// by this relative pointer.
     #[inline]
     pub fn as_mut_ptr(&mut self) -> *mut () {
+        self.debug_check_tag();
         unsafe { self.base_mut().offset(self.offset()).cast() }
     }
+
+    /// Calculates the memory address being pointed to by this relative pointer and combines it
+    /// with the given metadata to make a typed pointer.
+    ///
+    /// This allows pointer metadata to be stored separately from the relative pointer. The caller
+    /// is responsible for making sure that the metadata is valid for the pointee.
+    #[inline]
+    pub fn with_metadata<T: ArchivePointee + ?Sized>(
+        &self,
+        metadata: T::ArchivedMetadata,
+    ) -> *const T {
+        ptr_meta::from_raw_parts(self.as_ptr(), T::pointer_metadata(&metadata))
+    }
+
+    /// Calculates the memory address being pointed to by this relative pointer, returning `None`
+    /// if it falls outside of the given bounds.
+    ///
+    /// `bounds` must describe the backing buffer that this relative pointer is located in. The
+    /// end of the bounds is considered in-bounds so that pointers to zero-sized values at the end
+    /// of the buffer are accepted. This does not check that the pointee fits within the bounds.
+    #[inline]
+    pub fn try_as_ptr(&self, bounds: Range<*const u8>) -> Option<*const ()> {
+        let target = target_position(self.base() as usize, self.offset())?;
+        if target >= bounds.start as usize && target <= bounds.end as usize {
+            Some(self.base().wrapping_offset(self.offset()).cast())
+        } else {
+            None
+        }
+    }
+
+    /// Returns an unsafe mutable pointer to the memory address being pointed to by this relative
+    /// pointer, returning `None` if it falls outside of the given bounds.
+    ///
+    /// `bounds` must describe the backing buffer that this relative pointer is located in. See
+    /// [`try_as_ptr`](RawRelPtr::try_as_ptr) for more details.
+    #[inline]
+    pub fn try_as_mut_ptr(&mut self, bounds: Range<*mut u8>) -> Option<*mut ()> {
+        let target = target_position(self.base() as usize, self.offset())?;
+        if target >= bounds.start as usize && target <= bounds.end as usize {
+            let offset = self.offset();
+            Some(self.base_mut().wrapping_offset(offset).cast())
+        } else {
+            None
+        }
+    }
 }
 
 impl<O: fmt::Debug> fmt::Debug for RawRelPtr<O> {
@@ -245,6 +1061,9 @@ pub type RawRelPtrI32 = RawRelPtr<Archived<i32>>;
 /// A raw relative pointer that uses an archived `i64` as the underlying offset.
 #[cfg(target_pointer_width = "64")]
 pub type RawRelPtrI64 = RawRelPtr<Archived<i64>>;
+/// A raw relative pointer that uses an archived [`I24`] as the underlying offset.
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+pub type RawRelPtrI24 = RawRelPtr<I24>;
 
 /// A raw relative pointer that uses an archived `u8` as the underlying offset.
 pub type RawRelPtrU8 = RawRelPtr<Archived<u8>>;
@@ -256,8 +1075,53 @@ pub type RawRelPtrU32 = RawRelPtr<Archived<u32>>;
 /// A raw relative pointer that uses an archived `u64` as the underlying offset.
 #[cfg(target_pointer_width = "64")]
 pub type RawRelPtrU64 = RawRelPtr<Archived<u64>>;
+/// A raw relative pointer that uses an archived [`U24`] as the underlying offset.
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+pub type RawRelPtrU24 = RawRelPtr<U24>;
 
-// TODO: implement for NonZero types
+/// A raw relative pointer that uses an archived `NonZeroI8` as the underlying offset.
+///
+/// Nonzero offsets allow the compiler to niche-optimize types like `Option<RawRelPtr>`. The
+/// tradeoff is that a nonzero relative pointer can never point to itself.
+///
+/// # Examples
+///
+/// ```
+/// use core::{mem::size_of, num::NonZeroU32};
+/// use rkyv::{
+///     rel_ptr::{Offset, OffsetError, RawRelPtrNonZeroU32, RawRelPtrU32},
+///     Archived,
+/// };
+///
+/// assert_eq!(size_of::<Option<RawRelPtrNonZeroU32>>(), size_of::<RawRelPtrU32>());
+///
+/// // A nonzero offset can't point to its own position
+/// assert_eq!(
+///     Archived::<NonZeroU32>::between(8, 8),
+///     Err(OffsetError::ExceedsStorageRange),
+/// );
+/// assert_eq!(Archived::<NonZeroU32>::between(8, 12).unwrap().to_isize(), 4);
+/// ```
+pub type RawRelPtrNonZeroI8 = RawRelPtr<Archived<NonZeroI8>>;
+/// A raw relative pointer that uses an archived `NonZeroI16` as the underlying offset.
+pub type RawRelPtrNonZeroI16 = RawRelPtr<Archived<NonZeroI16>>;
+/// A raw relative pointer that uses an archived `NonZeroI32` as the underlying offset.
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+pub type RawRelPtrNonZeroI32 = RawRelPtr<Archived<NonZeroI32>>;
+/// A raw relative pointer that uses an archived `NonZeroI64` as the underlying offset.
+#[cfg(target_pointer_width = "64")]
+pub type RawRelPtrNonZeroI64 = RawRelPtr<Archived<NonZeroI64>>;
+
+/// A raw relative pointer that uses an archived `NonZeroU8` as the underlying offset.
+pub type RawRelPtrNonZeroU8 = RawRelPtr<Archived<NonZeroU8>>;
+/// A raw relative pointer that uses an archived `NonZeroU16` as the underlying offset.
+pub type RawRelPtrNonZeroU16 = RawRelPtr<Archived<NonZeroU16>>;
+/// A raw relative pointer that uses an archived `NonZeroU32` as the underlying offset.
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+pub type RawRelPtrNonZeroU32 = RawRelPtr<Archived<NonZeroU32>>;
+/// A raw relative pointer that uses an archived `NonZeroU64` as the underlying offset.
+#[cfg(target_pointer_width = "64")]
+pub type RawRelPtrNonZeroU64 = RawRelPtr<Archived<NonZeroU64>>;
 
 /// A pointer which resolves to relative to its position in memory.
 ///
@@ -273,6 +1137,8 @@ pub struct RelPtr<T: ArchivePointee + ?Sized, O> {
 impl<T, O: Offset> RelPtr<T, O> {
     /// Attempts to create a relative pointer from one position to another.
     ///
+    /// Returns [`OffsetError::Misaligned`] if `to` is not aligned for `T`.
+    ///
     /// # Safety
     ///
     /// - `from` must be the position of `out` within the archive
@@ -281,7 +1147,7 @@ impl<T, O: Offset> RelPtr<T, O> {
     pub unsafe fn try_emplace(from: usize, to: usize, out: *mut Self) -> Result<(), OffsetError> {
         let (fp, fo) = out_field!(out.raw_ptr);
         // Skip metadata since sized T is guaranteed to be ()
-        RawRelPtr::try_emplace(from + fp, to, fo)
+        RawRelPtr::try_emplace_aligned(from + fp, to, align_of::<T>(), fo)
     }
 
     /// Creates a relative pointer from one position to another.
@@ -290,6 +1156,7 @@ impl<T, O: Offset> RelPtr<T, O> {
     ///
     /// - The offset between `from` and `to` does not fit in an `isize`
     /// - The offset between `from` and `to` exceeds the offset storage
+    /// - `to` is not aligned for `T`
     ///
     /// # Safety
     ///
@@ -297,7 +1164,7 @@ impl<T, O: Offset> RelPtr<T, O> {
     /// - `to` must be the position of some valid `T`
     #[inline]
     pub unsafe fn emplace(from: usize, to: usize, out: *mut Self) {
-        Self::try_emplace(from, to, out).unwrap();
+        Self::unwrap_emplace(Self::try_emplace(from, to, out));
     }
 }
 
@@ -307,13 +1174,32 @@ where
 {
     /// Attempts to create a null relative pointer with default metadata.
     ///
+    /// A null relative pointer has an offset of 0 (it points to itself) and must never be
+    /// dereferenced through [`as_ptr`](RelPtr::as_ptr) or [`as_mut_ptr`](RelPtr::as_mut_ptr).
+    /// Returns an error if the offset type can't represent an offset of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use rkyv::RelPtr;
+    ///
+    /// let mut out = MaybeUninit::<RelPtr<[i32]>>::uninit();
+    /// unsafe {
+    ///     RelPtr::try_emplace_null(0, out.as_mut_ptr()).unwrap();
+    /// }
+    /// let ptr = unsafe { out.assume_init() };
+    /// assert!(ptr.is_null());
+    /// assert!(ptr.is_canonical_null());
+    /// ```
+    ///
     /// # Safety
     ///
     /// `pos` must be the position of `out` within the archive.
     #[inline]
     pub unsafe fn try_emplace_null(pos: usize, out: *mut Self) -> Result<(), OffsetError> {
         let (fp, fo) = out_field!(out.raw_ptr);
-        RawRelPtr::try_emplace(pos + fp, pos, fo)?;
+        RawRelPtr::try_emplace(pos + fp, pos + fp, fo)?;
         let (_, fo) = out_field!(out.metadata);
         fo.write(Default::default());
         Ok(())
@@ -321,6 +1207,9 @@ where
 
     /// Creates a null relative pointer with default metadata.
     ///
+    /// The created relative pointer must never be dereferenced. See
+    /// [`try_emplace_null`](RelPtr::try_emplace_null) for more details.
+    ///
     /// # Panics
     ///
     /// - An offset of `0` does not fit in an `isize`
@@ -333,9 +1222,38 @@ where
     pub unsafe fn emplace_null(pos: usize, out: *mut Self) {
         Self::try_emplace_null(pos, out).unwrap()
     }
+
+    /// Gets whether the relative pointer is a canonical null pointer, i.e. whether its offset is 0
+    /// and its metadata is the default archived metadata.
+    ///
+    /// This is the representation written by [`emplace_null`](RelPtr::emplace_null) and
+    /// [`try_emplace_null`](RelPtr::try_emplace_null). Unlike [`is_null`](RelPtr::is_null), this
+    /// also checks the metadata.
+    #[inline]
+    pub fn is_canonical_null(&self) -> bool
+    where
+        T::ArchivedMetadata: PartialEq,
+    {
+        self.is_null() && self.metadata == T::ArchivedMetadata::default()
+    }
 }
 
 impl<T: ArchivePointee + ?Sized, O: Offset> RelPtr<T, O> {
+    /// Unwraps the result of creating a relative pointer.
+    ///
+    /// With the `debug_serialize` feature, failures name the type and field being resolved. They
+    /// are returned as errors from [`serialize_value`](crate::ser::breadcrumb::serialize_value)
+    /// instead of panicking.
+    #[inline]
+    fn unwrap_emplace(result: Result<(), OffsetError>) {
+        #[cfg(feature = "debug_serialize")]
+        if let Err(error) = result {
+            crate::ser::breadcrumb::offset_failed(error, core::any::type_name::<T>());
+        }
+        #[cfg(not(feature = "debug_serialize"))]
+        result.unwrap();
+    }
+
     /// Attempts to create a relative pointer from one position to another.
     ///
     /// # Safety
@@ -380,7 +1298,13 @@ impl<T: ArchivePointee + ?Sized, O: Offset> RelPtr<T, O> {
         metadata_resolver: U::MetadataResolver,
         out: *mut Self,
     ) {
-        Self::try_resolve_emplace(from, to, value, metadata_resolver, out).unwrap();
+        Self::unwrap_emplace(Self::try_resolve_emplace(
+            from,
+            to,
+            value,
+            metadata_resolver,
+            out,
+        ));
     }
 
     /// Gets the base pointer for the relative pointer.
@@ -389,6 +1313,13 @@ impl<T: ArchivePointee + ?Sized, O: Offset> RelPtr<T, O> {
         self.raw_ptr.base()
     }
 
+    /// Gets the underlying raw relative pointer.
+    #[cfg(feature = "debug_rel_ptr")]
+    #[inline]
+    pub(crate) fn raw_ptr(&self) -> &RawRelPtr<O> {
+        &self.raw_ptr
+    }
+
     /// Gets the mutable base pointer for the relative pointer.
     #[inline]
     pub fn base_mut(&mut self) -> *mut u8 {
@@ -402,6 +1333,8 @@ impl<T: ArchivePointee + ?Sized, O: Offset> RelPtr<T, O> {
     }
 
     /// Gets whether the offset of the relative pointer is 0.
+    ///
+    /// A null relative pointer must never be dereferenced.
     #[inline]
     pub fn is_null(&self) -> bool {
         self.raw_ptr.is_null()
@@ -413,6 +1346,21 @@ impl<T: ArchivePointee + ?Sized, O: Offset> RelPtr<T, O> {
         &self.metadata
     }
 
+    /// Gets the mutable metadata of the relative pointer.
+    ///
+    /// This can be used to edit archives in-place, for example to shrink the length of an archived
+    /// slice without reserializing it.
+    ///
+    /// # Safety
+    ///
+    /// Any new metadata must describe a valid `T` that lives entirely within the original
+    /// allocation of the pointee. In practice, shrinking the pointee (e.g. reducing a slice length)
+    /// is the only safe modification.
+    #[inline]
+    pub unsafe fn metadata_mut(&mut self) -> &mut T::ArchivedMetadata {
+        &mut self.metadata
+    }
+
     /// Calculates the memory address being pointed to by this relative pointer.
     #[inline]
     pub fn as_ptr(&self) -> *const T {
@@ -430,6 +1378,88 @@ impl<T: ArchivePointee + ?Sized, O: Offset> RelPtr<T, O> {
     }
 }
 
+impl<T, O: Offset> RelPtr<[T], O> {
+    /// Calculates the memory address being pointed to by this relative pointer, returning `None`
+    /// if the pointed-to slice does not lie entirely within `archive`.
+    ///
+    /// This checks that the relative pointer is located in `archive`, that the start of the slice
+    /// is aligned for `T`, and that all `len * size_of::<T>()` bytes of the slice are within
+    /// `archive`. It does not check that the elements of the slice are valid.
+    #[inline]
+    pub fn checked_as_ptr(&self, archive: &[u8]) -> Option<*const [T]> {
+        let bounds = archive.as_ptr_range();
+        let base = self.base();
+        if base < bounds.start || base >= bounds.end {
+            return None;
+        }
+
+        let start = target_position(base as usize, self.offset())?;
+        let len = <[T]>::pointer_metadata(&self.metadata);
+        let end = start.checked_add(len.checked_mul(size_of::<T>())?)?;
+        if start < bounds.start as usize
+            || end > bounds.end as usize
+            || start % align_of::<T>() != 0
+        {
+            None
+        } else {
+            Some(self.as_ptr())
+        }
+    }
+}
+
+impl<T: ArchivePointee + fmt::Debug + ?Sized, O: Offset> RelPtr<T, O> {
+    /// Returns a value that formats the pointee of this relative pointer with its `Debug`
+    /// implementation, or `null` if the relative pointer is null.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use rkyv::RelPtr;
+    ///
+    /// #[repr(C)]
+    /// struct Example {
+    ///     ptr: RelPtr<i32>,
+    ///     value: i32,
+    /// }
+    ///
+    /// let mut example = MaybeUninit::<Example>::uninit();
+    /// let out = example.as_mut_ptr();
+    /// let (ptr_pos, ptr_out) = rkyv::out_field!(out.ptr);
+    /// let (value_pos, value_out) = rkyv::out_field!(out.value);
+    /// unsafe {
+    ///     value_out.write(42);
+    ///     RelPtr::emplace(ptr_pos, value_pos, ptr_out);
+    /// }
+    /// let example = unsafe { example.assume_init() };
+    ///
+    /// let debug = unsafe { example.ptr.debug_pointee() };
+    /// assert_eq!(format!("{:?}", debug), "42");
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The relative pointer must be null or point to a valid `T`.
+    #[inline]
+    pub unsafe fn debug_pointee(&self) -> impl fmt::Debug + '_ {
+        DebugPointee(self)
+    }
+}
+
+struct DebugPointee<'a, T: ArchivePointee + ?Sized, O>(&'a RelPtr<T, O>);
+
+impl<T: ArchivePointee + fmt::Debug + ?Sized, O: Offset> fmt::Debug for DebugPointee<'_, T, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_null() {
+            write!(f, "null")
+        } else {
+            // SAFETY: The caller of `debug_pointee` guaranteed that the relative pointer points to
+            // a valid `T`.
+            unsafe { (*self.0.as_ptr()).fmt(f) }
+        }
+    }
+}
+
 impl<T: ArchivePointee + ?Sized, O: fmt::Debug> fmt::Debug for RelPtr<T, O>
 where
     T::ArchivedMetadata: fmt::Debug,
@@ -447,3 +1477,81 @@ impl<T: ArchivePointee + ?Sized, O: Offset> fmt::Pointer for RelPtr<T, O> {
         fmt::Pointer::fmt(&self.as_ptr(), f)
     }
 }
+
+/// An iterator that follows a chain of relative pointers until it reaches a null pointer.
+///
+/// This is useful for walking linked structures in an archive, like linked lists. Each step
+/// dereferences the current relative pointer and uses a function to get the next relative pointer
+/// from the pointee.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::rel_ptr::RelPtrIter;
+/// # use rkyv::RelPtr;
+/// # struct Node { value: i32, next: RelPtr<Node> }
+///
+/// fn sum(head: &RelPtr<Node>) -> i32 {
+///     // Nodes are guaranteed to be valid and the list is guaranteed to be acyclic
+///     unsafe { RelPtrIter::new(head, |node| &node.next) }
+///         .map(|node| node.value)
+///         .sum()
+/// }
+/// ```
+pub struct RelPtrIter<'a, T: ArchivePointee + ?Sized, O> {
+    current: Option<&'a RelPtr<T, O>>,
+    next: fn(&'a T) -> &'a RelPtr<T, O>,
+    remaining: Option<usize>,
+}
+
+impl<'a, T: ArchivePointee + ?Sized, O: Offset> RelPtrIter<'a, T, O> {
+    /// Creates a new iterator that starts at the given relative pointer and gets each following
+    /// relative pointer with `next`.
+    ///
+    /// # Safety
+    ///
+    /// Every non-null relative pointer reached by the iterator must point to a valid `T` that lives
+    /// at least as long as `'a`.
+    #[inline]
+    pub unsafe fn new(start: &'a RelPtr<T, O>, next: fn(&'a T) -> &'a RelPtr<T, O>) -> Self {
+        Self {
+            current: Some(start),
+            next,
+            remaining: None,
+        }
+    }
+
+    /// Limits the iterator to yield at most `max_steps` values.
+    ///
+    /// This can be used to guard against cycles in the pointer chain.
+    #[inline]
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.remaining = Some(max_steps);
+        self
+    }
+}
+
+impl<'a, T: ArchivePointee + ?Sized, O: Offset> Iterator for RelPtrIter<'a, T, O> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(remaining) = self.remaining.as_mut() {
+            if *remaining == 0 {
+                return None;
+            }
+            *remaining -= 1;
+        }
+
+        let current = self.current.take()?;
+        if current.is_null() {
+            None
+        } else {
+            // SAFETY: The caller of `new` guaranteed that every non-null relative pointer reached
+            // by the iterator points to a valid `T`.
+            let value = unsafe { &*current.as_ptr() };
+            self.current = Some((self.next)(value));
+            Some(value)
+        }
+    }
+}
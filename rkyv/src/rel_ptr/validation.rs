@@ -1,16 +1,212 @@
 //! Validation implementations for relative pointers
 
 use crate::{
-    rel_ptr::{Offset, RawRelPtr, RelPtr},
+    rel_ptr::{
+        BigEndianOffset, LittleEndianOffset, Offset, RawRelPtr, RelPtr, ScaledOffset, VarintOffset,
+    },
     ArchivePointee, Fallible,
 };
 use bytecheck::CheckBytes;
 use core::{
     convert::Infallible,
+    fmt,
     marker::{PhantomData, PhantomPinned},
     ptr,
 };
 
+/// An error resulting from an invalid varint offset.
+///
+/// Varint offsets must terminate within their buffer and fit in an `isize`.
+#[derive(Debug)]
+pub struct VarintOffsetError;
+
+impl fmt::Display for VarintOffsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "varint offset is unterminated or overflows an `isize`")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VarintOffsetError {}
+
+/// Errors that can occur while checking a scaled offset.
+#[derive(Debug)]
+pub enum ScaledOffsetError<E> {
+    /// The underlying offset was invalid
+    OffsetCheckBytesError(E),
+    /// The underlying offset overflowed an `isize` after scaling
+    Overflow {
+        /// The underlying unscaled offset
+        offset: isize,
+        /// The scale of the offset
+        scale: usize,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ScaledOffsetError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaledOffsetError::OffsetCheckBytesError(e) => write!(f, "offset check error: {}", e),
+            ScaledOffsetError::Overflow { offset, scale } => write!(
+                f,
+                "offset {} scaled by {} overflows an `isize`",
+                offset, scale
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for ScaledOffsetError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScaledOffsetError::OffsetCheckBytesError(e) => Some(e as &dyn std::error::Error),
+            ScaledOffsetError::Overflow { .. } => None,
+        }
+    }
+}
+
+type RelPtrCheckBytesError<T, O, C> = RelPtrCheckError<
+    <O as CheckBytes<C>>::Error,
+    <<T as ArchivePointee>::ArchivedMetadata as CheckBytes<C>>::Error,
+>;
+
+/// Errors that can occur while checking a relative pointer with a fallible offset type.
+#[derive(Debug)]
+pub enum RelPtrCheckError<O, M> {
+    /// The offset of the relative pointer was invalid
+    OffsetCheckBytesError(O),
+    /// The metadata of the relative pointer was invalid
+    MetadataCheckBytesError(M),
+}
+
+impl<O: fmt::Display, M: fmt::Display> fmt::Display for RelPtrCheckError<O, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RelPtrCheckError::OffsetCheckBytesError(e) => write!(f, "offset check error: {}", e),
+            RelPtrCheckError::MetadataCheckBytesError(e) => {
+                write!(f, "metadata check error: {}", e)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<O, M> std::error::Error for RelPtrCheckError<O, M>
+where
+    O: std::error::Error + 'static,
+    M: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RelPtrCheckError::OffsetCheckBytesError(e) => Some(e as &dyn std::error::Error),
+            RelPtrCheckError::MetadataCheckBytesError(e) => Some(e as &dyn std::error::Error),
+        }
+    }
+}
+
+impl<C: Fallible + ?Sized, const N: usize> CheckBytes<C> for VarintOffset<N> {
+    type Error = VarintOffsetError;
+
+    #[inline]
+    unsafe fn check_bytes<'a>(value: *const Self, _: &mut C) -> Result<&'a Self, Self::Error> {
+        let offset = &*value;
+        offset.decode().ok_or(VarintOffsetError)?;
+        Ok(offset)
+    }
+}
+
+macro_rules! impl_check_bytes_endian_offset {
+    ($ty:ty) => {
+        impl<C: Fallible + ?Sized> CheckBytes<C> for LittleEndianOffset<$ty> {
+            type Error = Infallible;
+
+            #[inline]
+            unsafe fn check_bytes<'a>(
+                value: *const Self,
+                _: &mut C,
+            ) -> Result<&'a Self, Self::Error> {
+                // All bit patterns are valid
+                Ok(&*value)
+            }
+        }
+
+        impl<C: Fallible + ?Sized> CheckBytes<C> for BigEndianOffset<$ty> {
+            type Error = Infallible;
+
+            #[inline]
+            unsafe fn check_bytes<'a>(
+                value: *const Self,
+                _: &mut C,
+            ) -> Result<&'a Self, Self::Error> {
+                // All bit patterns are valid
+                Ok(&*value)
+            }
+        }
+    };
+}
+
+impl_check_bytes_endian_offset!(i16);
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_check_bytes_endian_offset!(i32);
+#[cfg(target_pointer_width = "64")]
+impl_check_bytes_endian_offset!(i64);
+impl_check_bytes_endian_offset!(u16);
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_check_bytes_endian_offset!(u32);
+#[cfg(target_pointer_width = "64")]
+impl_check_bytes_endian_offset!(u64);
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+const _: () = {
+    use crate::rel_ptr::{I24, U24};
+
+    impl<C: Fallible + ?Sized> CheckBytes<C> for I24 {
+        type Error = Infallible;
+
+        #[inline]
+        unsafe fn check_bytes<'a>(value: *const Self, _: &mut C) -> Result<&'a Self, Self::Error> {
+            // All bit patterns are valid
+            Ok(&*value)
+        }
+    }
+
+    impl<C: Fallible + ?Sized> CheckBytes<C> for U24 {
+        type Error = Infallible;
+
+        #[inline]
+        unsafe fn check_bytes<'a>(value: *const Self, _: &mut C) -> Result<&'a Self, Self::Error> {
+            // All bit patterns are valid
+            Ok(&*value)
+        }
+    }
+};
+
+impl<O, C, const SCALE: usize> CheckBytes<C> for ScaledOffset<O, SCALE>
+where
+    O: CheckBytes<C> + Offset,
+    C: Fallible + ?Sized,
+{
+    type Error = ScaledOffsetError<O::Error>;
+
+    #[inline]
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        let offset = O::check_bytes(value.cast::<O>(), context)
+            .map_err(ScaledOffsetError::OffsetCheckBytesError)?
+            .to_isize();
+        if offset.checked_mul(SCALE as isize).is_none() {
+            return Err(ScaledOffsetError::Overflow {
+                offset,
+                scale: SCALE,
+            });
+        }
+        Ok(&*value)
+    }
+}
+
 impl<O: Offset> RawRelPtr<O> {
     /// Checks the bytes of the given raw relative pointer.
     ///
@@ -25,11 +221,13 @@ impl<O: Offset> RawRelPtr<O> {
     pub unsafe fn manual_check_bytes<'a, C: Fallible + ?Sized>(
         value: *const Self,
         context: &mut C,
-    ) -> Result<&'a Self, Infallible>
+    ) -> Result<&'a Self, O::Error>
     where
         O: CheckBytes<C>,
     {
-        O::check_bytes(ptr::addr_of!((*value).offset), context).unwrap();
+        O::check_bytes(ptr::addr_of!((*value).offset), context)?;
+        #[cfg(feature = "debug_rel_ptr")]
+        u32::check_bytes(ptr::addr_of!((*value).tag), context).unwrap();
         PhantomPinned::check_bytes(ptr::addr_of!((*value)._phantom), context).unwrap();
         Ok(&*value)
     }
@@ -45,17 +243,46 @@ impl<T: ArchivePointee + ?Sized, O: Offset> RelPtr<T, O> {
     /// # Safety
     ///
     /// The given pointer must be aligned and point to enough bytes to represent a `RelPtr<T>`.
+    ///
+    /// This is only available for offset types that are always valid. Use
+    /// [`manual_check_bytes_with_offset`](RelPtr::manual_check_bytes_with_offset) to check relative
+    /// pointers with fallible offset types like [`VarintOffset`].
     #[inline]
     pub unsafe fn manual_check_bytes<'a, C: Fallible + ?Sized>(
         value: *const Self,
         context: &mut C,
     ) -> Result<&'a Self, <T::ArchivedMetadata as CheckBytes<C>>::Error>
+    where
+        O: CheckBytes<C, Error = Infallible>,
+        T::ArchivedMetadata: CheckBytes<C>,
+    {
+        Self::manual_check_bytes_with_offset(value, context).map_err(|e| match e {
+            RelPtrCheckError::OffsetCheckBytesError(e) => match e {},
+            RelPtrCheckError::MetadataCheckBytesError(e) => e,
+        })
+    }
+
+    /// Checks the bytes of the given relative pointer, including its offset.
+    ///
+    /// Unlike [`manual_check_bytes`](RelPtr::manual_check_bytes), this returns an error if the
+    /// offset itself is invalid.
+    ///
+    /// # Safety
+    ///
+    /// The given pointer must be aligned and point to enough bytes to represent a `RelPtr<T>`.
+    #[inline]
+    pub unsafe fn manual_check_bytes_with_offset<'a, C: Fallible + ?Sized>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, RelPtrCheckBytesError<T, O, C>>
     where
         O: CheckBytes<C>,
         T::ArchivedMetadata: CheckBytes<C>,
     {
-        RawRelPtr::manual_check_bytes(ptr::addr_of!((*value).raw_ptr), context).unwrap();
-        T::ArchivedMetadata::check_bytes(ptr::addr_of!((*value).metadata), context)?;
+        RawRelPtr::manual_check_bytes(ptr::addr_of!((*value).raw_ptr), context)
+            .map_err(RelPtrCheckError::OffsetCheckBytesError)?;
+        T::ArchivedMetadata::check_bytes(ptr::addr_of!((*value).metadata), context)
+            .map_err(RelPtrCheckError::MetadataCheckBytesError)?;
         PhantomData::<T>::check_bytes(ptr::addr_of!((*value)._phantom), context).unwrap();
         Ok(&*value)
     }
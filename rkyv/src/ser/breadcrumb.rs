@@ -0,0 +1,207 @@
+//! Field breadcrumbs for reporting which field failed to resolve.
+//!
+//! When the `debug_serialize` feature is enabled, derived [`Archive`](crate::Archive)
+//! implementations record the field they are resolving. If creating a relative pointer fails
+//! because the offset doesn't fit in the offset type, the error names the innermost field being
+//! resolved instead of just reporting that some offset was too far.
+//!
+//! Use [`serialize_value`] to get these failures back as a [`ResolveOffsetError`]. Outside of it,
+//! they panic with the same message.
+
+use crate::{rel_ptr::OffsetError, ser::Serializer, Serialize};
+use core::fmt;
+use std::{cell::RefCell, error::Error, vec::Vec};
+
+/// A field that is currently being resolved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Breadcrumb {
+    /// The name of the field
+    pub field: &'static str,
+    /// The type name of the field
+    pub type_name: &'static str,
+}
+
+std::thread_local! {
+    static BREADCRUMBS: RefCell<Vec<Breadcrumb>> = RefCell::new(Vec::new());
+    // `None` when offset errors should panic, otherwise the first offset error captured by
+    // `serialize_value`
+    static CAPTURED: RefCell<Option<Option<ResolveOffsetError>>> = RefCell::new(None);
+}
+
+/// A guard that removes a breadcrumb when dropped.
+///
+/// Returned by [`enter`].
+#[must_use]
+pub struct BreadcrumbGuard {
+    _private: (),
+}
+
+impl Drop for BreadcrumbGuard {
+    #[inline]
+    fn drop(&mut self) {
+        BREADCRUMBS.with(|b| {
+            b.borrow_mut().pop();
+        });
+    }
+}
+
+/// Records that the given field is being resolved until the returned guard is dropped.
+#[inline]
+pub fn enter(field: &'static str, type_name: &'static str) -> BreadcrumbGuard {
+    BREADCRUMBS.with(|b| b.borrow_mut().push(Breadcrumb { field, type_name }));
+    BreadcrumbGuard { _private: () }
+}
+
+/// Returns the innermost field currently being resolved, if any.
+#[inline]
+pub fn current() -> Option<Breadcrumb> {
+    BREADCRUMBS.with(|b| b.borrow().last().copied())
+}
+
+/// An [`OffsetError`] annotated with the field that was being resolved when it occurred.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResolveOffsetError {
+    /// The underlying offset error
+    pub error: OffsetError,
+    /// The type name of the relative pointer's pointee
+    pub pointee: &'static str,
+    /// The innermost field being resolved, if any
+    pub field: Option<Breadcrumb>,
+}
+
+impl ResolveOffsetError {
+    /// Annotates an offset error with the pointee type name and the current breadcrumb.
+    #[inline]
+    pub fn new(error: OffsetError, pointee: &'static str) -> Self {
+        Self {
+            error,
+            pointee,
+            field: current(),
+        }
+    }
+}
+
+impl fmt::Display for ResolveOffsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.field {
+            Some(field) => write!(f, "offset for field `{}: {}`", field.field, field.type_name)?,
+            None => write!(f, "offset for `{}`", self.pointee)?,
+        }
+        match self.error {
+            OffsetError::IsizeOverflow => write!(f, " overflows the range of `isize`"),
+            OffsetError::ExceedsStorageRange => write!(f, " exceeds storage range"),
+            OffsetError::Misaligned => write!(f, " is not aligned for the pointee type"),
+        }
+    }
+}
+
+impl Error for ResolveOffsetError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Reports an offset error with the context of the field being resolved.
+///
+/// Inside [`serialize_value`], the first error is recorded and returned once serialization
+/// finishes. Otherwise, this panics.
+#[cold]
+#[inline(never)]
+pub(crate) fn offset_failed(error: OffsetError, pointee: &'static str) {
+    let error = ResolveOffsetError::new(error, pointee);
+    let captured = CAPTURED.with(|c| match &mut *c.borrow_mut() {
+        Some(captured) => {
+            if captured.is_none() {
+                *captured = Some(error);
+            }
+            true
+        }
+        None => false,
+    });
+    if !captured {
+        panic!("{}", error);
+    }
+}
+
+/// An error returned by [`serialize_value`].
+#[derive(Debug)]
+pub enum DebugSerializeError<E> {
+    /// The serializer returned an error
+    Serialize(E),
+    /// A relative pointer could not be created
+    Offset(ResolveOffsetError),
+}
+
+impl<E: fmt::Display> fmt::Display for DebugSerializeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DebugSerializeError::Serialize(e) => write!(f, "serialization error: {}", e),
+            DebugSerializeError::Offset(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for DebugSerializeError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DebugSerializeError::Serialize(e) => Some(e as &dyn Error),
+            DebugSerializeError::Offset(e) => Some(e as &dyn Error),
+        }
+    }
+}
+
+struct CaptureGuard {
+    previous: Option<Option<ResolveOffsetError>>,
+}
+
+impl Drop for CaptureGuard {
+    #[inline]
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CAPTURED.with(|c| *c.borrow_mut() = previous);
+    }
+}
+
+/// Archives the given value like [`Serializer::serialize_value`], returning offset errors instead
+/// of panicking.
+///
+/// If a relative pointer can't be created, the returned [`ResolveOffsetError`] names the innermost
+/// field being resolved. The bytes written to the serializer are not a valid archive in that case
+/// and should be discarded.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::{
+///     ser::{breadcrumb, serializers::AllocSerializer},
+///     Archive, Serialize,
+/// };
+///
+/// #[derive(Archive, Serialize)]
+/// struct Example {
+///     name: String,
+/// }
+///
+/// let mut serializer = AllocSerializer::<256>::default();
+/// let value = Example {
+///     name: "pi".to_string(),
+/// };
+/// breadcrumb::serialize_value(&mut serializer, &value).unwrap();
+/// ```
+pub fn serialize_value<S, T>(
+    serializer: &mut S,
+    value: &T,
+) -> Result<usize, DebugSerializeError<S::Error>>
+where
+    S: Serializer + ?Sized,
+    T: Serialize<S>,
+{
+    let _guard = CaptureGuard {
+        previous: CAPTURED.with(|c| c.replace(Some(None))),
+    };
+    let result = serializer.serialize_value(value);
+    if let Some(Some(error)) = CAPTURED.with(|c| c.replace(Some(None))) {
+        return Err(DebugSerializeError::Offset(error));
+    }
+    result.map_err(DebugSerializeError::Serialize)
+}
@@ -1,9 +1,11 @@
 //! Serialization traits, serializers, and adapters.
 
+#[cfg(feature = "debug_serialize")]
+pub mod breadcrumb;
 pub mod serializers;
 
 use crate::{Archive, ArchiveUnsized, Fallible, RelPtr, Serialize, SerializeUnsized};
-use core::{alloc::Layout, mem, ptr::NonNull, slice};
+use core::{alloc::Layout, cmp, mem, ptr::NonNull, slice};
 
 /// A byte sink that knows where it is.
 ///
@@ -20,17 +22,40 @@ pub trait Serializer: Fallible {
     /// Attempts to write the given bytes to the serializer.
     fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
 
-    /// Advances the given number of bytes as padding.
+    /// Advances the given number of bytes as padding by writing zeroes.
     #[inline]
-    fn pad(&mut self, padding: usize) -> Result<(), Self::Error> {
+    fn pad(&mut self, mut padding: usize) -> Result<(), Self::Error> {
         const MAX_ZEROES: usize = 32;
         const ZEROES: [u8; MAX_ZEROES] = [0; MAX_ZEROES];
-        debug_assert!(padding < MAX_ZEROES);
 
-        self.write(&ZEROES[0..padding])
+        while padding > 0 {
+            let len = cmp::min(padding, MAX_ZEROES);
+            self.write(&ZEROES[0..len])?;
+            padding -= len;
+        }
+        Ok(())
     }
 
-    /// Aligns the position of the serializer to the given alignment.
+    /// Aligns the position of the serializer to the given alignment by padding it with zeroes, and
+    /// returns the new position.
+    ///
+    /// `align` must be a power of two. This is useful in manual `Serialize` implementations that
+    /// write archived data directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rkyv::{
+    ///     ser::{serializers::AlignedSerializer, Serializer},
+    ///     AlignedVec,
+    /// };
+    ///
+    /// let mut serializer = AlignedSerializer::new(AlignedVec::new());
+    /// serializer.write(&[1]).unwrap();
+    /// assert_eq!(serializer.align(8).unwrap(), 8);
+    /// assert_eq!(serializer.align(8).unwrap(), 8);
+    /// assert_eq!(serializer.into_inner().as_slice(), &[1, 0, 0, 0, 0, 0, 0, 0]);
+    /// ```
     #[inline]
     fn align(&mut self, align: usize) -> Result<usize, Self::Error> {
         let mask = align - 1;
@@ -40,7 +65,10 @@ pub trait Serializer: Fallible {
         Ok(self.pos())
     }
 
-    /// Aligns the position of the serializer to be suitable to write the given type.
+    /// Aligns the position of the serializer to be suitable to write the given type, and returns
+    /// the new position.
+    ///
+    /// This is equivalent to calling [`align`](Serializer::align) with the alignment of `T`.
     #[inline]
     fn align_for<T>(&mut self) -> Result<usize, Self::Error> {
         self.align(mem::align_of::<T>())
@@ -119,6 +147,17 @@ pub trait Serializer: Fallible {
         self.align_for::<RelPtr<T::Archived>>()?;
         unsafe { self.resolve_unsized_aligned(value, to, metadata_resolver) }
     }
+
+    /// Writes the bytes of the given string and returns the position they were written at.
+    ///
+    /// This is used to serialize the contents of strings. Serializers may override it to reuse
+    /// the bytes of a previously-serialized string instead of writing them again.
+    #[inline]
+    fn serialize_str(&mut self, value: &str) -> Result<usize, Self::Error> {
+        let result = self.pos();
+        self.write(value.as_bytes())?;
+        Ok(result)
+    }
 }
 
 // Someday this can probably be replaced with alloc::Allocator
@@ -1,6 +1,10 @@
 use crate::{
-    ser::{serializers::BufferScratch, ScratchSpace, Serializer, SharedSerializeRegistry},
-    AlignedBytes, AlignedVec, Archive, ArchiveUnsized, Fallible, RelPtr,
+    ser::{
+        serializers::{BufferScratch, CompositeSerializer, CompositeSerializerError},
+        ScratchSpace, Serializer, SharedSerializeRegistry,
+    },
+    AlignedBytes, AlignedVec, Archive, ArchiveUnsized, Fallible, RelPtr, Serialize,
+    SerializeUnsized,
 };
 #[cfg(not(feature = "std"))]
 use ::alloc::{alloc, boxed::Box, vec::Vec};
@@ -323,6 +327,280 @@ impl ScratchSpace for AllocScratch {
     }
 }
 
+/// A policy that decides how much memory a [`BumpScratch`] allocates when it runs out of space.
+///
+/// This is implemented for [`LinearGrowth`] and for closures of the form
+/// `FnMut(capacity, required) -> usize`.
+pub trait ScratchGrowth {
+    /// Returns the size of the next chunk to allocate.
+    ///
+    /// `capacity` is the total size of all the chunks that have been allocated so far, and
+    /// `required` is the minimum size of the next chunk. If the returned size is less than
+    /// `required`, `required` is used instead.
+    fn next_chunk_size(&mut self, capacity: usize, required: usize) -> usize;
+}
+
+impl<F: FnMut(usize, usize) -> usize> ScratchGrowth for F {
+    #[inline]
+    fn next_chunk_size(&mut self, capacity: usize, required: usize) -> usize {
+        self(capacity, required)
+    }
+}
+
+/// A scratch growth policy that allocates chunks of a fixed size.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearGrowth(pub usize);
+
+impl ScratchGrowth for LinearGrowth {
+    #[inline]
+    fn next_chunk_size(&mut self, _: usize, _: usize) -> usize {
+        self.0
+    }
+}
+
+// (ptr, layout, chunk index and chunk used before the allocation)
+// Zero-sized allocations aren't made in a chunk and don't have a chunk index.
+type BumpAllocation = (*mut u8, Layout, Option<(usize, usize)>);
+
+#[derive(Debug)]
+struct BumpChunk {
+    ptr: *mut u8,
+    layout: Layout,
+    used: usize,
+}
+
+/// Scratch space that bump allocates from chunks of memory allocated with the global allocator.
+///
+/// When the current chunk is full, a new chunk is allocated with a size chosen by the growth policy
+/// `G`. Chunks that become empty are kept so that they can be reused by later allocations. An
+/// optional limit bounds the total size of all the chunks, and allocations that
+/// would exceed it fail instead of allocating more memory.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::ser::{
+///     serializers::{AlignedSerializer, BumpScratch, CompositeSerializer, LinearGrowth},
+///     Serializer,
+/// };
+/// use rkyv::{AlignedVec, Infallible};
+///
+/// let mut serializer = CompositeSerializer::new(
+///     AlignedSerializer::new(AlignedVec::new()),
+///     BumpScratch::with_limit(1024, LinearGrowth(1024), 8192),
+///     Infallible,
+/// );
+/// serializer.serialize_value(&vec![vec![1u32, 2, 3], vec![4, 5, 6]]).unwrap();
+///
+/// let (_, scratch, _) = serializer.into_components();
+/// assert!(scratch.peak_bytes() > 0);
+/// assert_eq!(scratch.capacity(), 1024);
+/// ```
+#[derive(Debug)]
+pub struct BumpScratch<G> {
+    growth: G,
+    limit: Option<usize>,
+    chunks: Vec<BumpChunk>,
+    current: usize,
+    capacity: usize,
+    used: usize,
+    peak: usize,
+    allocations: Vec<BumpAllocation>,
+}
+
+// SAFETY: BumpScratch is safe to send to another thread
+// This trait is not automatically implemented because the struct contains a pointer
+unsafe impl<G: Send> Send for BumpScratch<G> {}
+
+// SAFETY: BumpScratch is safe to share between threads
+// This trait is not automatically implemented because the struct contains a pointer
+unsafe impl<G: Sync> Sync for BumpScratch<G> {}
+
+impl<G> BumpScratch<G> {
+    const CHUNK_ALIGN: usize = 16;
+
+    /// Creates a new bump scratch space that allocates its first chunk when it is first used.
+    pub fn new(growth: G) -> Self {
+        Self {
+            growth,
+            limit: None,
+            chunks: Vec::new(),
+            current: 0,
+            capacity: 0,
+            used: 0,
+            peak: 0,
+            allocations: Vec::new(),
+        }
+    }
+
+    /// Creates a new bump scratch space with a first chunk of the given capacity.
+    pub fn with_capacity(capacity: usize, growth: G) -> Self {
+        let mut result = Self::new(growth);
+        if capacity != 0 {
+            result.push_chunk(capacity, 1);
+        }
+        result
+    }
+
+    /// Creates a new bump scratch space with a first chunk of the given capacity, which will never
+    /// allocate more than `limit` bytes in total.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is greater than `limit`.
+    pub fn with_limit(capacity: usize, growth: G, limit: usize) -> Self {
+        assert!(
+            capacity <= limit,
+            "the initial capacity of a bump scratch space must not exceed its limit"
+        );
+        let mut result = Self::with_capacity(capacity, growth);
+        result.limit = Some(limit);
+        result
+    }
+
+    /// Returns the total size of all the chunks allocated by the scratch space.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the maximum number of bytes in use at the same time, including alignment padding.
+    ///
+    /// This can be used to tune the initial capacity of the scratch space after profiling.
+    pub fn peak_bytes(&self) -> usize {
+        self.peak
+    }
+
+    fn push_chunk(&mut self, size: usize, align: usize) {
+        debug_assert!(size != 0, "bump scratch chunks must not be zero-sized");
+        let layout = Layout::from_size_align(size, usize::max(align, Self::CHUNK_ALIGN)).unwrap();
+        let ptr = unsafe { alloc::alloc(layout) };
+        assert!(!ptr.is_null());
+        self.chunks.push(BumpChunk {
+            ptr,
+            layout,
+            used: 0,
+        });
+        self.capacity += size;
+    }
+
+    /// Tries to allocate from the chunk at the given index, returning the pointer and the number of
+    /// bytes used in the chunk after the allocation.
+    fn try_alloc_in(&self, index: usize, layout: Layout) -> Option<(*mut u8, usize)> {
+        let chunk = self.chunks.get(index)?;
+        let start = chunk.ptr as usize + chunk.used;
+        let padding = (layout.align() - start % layout.align()) % layout.align();
+        let end = chunk
+            .used
+            .checked_add(padding)?
+            .checked_add(layout.size())?;
+        if end <= chunk.layout.size() {
+            Some((unsafe { chunk.ptr.add(chunk.used + padding) }, end))
+        } else {
+            None
+        }
+    }
+}
+
+impl<G> Drop for BumpScratch<G> {
+    fn drop(&mut self) {
+        for chunk in self.chunks.drain(..) {
+            unsafe {
+                alloc::dealloc(chunk.ptr, chunk.layout);
+            }
+        }
+    }
+}
+
+impl<G> Fallible for BumpScratch<G> {
+    type Error = AllocScratchError;
+}
+
+impl<G: ScratchGrowth> ScratchSpace for BumpScratch<G> {
+    unsafe fn push_scratch(&mut self, layout: Layout) -> Result<NonNull<[u8]>, Self::Error> {
+        if layout.size() == 0 {
+            // Zero-sized allocations don't need any memory, and allocating a zero-sized chunk for
+            // them would be undefined behavior
+            let ptr = layout.align() as *mut u8;
+            self.allocations.push((ptr, layout, None));
+            let result_slice = ptr_meta::from_raw_parts_mut(ptr.cast(), 0);
+            return Ok(NonNull::new_unchecked(result_slice));
+        }
+
+        let mut index = self.current;
+        let mut result = self.try_alloc_in(index, layout);
+        if result.is_none() && !self.chunks.is_empty() {
+            // Chunks after the current one are empty and can be reused
+            index += 1;
+            result = self.try_alloc_in(index, layout);
+        }
+        let (ptr, chunk_used) = match result {
+            Some(result) => result,
+            None => {
+                // Free any empty chunks that are too small, then allocate a new one
+                for chunk in self.chunks.drain(index..) {
+                    self.capacity -= chunk.layout.size();
+                    alloc::dealloc(chunk.ptr, chunk.layout);
+                }
+
+                let required = layout.size();
+                let mut size = usize::max(
+                    self.growth.next_chunk_size(self.capacity, required),
+                    required,
+                );
+                if let Some(limit) = self.limit {
+                    let remaining = limit - self.capacity;
+                    if remaining < required {
+                        return Err(AllocScratchError::ExceededLimit {
+                            requested: required,
+                            remaining,
+                        });
+                    }
+                    size = usize::min(size, remaining);
+                }
+                self.push_chunk(size, layout.align());
+                index = self.chunks.len() - 1;
+                (self.chunks[index].ptr, required)
+            }
+        };
+
+        let chunk = &mut self.chunks[index];
+        let padded_size = chunk_used - chunk.used;
+        self.allocations
+            .push((ptr, layout, Some((index, chunk.used))));
+        chunk.used = chunk_used;
+        self.current = index;
+        self.used += padded_size;
+        self.peak = usize::max(self.peak, self.used);
+
+        let result_slice = ptr_meta::from_raw_parts_mut(ptr.cast(), layout.size());
+        Ok(NonNull::new_unchecked(result_slice))
+    }
+
+    unsafe fn pop_scratch(&mut self, ptr: NonNull<u8>, layout: Layout) -> Result<(), Self::Error> {
+        if let Some(&(last_ptr, last_layout, chunk)) = self.allocations.last() {
+            if ptr.as_ptr() == last_ptr && layout == last_layout {
+                if let Some((index, prev_used)) = chunk {
+                    let chunk = &mut self.chunks[index];
+                    self.used -= chunk.used - prev_used;
+                    chunk.used = prev_used;
+                    self.current = index;
+                }
+                self.allocations.pop();
+                Ok(())
+            } else {
+                Err(AllocScratchError::NotPoppedInReverseOrder {
+                    expected: last_ptr,
+                    expected_layout: last_layout,
+                    actual: ptr.as_ptr(),
+                    actual_layout: layout,
+                })
+            }
+        } else {
+            Err(AllocScratchError::NoAllocationsToPop)
+        }
+    }
+}
+
 /// An error that can occur while serializing shared pointers.
 #[derive(Debug)]
 pub enum SharedSerializeMapError {
@@ -405,3 +683,490 @@ impl SharedSerializeRegistry for SharedSerializeMap {
         }
     }
 }
+
+/// A shared registry that deduplicates shared values by the contents of their archived bytes in
+/// addition to their addresses.
+///
+/// When a shared value is serialized, the bytes it wrote are hashed and compared against the
+/// bytes of the shared values that were serialized before it. If an identical serialization is
+/// found, the new bytes are discarded and the position of the earlier value is reused. This means
+/// that two distinct `Rc`s or `Arc`s with equal contents are only written once.
+///
+/// Discarding bytes requires rewinding the output, so this registry can only be used with a
+/// [`CompositeSerializer`] that writes to an [`AlignedSerializer`]. Hashing and comparing the
+/// archived bytes of every shared value is not free, so [`SharedSerializeMap`] remains the
+/// default.
+///
+/// Values that reference shared values which were serialized before them are never deduplicated.
+/// The relative pointers in two such serializations may be byte-for-byte identical while pointing
+/// to different targets.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{
+///     archived_root,
+///     ser::{
+///         serializers::{
+///             AllocScratch, AlignedSerializer, CompositeSerializer, ContentDedupRegistry,
+///         },
+///         Serializer,
+///     },
+///     AlignedVec,
+/// };
+/// use std::rc::Rc;
+///
+/// let value = vec![Rc::new(1234u32), Rc::new(1234u32)];
+///
+/// let mut serializer = CompositeSerializer::new(
+///     AlignedSerializer::new(AlignedVec::new()),
+///     AllocScratch::default(),
+///     ContentDedupRegistry::new(),
+/// );
+/// serializer.serialize_value(&value).unwrap();
+/// let bytes = serializer.into_serializer().into_inner();
+///
+/// let archived = unsafe { archived_root::<Vec<Rc<u32>>>(&bytes) };
+/// assert_eq!(&*archived[0] as *const _, &*archived[1] as *const _);
+/// ```
+#[derive(Debug)]
+pub struct ContentDedupRegistry {
+    shared_resolvers: hash_map::HashMap<*const u8, usize>,
+    contents: hash_map::HashMap<u64, Vec<SharedContent>>,
+    min_reference: usize,
+}
+
+#[derive(Debug)]
+struct SharedContent {
+    start: usize,
+    end: usize,
+    pos: usize,
+}
+
+// SAFETY: ContentDedupRegistry is safe to send to another thread
+// This trait is not automatically implemented because the struct contains a pointer
+unsafe impl Send for ContentDedupRegistry {}
+
+// SAFETY: ContentDedupRegistry is safe to share between threads
+// This trait is not automatically implemented because the struct contains a pointer
+unsafe impl Sync for ContentDedupRegistry {}
+
+impl ContentDedupRegistry {
+    /// Creates a new content-deduplicating shared registry.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            shared_resolvers: hash_map::HashMap::new(),
+            contents: hash_map::HashMap::new(),
+            min_reference: usize::MAX,
+        }
+    }
+
+    /// Forgets every shared value serialized at or after `start`.
+    fn rewind(&mut self, start: usize) {
+        self.shared_resolvers.retain(|_, pos| *pos < start);
+        self.contents.retain(|_, entries| {
+            entries.retain(|entry| entry.start < start);
+            !entries.is_empty()
+        });
+    }
+}
+
+impl Default for ContentDedupRegistry {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fallible for ContentDedupRegistry {
+    type Error = SharedSerializeMapError;
+}
+
+impl<A, C> SharedSerializeRegistry
+    for CompositeSerializer<AlignedSerializer<A>, C, ContentDedupRegistry>
+where
+    A: Borrow<AlignedVec> + BorrowMut<AlignedVec>,
+    C: Fallible,
+{
+    fn get_shared_ptr(&self, value: *const u8) -> Option<usize> {
+        self.shared.shared_resolvers.get(&value).copied()
+    }
+
+    fn add_shared_ptr(&mut self, value: *const u8, pos: usize) -> Result<(), Self::Error> {
+        match self.shared.shared_resolvers.entry(value) {
+            hash_map::Entry::Occupied(_) => Err(CompositeSerializerError::SharedError(
+                SharedSerializeMapError::DuplicateSharedPointer(value),
+            )),
+            hash_map::Entry::Vacant(e) => {
+                e.insert(pos);
+                Ok(())
+            }
+        }
+    }
+
+    fn serialize_shared<T: SerializeUnsized<Self> + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<usize, Self::Error> {
+        if let Some(pos) = self.get_shared(value) {
+            self.shared.min_reference = self.shared.min_reference.min(pos);
+            return Ok(pos);
+        }
+
+        let outer_min_reference = mem::replace(&mut self.shared.min_reference, usize::MAX);
+        let start = self.pos();
+        let result = value.serialize_unsized(self);
+        let min_reference = mem::replace(&mut self.shared.min_reference, outer_min_reference);
+        let mut pos = result?;
+        let end = self.pos();
+
+        if min_reference >= start {
+            let buffer = self.serializer.inner.borrow().as_slice();
+            let bytes = &buffer[start..end];
+            let hash = seahash::hash(bytes);
+            let previous = self.shared.contents.get(&hash).and_then(|entries| {
+                entries
+                    .iter()
+                    .find(|entry| {
+                        entry.pos - entry.start == pos - start
+                            && buffer[entry.start..entry.end] == *bytes
+                    })
+                    .map(|entry| entry.pos)
+            });
+
+            if let Some(previous) = previous {
+                self.shared.rewind(start);
+                // SAFETY: `start` is not greater than the current length of the buffer and all
+                // bytes up to it are initialized.
+                unsafe {
+                    self.serializer.inner.borrow_mut().set_len(start);
+                }
+                pos = previous;
+            } else {
+                self.shared
+                    .contents
+                    .entry(hash)
+                    .or_default()
+                    .push(SharedContent { start, end, pos });
+            }
+        }
+
+        self.shared.min_reference = self.shared.min_reference.min(pos);
+        self.add_shared(value, pos)?;
+        Ok(pos)
+    }
+}
+
+/// A serializer adapter that records the root position of each value serialized with
+/// [`serialize_and_record`](MultiRootSerializer::serialize_and_record).
+///
+/// This is useful for packing multiple independent values into a single archive. The recorded
+/// positions returned by [`into_manifest`](MultiRootSerializer::into_manifest) can be serialized as
+/// a manifest at the end of the archive and then used with [`archived_value`](crate::archived_value)
+/// to access each value.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::{
+///     archived_root, archived_value,
+///     ser::{serializers::{AllocSerializer, MultiRootSerializer}, Serializer},
+///     Archived,
+/// };
+///
+/// let mut serializer = MultiRootSerializer::new(AllocSerializer::<256>::default());
+/// serializer.serialize_and_record(&"first".to_string()).unwrap();
+/// serializer.serialize_and_record(&"second".to_string()).unwrap();
+/// let (mut serializer, manifest) = serializer.into_manifest();
+/// serializer.serialize_value(&manifest).unwrap();
+/// let bytes = serializer.into_serializer().into_inner();
+///
+/// let manifest = unsafe { archived_root::<Vec<usize>>(&bytes) };
+/// let second = unsafe { archived_value::<String>(&bytes, manifest[1] as usize) };
+/// assert_eq!(second, "second");
+/// ```
+#[derive(Debug)]
+pub struct MultiRootSerializer<S> {
+    inner: S,
+    roots: Vec<usize>,
+}
+
+impl<S> MultiRootSerializer<S> {
+    /// Creates a new multi-root serializer from the given inner serializer.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            roots: Vec::new(),
+        }
+    }
+
+    /// Consumes the multi-root serializer and returns the inner serializer.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Consumes the multi-root serializer and returns the inner serializer and the recorded root
+    /// positions, in the order they were serialized.
+    #[inline]
+    pub fn into_manifest(self) -> (S, Vec<usize>) {
+        (self.inner, self.roots)
+    }
+}
+
+impl<S: Serializer> MultiRootSerializer<S> {
+    /// Serializes the given value and records its root position.
+    ///
+    /// Returns the position of the archived value.
+    #[inline]
+    pub fn serialize_and_record<T: Serialize<Self>>(
+        &mut self,
+        value: &T,
+    ) -> Result<usize, S::Error> {
+        let pos = self.serialize_value(value)?;
+        self.roots.push(pos);
+        Ok(pos)
+    }
+}
+
+impl<S: Default> Default for MultiRootSerializer<S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S: Fallible> Fallible for MultiRootSerializer<S> {
+    type Error = S::Error;
+}
+
+impl<S: Serializer> Serializer for MultiRootSerializer<S> {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.inner.pos()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.inner.write(bytes)
+    }
+
+    #[inline]
+    fn pad(&mut self, padding: usize) -> Result<(), Self::Error> {
+        self.inner.pad(padding)
+    }
+
+    #[inline]
+    fn align(&mut self, align: usize) -> Result<usize, Self::Error> {
+        self.inner.align(align)
+    }
+
+    #[inline]
+    fn align_for<T>(&mut self) -> Result<usize, Self::Error> {
+        self.inner.align_for::<T>()
+    }
+
+    #[inline]
+    unsafe fn resolve_aligned<T: Archive + ?Sized>(
+        &mut self,
+        value: &T,
+        resolver: T::Resolver,
+    ) -> Result<usize, Self::Error> {
+        self.inner.resolve_aligned::<T>(value, resolver)
+    }
+
+    #[inline]
+    unsafe fn resolve_unsized_aligned<T: ArchiveUnsized + ?Sized>(
+        &mut self,
+        value: &T,
+        to: usize,
+        metadata_resolver: T::MetadataResolver,
+    ) -> Result<usize, Self::Error> {
+        self.inner
+            .resolve_unsized_aligned(value, to, metadata_resolver)
+    }
+
+    #[inline]
+    fn serialize_str(&mut self, value: &str) -> Result<usize, Self::Error> {
+        self.inner.serialize_str(value)
+    }
+}
+
+impl<S: ScratchSpace> ScratchSpace for MultiRootSerializer<S> {
+    #[inline]
+    unsafe fn push_scratch(&mut self, layout: Layout) -> Result<NonNull<[u8]>, Self::Error> {
+        self.inner.push_scratch(layout)
+    }
+
+    #[inline]
+    unsafe fn pop_scratch(&mut self, ptr: NonNull<u8>, layout: Layout) -> Result<(), Self::Error> {
+        self.inner.pop_scratch(ptr, layout)
+    }
+}
+
+impl<S: SharedSerializeRegistry> SharedSerializeRegistry for MultiRootSerializer<S> {
+    #[inline]
+    fn get_shared_ptr(&self, value: *const u8) -> Option<usize> {
+        self.inner.get_shared_ptr(value)
+    }
+
+    #[inline]
+    fn add_shared_ptr(&mut self, value: *const u8, pos: usize) -> Result<(), Self::Error> {
+        self.inner.add_shared_ptr(value, pos)
+    }
+}
+
+/// A serializer adapter that writes the bytes of identical strings only once.
+///
+/// Each string serialized through this adapter is looked up by its contents. If an identical
+/// string was already written, the relative pointer of the new string points to the bytes of the
+/// earlier one instead of writing them again. This is much cheaper than deduplicating by archived
+/// contents with a [`ContentDedupRegistry`], and it works with any inner serializer. The archive
+/// stays position-independent because the shared bytes are still reached through relative
+/// pointers.
+///
+/// Strings short enough to be stored inline are never written out of line, so only longer strings
+/// benefit from interning. Because interned strings share their bytes, mutating one string in the
+/// archive also changes every string that was deduplicated with it.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::{
+///     archived_root,
+///     ser::{serializers::{AllocSerializer, StringInterningSerializer}, Serializer},
+/// };
+///
+/// let label = "a label that is too long to be inlined".to_string();
+/// let value = vec![label.clone(), label.clone(), label];
+///
+/// let mut serializer = StringInterningSerializer::new(AllocSerializer::<256>::default());
+/// serializer.serialize_value(&value).unwrap();
+/// assert_eq!(serializer.interned_len(), 1);
+/// let bytes = serializer.into_inner().into_serializer().into_inner();
+///
+/// let archived = unsafe { archived_root::<Vec<String>>(&bytes) };
+/// assert_eq!(archived[0].as_ptr(), archived[2].as_ptr());
+/// ```
+#[derive(Debug)]
+pub struct StringInterningSerializer<S> {
+    inner: S,
+    strings: hash_map::HashMap<Box<str>, usize>,
+}
+
+impl<S> StringInterningSerializer<S> {
+    /// Creates a new string interning serializer from the given inner serializer.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            strings: hash_map::HashMap::new(),
+        }
+    }
+
+    /// Returns the number of distinct strings that have been written.
+    #[inline]
+    pub fn interned_len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Consumes the string interning serializer and returns the inner serializer.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: Default> Default for StringInterningSerializer<S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S: Fallible> Fallible for StringInterningSerializer<S> {
+    type Error = S::Error;
+}
+
+impl<S: Serializer> Serializer for StringInterningSerializer<S> {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.inner.pos()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.inner.write(bytes)
+    }
+
+    #[inline]
+    fn pad(&mut self, padding: usize) -> Result<(), Self::Error> {
+        self.inner.pad(padding)
+    }
+
+    #[inline]
+    fn align(&mut self, align: usize) -> Result<usize, Self::Error> {
+        self.inner.align(align)
+    }
+
+    #[inline]
+    fn align_for<T>(&mut self) -> Result<usize, Self::Error> {
+        self.inner.align_for::<T>()
+    }
+
+    #[inline]
+    unsafe fn resolve_aligned<T: Archive + ?Sized>(
+        &mut self,
+        value: &T,
+        resolver: T::Resolver,
+    ) -> Result<usize, Self::Error> {
+        self.inner.resolve_aligned::<T>(value, resolver)
+    }
+
+    #[inline]
+    unsafe fn resolve_unsized_aligned<T: ArchiveUnsized + ?Sized>(
+        &mut self,
+        value: &T,
+        to: usize,
+        metadata_resolver: T::MetadataResolver,
+    ) -> Result<usize, Self::Error> {
+        self.inner
+            .resolve_unsized_aligned(value, to, metadata_resolver)
+    }
+
+    fn serialize_str(&mut self, value: &str) -> Result<usize, Self::Error> {
+        if let Some(&pos) = self.strings.get(value) {
+            Ok(pos)
+        } else {
+            let pos = self.inner.serialize_str(value)?;
+            self.strings.insert(Box::from(value), pos);
+            Ok(pos)
+        }
+    }
+}
+
+impl<S: ScratchSpace> ScratchSpace for StringInterningSerializer<S> {
+    #[inline]
+    unsafe fn push_scratch(&mut self, layout: Layout) -> Result<NonNull<[u8]>, Self::Error> {
+        self.inner.push_scratch(layout)
+    }
+
+    #[inline]
+    unsafe fn pop_scratch(&mut self, ptr: NonNull<u8>, layout: Layout) -> Result<(), Self::Error> {
+        self.inner.pop_scratch(ptr, layout)
+    }
+}
+
+impl<S: SharedSerializeRegistry> SharedSerializeRegistry for StringInterningSerializer<S> {
+    #[inline]
+    fn get_shared_ptr(&self, value: *const u8) -> Option<usize> {
+        self.inner.get_shared_ptr(value)
+    }
+
+    #[inline]
+    fn add_shared_ptr(&mut self, value: *const u8, pos: usize) -> Result<(), Self::Error> {
+        self.inner.add_shared_ptr(value, pos)
+    }
+}
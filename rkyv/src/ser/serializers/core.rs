@@ -1,6 +1,7 @@
 use crate::{
-    ser::{ScratchSpace, Serializer},
-    Fallible,
+    checksum::{ChecksumTrailer, Crc32},
+    ser::{ScratchSpace, Serializer, SharedSerializeRegistry},
+    Archive, ArchiveUnsized, Fallible, Serialize,
 };
 use core::{
     alloc::Layout,
@@ -149,6 +150,84 @@ impl<T: AsMut<[u8]>> Serializer for BufferSerializer<T> {
     }
 }
 
+/// A serializer that doesn't write any bytes and only tracks its position.
+///
+/// Serializing a value with a `SizeSerializer` performs a dry run of serialization. The final
+/// position of the serializer is exactly the length of the archive that a real serializer starting
+/// at the same position would produce, including alignment padding. This can be used to allocate a
+/// buffer of the right size ahead of time.
+///
+/// Types that need scratch space or shared pointers still need those capabilities to be serialized,
+/// so a `SizeSerializer` is usually combined with other serializers in a
+/// [`CompositeSerializer`](crate::ser::serializers::CompositeSerializer). For the computed size to
+/// match, the shared registry must deduplicate shared pointers the same way as the one used for the
+/// real serialization.
+///
+/// # Examples
+/// ```
+/// use rkyv::ser::{
+///     serializers::{AllocScratch, CompositeSerializer, SharedSerializeMap, SizeSerializer},
+///     Serializer,
+/// };
+///
+/// let value = vec!["hello".to_string(), "world".to_string()];
+///
+/// let mut serializer = CompositeSerializer::new(
+///     SizeSerializer::new(),
+///     AllocScratch::default(),
+///     SharedSerializeMap::default(),
+/// );
+/// serializer.serialize_value(&value).unwrap();
+/// let size = serializer.into_serializer().pos();
+///
+/// let bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+/// assert_eq!(size, bytes.len());
+/// ```
+#[derive(Debug, Default)]
+pub struct SizeSerializer {
+    pos: usize,
+}
+
+impl SizeSerializer {
+    /// Creates a new size serializer starting at position 0.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_pos(0)
+    }
+
+    /// Creates a new size serializer starting at the given position.
+    ///
+    /// The alignment padding computed by the serializer depends on its position, so this should be
+    /// the position that the real serializer will start at.
+    #[inline]
+    pub fn with_pos(pos: usize) -> Self {
+        Self { pos }
+    }
+}
+
+impl Fallible for SizeSerializer {
+    type Error = core::convert::Infallible;
+}
+
+impl Serializer for SizeSerializer {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.pos += bytes.len();
+        Ok(())
+    }
+
+    #[inline]
+    fn pad(&mut self, padding: usize) -> Result<(), Self::Error> {
+        self.pos += padding;
+        Ok(())
+    }
+}
+
 /// Errors that can occur when using a fixed-size allocator.
 ///
 /// Pairing a fixed-size allocator with a fallback allocator can help prevent running out of scratch
@@ -413,3 +492,288 @@ impl<T> From<T> for ScratchTracker<T> {
         Self::new(inner)
     }
 }
+
+/// A serializer adapter that appends a CRC32 [`ChecksumTrailer`] to the end of the archive.
+///
+/// The wrapped serializer should start empty, since only the bytes written through the adapter are
+/// checksummed. Call [`finish`](ChecksumSerializer::finish) after serializing to write the trailer,
+/// and use [`verify_checksum`](crate::checksum::verify_checksum) to check and strip the trailer
+/// before accessing the archive.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::{
+///     checksum::{verify_checksum, ChecksumError},
+///     ser::{serializers::{AlignedSerializer, ChecksumSerializer}, Serializer},
+///     AlignedVec,
+/// };
+///
+/// let mut serializer = ChecksumSerializer::new(AlignedSerializer::new(AlignedVec::new()));
+/// serializer.serialize_value(&"hello world".to_string()).unwrap();
+/// let mut bytes = serializer.finish().unwrap().into_inner();
+/// assert!(verify_checksum(&bytes).is_ok());
+///
+/// bytes[0] ^= 1;
+/// assert!(matches!(verify_checksum(&bytes), Err(ChecksumError::Mismatch { .. })));
+/// ```
+#[derive(Debug)]
+pub struct ChecksumSerializer<S> {
+    inner: S,
+    crc: Crc32,
+}
+
+impl<S> ChecksumSerializer<S> {
+    /// Creates a new checksum serializer from the given inner serializer.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            crc: Crc32::new(),
+        }
+    }
+
+    /// Returns the checksum of the bytes written so far.
+    #[inline]
+    pub fn checksum(&self) -> u32 {
+        self.crc.finish()
+    }
+
+    /// Consumes the checksum serializer and returns the inner serializer without writing a
+    /// trailer.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: Serializer> ChecksumSerializer<S> {
+    /// Writes the checksum trailer and returns the inner serializer.
+    ///
+    /// The trailer is written immediately after the archive without any padding.
+    #[inline]
+    pub fn finish(mut self) -> Result<S, S::Error> {
+        let trailer = ChecksumTrailer {
+            checksum: to_archived!(self.crc.finish()),
+        };
+        self.inner.write(trailer.as_bytes())?;
+        Ok(self.inner)
+    }
+}
+
+impl<S: Default> Default for ChecksumSerializer<S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S: Fallible> Fallible for ChecksumSerializer<S> {
+    type Error = S::Error;
+}
+
+impl<S: Serializer> Serializer for ChecksumSerializer<S> {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.inner.pos()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.inner.write(bytes)?;
+        self.crc.update(bytes);
+        Ok(())
+    }
+}
+
+impl<S: ScratchSpace> ScratchSpace for ChecksumSerializer<S> {
+    #[inline]
+    unsafe fn push_scratch(&mut self, layout: Layout) -> Result<NonNull<[u8]>, Self::Error> {
+        self.inner.push_scratch(layout)
+    }
+
+    #[inline]
+    unsafe fn pop_scratch(&mut self, ptr: NonNull<u8>, layout: Layout) -> Result<(), Self::Error> {
+        self.inner.pop_scratch(ptr, layout)
+    }
+}
+
+impl<S: SharedSerializeRegistry> SharedSerializeRegistry for ChecksumSerializer<S> {
+    #[inline]
+    fn get_shared_ptr(&self, value: *const u8) -> Option<usize> {
+        self.inner.get_shared_ptr(value)
+    }
+
+    #[inline]
+    fn add_shared_ptr(&mut self, value: *const u8, pos: usize) -> Result<(), Self::Error> {
+        self.inner.add_shared_ptr(value, pos)
+    }
+}
+
+/// A serializer adapter that aligns the root of the archive to `ALIGN` bytes.
+///
+/// Use [`serialize_root`](AlignSerializer::serialize_root) to serialize the root value. After its
+/// dependencies are serialized, the serializer is padded with zeroes so that the root object starts
+/// at a multiple of `ALIGN`. This can be used to place the root on a page boundary when the archive
+/// is memory-mapped. `ALIGN` must be a power of two.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::{
+///     archived_root,
+///     ser::serializers::{AlignSerializer, AllocSerializer},
+/// };
+///
+/// let mut serializer = AlignSerializer::<_, 4096>::new(AllocSerializer::<256>::default());
+/// let pos = serializer.serialize_root(&vec![1u8, 2, 3]).unwrap();
+/// assert_eq!(pos % 4096, 0);
+/// assert_eq!(serializer.root_pos(), Some(pos));
+///
+/// let bytes = serializer.into_inner().into_serializer().into_inner();
+/// let archived = unsafe { archived_root::<Vec<u8>>(&bytes) };
+/// assert_eq!(archived.as_slice(), &[1, 2, 3]);
+/// ```
+#[derive(Debug)]
+pub struct AlignSerializer<S, const ALIGN: usize> {
+    inner: S,
+    root_pos: Option<usize>,
+}
+
+impl<S, const ALIGN: usize> AlignSerializer<S, ALIGN> {
+    /// Creates a new align serializer from the given inner serializer.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        debug_assert!(ALIGN.is_power_of_two(), "ALIGN must be a power of two");
+        Self {
+            inner,
+            root_pos: None,
+        }
+    }
+
+    /// Returns the position of the last root serialized with
+    /// [`serialize_root`](AlignSerializer::serialize_root), if any.
+    #[inline]
+    pub fn root_pos(&self) -> Option<usize> {
+        self.root_pos
+    }
+
+    /// Consumes the align serializer and returns the inner serializer.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: Serializer, const ALIGN: usize> AlignSerializer<S, ALIGN> {
+    /// Serializes the given value as the root of the archive, aligned to `ALIGN` bytes.
+    ///
+    /// Returns the position of the root, which is always a multiple of `ALIGN`. The root should
+    /// be the last value serialized.
+    #[inline]
+    pub fn serialize_root<T: Serialize<Self>>(&mut self, value: &T) -> Result<usize, S::Error> {
+        const ZEROES: [u8; 64] = [0; 64];
+
+        let resolver = value.serialize(self)?;
+        let align = usize::max(ALIGN, core::mem::align_of::<T::Archived>());
+        let mut padding = (align - (self.pos() & (align - 1))) & (align - 1);
+        while padding > 0 {
+            let len = usize::min(padding, ZEROES.len());
+            self.inner.write(&ZEROES[..len])?;
+            padding -= len;
+        }
+
+        let pos = unsafe { self.resolve_aligned(value, resolver)? };
+        self.root_pos = Some(pos);
+        Ok(pos)
+    }
+}
+
+impl<S: Default, const ALIGN: usize> Default for AlignSerializer<S, ALIGN> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S: Fallible, const ALIGN: usize> Fallible for AlignSerializer<S, ALIGN> {
+    type Error = S::Error;
+}
+
+impl<S: Serializer, const ALIGN: usize> Serializer for AlignSerializer<S, ALIGN> {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.inner.pos()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.inner.write(bytes)
+    }
+
+    #[inline]
+    fn pad(&mut self, padding: usize) -> Result<(), Self::Error> {
+        self.inner.pad(padding)
+    }
+
+    #[inline]
+    fn align(&mut self, align: usize) -> Result<usize, Self::Error> {
+        self.inner.align(align)
+    }
+
+    #[inline]
+    fn align_for<T>(&mut self) -> Result<usize, Self::Error> {
+        self.inner.align_for::<T>()
+    }
+
+    #[inline]
+    unsafe fn resolve_aligned<T: Archive + ?Sized>(
+        &mut self,
+        value: &T,
+        resolver: T::Resolver,
+    ) -> Result<usize, Self::Error> {
+        self.inner.resolve_aligned::<T>(value, resolver)
+    }
+
+    #[inline]
+    unsafe fn resolve_unsized_aligned<T: ArchiveUnsized + ?Sized>(
+        &mut self,
+        value: &T,
+        to: usize,
+        metadata_resolver: T::MetadataResolver,
+    ) -> Result<usize, Self::Error> {
+        self.inner
+            .resolve_unsized_aligned(value, to, metadata_resolver)
+    }
+
+    #[inline]
+    fn serialize_str(&mut self, value: &str) -> Result<usize, Self::Error> {
+        self.inner.serialize_str(value)
+    }
+}
+
+impl<S: ScratchSpace, const ALIGN: usize> ScratchSpace for AlignSerializer<S, ALIGN> {
+    #[inline]
+    unsafe fn push_scratch(&mut self, layout: Layout) -> Result<NonNull<[u8]>, Self::Error> {
+        self.inner.push_scratch(layout)
+    }
+
+    #[inline]
+    unsafe fn pop_scratch(&mut self, ptr: NonNull<u8>, layout: Layout) -> Result<(), Self::Error> {
+        self.inner.pop_scratch(ptr, layout)
+    }
+}
+
+impl<S: SharedSerializeRegistry, const ALIGN: usize> SharedSerializeRegistry
+    for AlignSerializer<S, ALIGN>
+{
+    #[inline]
+    fn get_shared_ptr(&self, value: *const u8) -> Option<usize> {
+        self.inner.get_shared_ptr(value)
+    }
+
+    #[inline]
+    fn add_shared_ptr(&mut self, value: *const u8, pos: usize) -> Result<(), Self::Error> {
+        self.inner.add_shared_ptr(value, pos)
+    }
+}
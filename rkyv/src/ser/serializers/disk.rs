@@ -0,0 +1,299 @@
+use crate::{
+    ser::{
+        serializers::{BufferScratch, FixedSizeScratchError},
+        ScratchSpace,
+    },
+    AlignedVec, Fallible,
+};
+use core::{alloc::Layout, fmt, ptr::NonNull};
+use memmap2::{MmapMut, MmapOptions};
+use std::{
+    boxed::Box,
+    env,
+    fs::{self, File, OpenOptions},
+    io,
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+    vec::Vec,
+};
+
+/// The granularity of offsets into the spill file.
+///
+/// This is a multiple of the page size and allocation granularity of common platforms, so every
+/// spilled allocation is mapped at a page-aligned address.
+const SPILL_GRANULARITY: u64 = 64 * 1024;
+
+static NEXT_SPILL_FILE: AtomicUsize = AtomicUsize::new(0);
+
+/// Errors that can occur when allocating with a [`DiskScratch`].
+#[derive(Debug)]
+pub enum DiskScratchError {
+    /// An I/O error occurred while creating, resizing, or mapping the spill file
+    Io(io::Error),
+    /// The requested alignment is greater than the alignment of spilled allocations
+    AlignmentTooLarge(Layout),
+    /// Scratch space was not popped in reverse order
+    NotPoppedInReverseOrder {
+        /// The pointer of the allocation that was expected to be next
+        expected: *mut u8,
+        /// The pointer that was popped instead
+        actual: *mut u8,
+    },
+    /// There are no allocations to pop
+    NoAllocationsToPop,
+    /// An error occurred while popping from the in-memory window
+    WindowError(FixedSizeScratchError),
+}
+
+// SAFETY: DiskScratchError is safe to send to another thread
+// This trait is not automatically implemented because the enum contains a pointer
+unsafe impl Send for DiskScratchError {}
+
+// SAFETY: DiskScratchError is safe to share between threads
+// This trait is not automatically implemented because the enum contains a pointer
+unsafe impl Sync for DiskScratchError {}
+
+impl From<io::Error> for DiskScratchError {
+    #[inline]
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl fmt::Display for DiskScratchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to spill scratch space to disk: {}", e),
+            Self::AlignmentTooLarge(layout) => write!(
+                f,
+                "alignment of {} is too large to spill to disk, the maximum is {}",
+                layout.align(),
+                SPILL_GRANULARITY
+            ),
+            Self::NotPoppedInReverseOrder { expected, actual } => write!(
+                f,
+                "scratch space was not popped in reverse order: expected {:p}, found {:p}",
+                expected, actual
+            ),
+            Self::NoAllocationsToPop => write!(
+                f,
+                "attempted to pop scratch space but there were no allocations to pop"
+            ),
+            Self::WindowError(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for DiskScratchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::WindowError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Spilled {
+    map: MmapMut,
+    offset: u64,
+}
+
+/// Scratch space that keeps a fixed-size window in memory and spills the rest to a temporary file.
+///
+/// Allocations are made in the in-memory window while it has room. Once it runs out, allocations
+/// are made in a temporary file that is memory-mapped. Allocations are never moved once they're
+/// handed out, so the operating system writes spilled allocations back to the file when memory
+/// runs low and faults them back in when they are accessed again. Because scratch space is used
+/// like a stack, the file is truncated as spilled allocations are popped.
+///
+/// The temporary file is created the first time an allocation spills and removed when the scratch
+/// space is dropped. This is much slower than keeping scratch space in memory, and should only be
+/// used when the scratch space needed to serialize a value doesn't fit in memory.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::{
+///     archived_root,
+///     ser::{
+///         serializers::{AlignedSerializer, CompositeSerializer, DiskScratch},
+///         Serializer,
+///     },
+///     AlignedVec, Infallible,
+/// };
+///
+/// let value = (0..100).map(|i| vec![i; 10]).collect::<Vec<Vec<u32>>>();
+///
+/// let mut serializer = CompositeSerializer::new(
+///     AlignedSerializer::new(AlignedVec::new()),
+///     DiskScratch::new(16),
+///     Infallible,
+/// );
+/// serializer.serialize_value(&value).unwrap();
+/// let (serializer, scratch, _) = serializer.into_components();
+/// assert!(scratch.spills() > 0);
+///
+/// let bytes = serializer.into_inner();
+/// let archived = unsafe { archived_root::<Vec<Vec<u32>>>(&bytes) };
+/// assert_eq!(archived[42].as_slice(), &[42; 10]);
+/// ```
+pub struct DiskScratch {
+    window: BufferScratch<Box<AlignedVec>>,
+    cap: usize,
+    dir: PathBuf,
+    file: Option<(File, PathBuf)>,
+    allocations: Vec<Option<Spilled>>,
+    spills: usize,
+}
+
+// SAFETY: DiskScratch is safe to send to another thread
+// This trait is not automatically implemented because the struct contains a pointer
+unsafe impl Send for DiskScratch {}
+
+// SAFETY: DiskScratch is safe to share between threads
+// This trait is not automatically implemented because the struct contains a pointer
+unsafe impl Sync for DiskScratch {}
+
+impl DiskScratch {
+    /// Creates a new disk scratch space that keeps up to `cap` bytes in memory and spills to the
+    /// system's temporary directory.
+    #[inline]
+    pub fn new(cap: usize) -> Self {
+        Self::in_dir(cap, env::temp_dir())
+    }
+
+    /// Creates a new disk scratch space that keeps up to `cap` bytes in memory and spills to the
+    /// given directory.
+    #[inline]
+    pub fn in_dir(cap: usize, dir: impl Into<PathBuf>) -> Self {
+        let mut window = AlignedVec::with_capacity(cap);
+        window.resize(cap, 0);
+        Self {
+            window: BufferScratch::new(Box::new(window)),
+            cap,
+            dir: dir.into(),
+            file: None,
+            allocations: Vec::new(),
+            spills: 0,
+        }
+    }
+
+    /// Returns the number of bytes that are kept in memory.
+    #[inline]
+    pub fn cap(&self) -> usize {
+        self.cap
+    }
+
+    /// Returns the total number of allocations that have been spilled to disk.
+    #[inline]
+    pub fn spills(&self) -> usize {
+        self.spills
+    }
+
+    fn spill_file(&mut self) -> io::Result<&File> {
+        if self.file.is_none() {
+            let name = format!(
+                "rkyv-scratch-{}-{}",
+                process::id(),
+                NEXT_SPILL_FILE.fetch_add(1, Ordering::Relaxed)
+            );
+            let path = self.dir.join(name);
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create_new(true)
+                .open(&path)?;
+            self.file = Some((file, path));
+        }
+        Ok(&self.file.as_ref().unwrap().0)
+    }
+}
+
+impl fmt::Debug for DiskScratch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DiskScratch")
+            .field("cap", &self.cap)
+            .field("dir", &self.dir)
+            .field("allocations", &self.allocations.len())
+            .field("spills", &self.spills)
+            .finish()
+    }
+}
+
+impl Drop for DiskScratch {
+    fn drop(&mut self) {
+        self.allocations.clear();
+        if let Some((file, path)) = self.file.take() {
+            drop(file);
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+impl Fallible for DiskScratch {
+    type Error = DiskScratchError;
+}
+
+impl ScratchSpace for DiskScratch {
+    unsafe fn push_scratch(&mut self, layout: Layout) -> Result<NonNull<[u8]>, Self::Error> {
+        if let Ok(result) = self.window.push_scratch(layout) {
+            self.allocations.push(None);
+            return Ok(result);
+        }
+
+        if layout.align() as u64 > SPILL_GRANULARITY {
+            return Err(DiskScratchError::AlignmentTooLarge(layout));
+        }
+
+        let offset = match self.allocations.iter().rev().find_map(Option::as_ref) {
+            Some(last) => {
+                let end = last.offset + last.map.len() as u64;
+                (end + SPILL_GRANULARITY - 1) & !(SPILL_GRANULARITY - 1)
+            }
+            None => 0,
+        };
+        // Mapping zero bytes is an error, so zero-sized allocations take up a single byte
+        let len = usize::max(layout.size(), 1);
+
+        let file = self.spill_file()?;
+        file.set_len(offset + len as u64)?;
+        let mut map = MmapOptions::new().offset(offset).len(len).map_mut(file)?;
+
+        let result_slice = ptr_meta::from_raw_parts_mut(map.as_mut_ptr().cast(), layout.size());
+        self.allocations.push(Some(Spilled { map, offset }));
+        self.spills += 1;
+        Ok(NonNull::new_unchecked(result_slice))
+    }
+
+    unsafe fn pop_scratch(&mut self, ptr: NonNull<u8>, layout: Layout) -> Result<(), Self::Error> {
+        match self.allocations.last_mut() {
+            None => Err(DiskScratchError::NoAllocationsToPop),
+            Some(None) => {
+                self.window
+                    .pop_scratch(ptr, layout)
+                    .map_err(DiskScratchError::WindowError)?;
+                self.allocations.pop();
+                Ok(())
+            }
+            Some(Some(spilled)) => {
+                let expected = spilled.map.as_mut_ptr();
+                if ptr.as_ptr() != expected {
+                    return Err(DiskScratchError::NotPoppedInReverseOrder {
+                        expected,
+                        actual: ptr.as_ptr(),
+                    });
+                }
+
+                let offset = spilled.offset;
+                self.allocations.pop();
+                if let Some((file, _)) = self.file.as_ref() {
+                    file.set_len(offset)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -3,8 +3,14 @@
 #[cfg(feature = "alloc")]
 mod alloc;
 mod core;
+#[cfg(feature = "disk_scratch")]
+mod disk;
+#[cfg(feature = "debug_serialize")]
+mod profiling;
 #[cfg(feature = "std")]
 mod std;
+#[cfg(all(feature = "zstd", feature = "std"))]
+mod zstd;
 
 #[cfg(feature = "alloc")]
 use crate::AlignedVec;
@@ -20,8 +26,17 @@ pub use self::alloc::*;
 #[doc(inline)]
 pub use self::core::*;
 #[doc(inline)]
+#[cfg(feature = "disk_scratch")]
+pub use self::disk::*;
+#[doc(inline)]
+#[cfg(feature = "debug_serialize")]
+pub use self::profiling::*;
+#[doc(inline)]
 #[cfg(feature = "std")]
 pub use self::std::*;
+#[doc(inline)]
+#[cfg(all(feature = "zstd", feature = "std"))]
+pub use self::zstd::*;
 
 /// The default serializer error.
 #[derive(Debug)]
@@ -174,6 +189,13 @@ impl<S: Serializer, C: Fallible, H: Fallible> Serializer for CompositeSerializer
             .resolve_unsized_aligned(value, to, metadata_resolver)
             .map_err(CompositeSerializerError::SerializerError)
     }
+
+    #[inline]
+    fn serialize_str(&mut self, value: &str) -> Result<usize, Self::Error> {
+        self.serializer
+            .serialize_str(value)
+            .map_err(CompositeSerializerError::SerializerError)
+    }
 }
 
 impl<S: Fallible, C: ScratchSpace, H: Fallible> ScratchSpace for CompositeSerializer<S, C, H> {
@@ -219,6 +241,17 @@ pub type CoreSerializer<const S: usize, const C: usize> = CompositeSerializer<
     Infallible,
 >;
 
+/// The error type returned by [`CoreSerializer`].
+///
+/// Like the serializer itself, this error never allocates: it is a plain enum of
+/// [`BufferSerializerError`] and [`FixedSizeScratchError`], so it can be matched on and handled
+/// without `alloc`.
+pub type CoreSerializerError = CompositeSerializerError<
+    BufferSerializerError,
+    FixedSizeScratchError,
+    ::core::convert::Infallible,
+>;
+
 /// A general-purpose serializer suitable for environments where allocations can be made.
 ///
 /// `AllocSerializer` takes one argument: the amount of scratch space to allocate before spilling
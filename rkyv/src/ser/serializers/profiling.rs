@@ -0,0 +1,191 @@
+use crate::{
+    ser::{ScratchSpace, Serializer, SharedSerializeRegistry},
+    Archive, ArchiveUnsized, Fallible, RelPtr,
+};
+use core::{alloc::Layout, any::type_name, mem, ptr::NonNull};
+use std::vec::Vec;
+
+/// A value written by a [`ProfilingSerializer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProfileEntry {
+    /// The name of the type that was written
+    pub type_name: &'static str,
+    /// The position the value was written at
+    pub start: usize,
+    /// The number of bytes the value takes up
+    pub len: usize,
+}
+
+impl ProfileEntry {
+    /// Returns the position just past the end of the value.
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.start + self.len
+    }
+}
+
+/// A serializer adapter that records where each value is written in the archive.
+///
+/// Every value resolved through [`resolve_aligned`](Serializer::resolve_aligned) is recorded with
+/// the name of its unarchived type, and every relative pointer resolved through
+/// [`resolve_unsized_aligned`](Serializer::resolve_unsized_aligned) is recorded as a `RelPtr` to
+/// its archived type. This includes the root value, the elements of archived slices, and the
+/// entries of archived collections. Raw bytes written directly, like the contents of strings, are
+/// not recorded.
+///
+/// Entries are recorded in the order they are written, so the root value of a call to
+/// [`serialize_value`](Serializer::serialize_value) is always the last entry.
+///
+/// # Examples
+///
+/// ```
+/// use rkyv::ser::{
+///     serializers::{AllocSerializer, ProfilingSerializer},
+///     Serializer,
+/// };
+///
+/// let mut serializer = ProfilingSerializer::new(AllocSerializer::<256>::default());
+/// serializer.serialize_value(&vec![1u32, 2, 3]).unwrap();
+///
+/// for entry in serializer.entries() {
+///     println!("{}..{}: {}", entry.start, entry.end(), entry.type_name);
+/// }
+/// let root = serializer.entries().last().unwrap();
+/// assert_eq!(root.type_name, "alloc::vec::Vec<u32>");
+/// assert_eq!(root.end(), serializer.pos());
+/// ```
+#[derive(Debug)]
+pub struct ProfilingSerializer<S> {
+    inner: S,
+    entries: Vec<ProfileEntry>,
+}
+
+impl<S> ProfilingSerializer<S> {
+    /// Creates a new profiling serializer from the given inner serializer.
+    #[inline]
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the entries recorded so far, in the order they were written.
+    #[inline]
+    pub fn entries(&self) -> &[ProfileEntry] {
+        &self.entries
+    }
+
+    /// Consumes the profiling serializer and returns the inner serializer.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Consumes the profiling serializer and returns the inner serializer and the recorded
+    /// entries.
+    #[inline]
+    pub fn into_components(self) -> (S, Vec<ProfileEntry>) {
+        (self.inner, self.entries)
+    }
+}
+
+impl<S: Default> Default for ProfilingSerializer<S> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S: Fallible> Fallible for ProfilingSerializer<S> {
+    type Error = S::Error;
+}
+
+impl<S: Serializer> Serializer for ProfilingSerializer<S> {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.inner.pos()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.inner.write(bytes)
+    }
+
+    #[inline]
+    fn pad(&mut self, padding: usize) -> Result<(), Self::Error> {
+        self.inner.pad(padding)
+    }
+
+    #[inline]
+    fn align(&mut self, align: usize) -> Result<usize, Self::Error> {
+        self.inner.align(align)
+    }
+
+    #[inline]
+    fn align_for<T>(&mut self) -> Result<usize, Self::Error> {
+        self.inner.align_for::<T>()
+    }
+
+    #[inline]
+    unsafe fn resolve_aligned<T: Archive + ?Sized>(
+        &mut self,
+        value: &T,
+        resolver: T::Resolver,
+    ) -> Result<usize, Self::Error> {
+        let start = self.inner.resolve_aligned::<T>(value, resolver)?;
+        self.entries.push(ProfileEntry {
+            type_name: type_name::<T>(),
+            start,
+            len: mem::size_of::<T::Archived>(),
+        });
+        Ok(start)
+    }
+
+    #[inline]
+    unsafe fn resolve_unsized_aligned<T: ArchiveUnsized + ?Sized>(
+        &mut self,
+        value: &T,
+        to: usize,
+        metadata_resolver: T::MetadataResolver,
+    ) -> Result<usize, Self::Error> {
+        let start = self
+            .inner
+            .resolve_unsized_aligned(value, to, metadata_resolver)?;
+        self.entries.push(ProfileEntry {
+            type_name: type_name::<RelPtr<T::Archived>>(),
+            start,
+            len: mem::size_of::<RelPtr<T::Archived>>(),
+        });
+        Ok(start)
+    }
+
+    #[inline]
+    fn serialize_str(&mut self, value: &str) -> Result<usize, Self::Error> {
+        self.inner.serialize_str(value)
+    }
+}
+
+impl<S: ScratchSpace> ScratchSpace for ProfilingSerializer<S> {
+    #[inline]
+    unsafe fn push_scratch(&mut self, layout: Layout) -> Result<NonNull<[u8]>, Self::Error> {
+        self.inner.push_scratch(layout)
+    }
+
+    #[inline]
+    unsafe fn pop_scratch(&mut self, ptr: NonNull<u8>, layout: Layout) -> Result<(), Self::Error> {
+        self.inner.pop_scratch(ptr, layout)
+    }
+}
+
+impl<S: SharedSerializeRegistry> SharedSerializeRegistry for ProfilingSerializer<S> {
+    #[inline]
+    fn get_shared_ptr(&self, value: *const u8) -> Option<usize> {
+        self.inner.get_shared_ptr(value)
+    }
+
+    #[inline]
+    fn add_shared_ptr(&mut self, value: *const u8, pos: usize) -> Result<(), Self::Error> {
+        self.inner.add_shared_ptr(value, pos)
+    }
+}
@@ -3,6 +3,17 @@ use std::io;
 
 /// Wraps a type that implements [`io::Write`](std::io::Write) and equips it with [`Serializer`].
 ///
+/// Bytes are passed to the writer as soon as they are written to the serializer, so the archive
+/// is never buffered in memory. This is possible because archives are written append-only: the
+/// dependencies of an object are always serialized before the object itself, so every relative
+/// pointer points backward to a position that has already been written. Nothing ever needs to be
+/// backpatched once it has been written.
+///
+/// To serialize types that require scratch space or shared pointers, combine this serializer with
+/// other serializers using a [`CompositeSerializer`](crate::ser::serializers::CompositeSerializer).
+/// Only the scratch space is then kept in memory. Wrap the writer in an
+/// [`io::BufWriter`](std::io::BufWriter) to avoid issuing many small writes.
+///
 /// # Examples
 /// ```
 /// use rkyv::ser::{serializers::WriteSerializer, Serializer};
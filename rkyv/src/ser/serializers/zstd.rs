@@ -0,0 +1,80 @@
+use crate::{ser::Serializer, Fallible};
+use std::io;
+
+/// Wraps a type that implements [`io::Write`](std::io::Write) and compresses everything written
+/// to it with [zstd](https://docs.rs/zstd).
+///
+/// This works like [`WriteSerializer`](crate::ser::serializers::WriteSerializer), but bytes pass
+/// through a streaming zstd encoder before they reach the writer. Like other serializers, the
+/// position it reports is the position in the *uncompressed* archive, so relative pointers stay
+/// valid once the data is decompressed.
+///
+/// Compression trades away zero-copy access: a compressed archive has to be decompressed into an
+/// aligned buffer (for example with [`zstd_decompress_to_aligned`](crate::zstd_decompress_to_aligned))
+/// before it can be accessed. This is worthwhile when archives are stored or sent over the network
+/// and size matters more than the cost of one decompression pass. For memory-mapped archives that
+/// are accessed in place, use an uncompressed serializer instead.
+///
+/// The encoder must be finished with [`finish`](ZstdSerializer::finish) once serialization is
+/// complete, or the compressed stream will be truncated.
+///
+/// # Examples
+/// ```
+/// use rkyv::ser::{serializers::ZstdSerializer, Serializer};
+///
+/// let mut serializer = ZstdSerializer::new(Vec::new()).unwrap();
+/// serializer.write(&[0u8, 1u8, 2u8, 3u8]).unwrap();
+/// assert_eq!(serializer.pos(), 4);
+/// let compressed = serializer.finish().unwrap();
+///
+/// let bytes = rkyv::zstd_decompress_to_aligned(compressed.as_slice()).unwrap();
+/// assert_eq!(bytes.as_slice(), &[0u8, 1u8, 2u8, 3u8]);
+/// ```
+pub struct ZstdSerializer<W: io::Write> {
+    inner: ::zstd::stream::Encoder<'static, W>,
+    pos: usize,
+}
+
+impl<W: io::Write> ZstdSerializer<W> {
+    /// Creates a new serializer from a writer using the default compression level.
+    #[inline]
+    pub fn new(inner: W) -> io::Result<Self> {
+        Self::with_level(inner, ::zstd::DEFAULT_COMPRESSION_LEVEL)
+    }
+
+    /// Creates a new serializer from a writer using the given compression level.
+    ///
+    /// Levels range from 1 to 22, and 0 selects the default level.
+    #[inline]
+    pub fn with_level(inner: W, level: i32) -> io::Result<Self> {
+        Ok(Self {
+            inner: ::zstd::stream::Encoder::new(inner, level)?,
+            pos: 0,
+        })
+    }
+
+    /// Finishes the compressed stream and returns the internal writer used to create the
+    /// serializer.
+    #[inline]
+    pub fn finish(self) -> io::Result<W> {
+        self.inner.finish()
+    }
+}
+
+impl<W: io::Write> Fallible for ZstdSerializer<W> {
+    type Error = io::Error;
+}
+
+impl<W: io::Write> Serializer for ZstdSerializer<W> {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.pos
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        io::Write::write_all(&mut self.inner, bytes)?;
+        self.pos += bytes.len();
+        Ok(())
+    }
+}
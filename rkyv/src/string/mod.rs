@@ -3,6 +3,10 @@
 pub mod repr;
 
 use crate::{Fallible, SerializeUnsized};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use ::alloc::{borrow::Cow, string::String};
+#[cfg(feature = "std")]
+use ::std::borrow::Cow;
 use core::{
     borrow::Borrow,
     cmp, fmt, hash,
@@ -27,6 +31,30 @@ impl ArchivedString {
         self.0.as_str()
     }
 
+    /// Extracts a string slice containing the entire `ArchivedString`, checking that the archived
+    /// bytes are valid UTF-8.
+    ///
+    /// This is useful when reading archives that may be partially corrupt. Unlike
+    /// [`as_str`](ArchivedString::as_str), this does not assume that the bytes were valid UTF-8 when
+    /// the string was serialized. It does not check that an out-of-line string points to valid
+    /// memory, use validation (e.g. `check_archived_root`) for untrusted archives.
+    #[inline]
+    pub fn as_str_checked(&self) -> Result<&str, str::Utf8Error> {
+        str::from_utf8(self.0.bytes())
+    }
+
+    /// Converts the archived bytes of the `ArchivedString` to a string, replacing any invalid UTF-8
+    /// sequences with [`U+FFFD REPLACEMENT CHARACTER`](core::char::REPLACEMENT_CHARACTER).
+    ///
+    /// This behaves like [`String::from_utf8_lossy`] and only allocates if the archived bytes are
+    /// not valid UTF-8. Like [`as_str_checked`](ArchivedString::as_str_checked), it is useful for
+    /// salvaging text from partially corrupt archives.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(self.0.bytes())
+    }
+
     /// Extracts a pinned mutable string slice containing the entire `ArchivedString`.
     #[inline]
     pub fn pin_mut_str(self: Pin<&mut Self>) -> Pin<&mut str> {
@@ -187,10 +215,7 @@ pub struct StringResolver {
 
 #[cfg(feature = "validation")]
 const _: () = {
-    use crate::validation::{
-        owned::OwnedPointerError,
-        ArchiveContext,
-    };
+    use crate::validation::{owned::OwnedPointerError, ArchiveContext};
     use bytecheck::{CheckBytes, Error};
 
     impl<C: ArchiveContext + ?Sized> CheckBytes<C> for ArchivedString
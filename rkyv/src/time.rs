@@ -1,6 +1,13 @@
 //! Archived versions of `time` types.
 
 use crate::Archived;
+use core::time::Duration;
+
+#[cfg(feature = "time")]
+mod date_time;
+
+#[cfg(feature = "time")]
+pub use self::date_time::*;
 
 /// An archived [`Duration`](core::time::Duration).
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -95,6 +102,12 @@ impl ArchivedDuration {
         (self.as_secs() as f32) + (self.subsec_nanos() as f32) / (NANOS_PER_SEC as f32)
     }
 
+    /// Converts this `ArchivedDuration` to a [`Duration`].
+    #[inline]
+    pub fn to_duration(&self) -> Duration {
+        Duration::new(self.as_secs(), self.subsec_nanos())
+    }
+
     /// Constructs an archived duration at the given position.
     ///
     /// # Safety
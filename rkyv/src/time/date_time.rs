@@ -0,0 +1,309 @@
+//! Archived versions of the [`time`](https://docs.rs/time) crate's calendar types.
+
+use crate::Archived;
+use ::time::{Date, OffsetDateTime, Time, UtcOffset};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const NANOS_PER_MINUTE: u64 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: u64 = 60 * NANOS_PER_MINUTE;
+const NANOS_PER_DAY: u64 = 24 * NANOS_PER_HOUR;
+
+/// An archived [`Date`](::time::Date).
+///
+/// Dates are stored as a year and a one-based day of the year, so negative years round-trip
+/// unchanged.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "strict", repr(C))]
+pub struct ArchivedDate {
+    year: Archived<i32>,
+    ordinal: Archived<u16>,
+}
+
+impl ArchivedDate {
+    /// Returns the year of this date.
+    #[inline]
+    pub const fn year(&self) -> i32 {
+        from_archived!(self.year)
+    }
+
+    /// Returns the day of the year of this date, starting at 1.
+    #[inline]
+    pub const fn ordinal(&self) -> u16 {
+        from_archived!(self.ordinal)
+    }
+
+    /// Converts this `ArchivedDate` to a [`Date`](::time::Date).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the archived year and ordinal do not form a valid date. This can only happen if
+    /// the archive was not validated.
+    #[inline]
+    pub fn to_date(&self) -> Date {
+        Date::from_ordinal_date(self.year(), self.ordinal()).expect("invalid archived date")
+    }
+
+    /// Constructs an archived date at the given position.
+    ///
+    /// # Safety
+    ///
+    /// `out` must point to memory suitable for holding an `ArchivedDate`.
+    #[inline]
+    pub unsafe fn emplace(year: i32, ordinal: u16, out: *mut ArchivedDate) {
+        use core::ptr::addr_of_mut;
+
+        addr_of_mut!((*out).year).write(to_archived!(year));
+        addr_of_mut!((*out).ordinal).write(to_archived!(ordinal));
+    }
+}
+
+impl PartialEq<Date> for ArchivedDate {
+    #[inline]
+    fn eq(&self, other: &Date) -> bool {
+        self.year() == other.year() && self.ordinal() == other.ordinal()
+    }
+}
+
+/// An archived [`Time`](::time::Time).
+///
+/// Times are stored as the number of nanoseconds since midnight.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "strict", repr(C))]
+pub struct ArchivedTime {
+    nanos: Archived<u64>,
+}
+
+impl ArchivedTime {
+    /// Returns the number of nanoseconds elapsed since midnight.
+    #[inline]
+    pub const fn nanos_since_midnight(&self) -> u64 {
+        from_archived!(self.nanos)
+    }
+
+    /// Converts this `ArchivedTime` to a [`Time`](::time::Time).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the archived time is not less than one day. This can only happen if the archive
+    /// was not validated.
+    #[inline]
+    pub fn to_time(&self) -> Time {
+        let nanos = self.nanos_since_midnight();
+        Time::from_hms_nano(
+            (nanos / NANOS_PER_HOUR) as u8,
+            (nanos % NANOS_PER_HOUR / NANOS_PER_MINUTE) as u8,
+            (nanos % NANOS_PER_MINUTE / NANOS_PER_SECOND) as u8,
+            (nanos % NANOS_PER_SECOND) as u32,
+        )
+        .expect("invalid archived time")
+    }
+
+    /// Constructs an archived time at the given position.
+    ///
+    /// # Safety
+    ///
+    /// `out` must point to memory suitable for holding an `ArchivedTime`.
+    #[inline]
+    pub unsafe fn emplace(nanos_since_midnight: u64, out: *mut ArchivedTime) {
+        use core::ptr::addr_of_mut;
+
+        addr_of_mut!((*out).nanos).write(to_archived!(nanos_since_midnight));
+    }
+}
+
+impl PartialEq<Time> for ArchivedTime {
+    #[inline]
+    fn eq(&self, other: &Time) -> bool {
+        self.nanos_since_midnight() == time_to_nanos(*other)
+    }
+}
+
+#[inline]
+pub(crate) fn time_to_nanos(time: Time) -> u64 {
+    let (hour, minute, second, nanosecond) = time.as_hms_nano();
+    hour as u64 * NANOS_PER_HOUR
+        + minute as u64 * NANOS_PER_MINUTE
+        + second as u64 * NANOS_PER_SECOND
+        + nanosecond as u64
+}
+
+/// An archived [`OffsetDateTime`](::time::OffsetDateTime).
+///
+/// The date and time are stored in the local offset alongside the UTC offset in seconds, so the
+/// original offset is preserved. Like `OffsetDateTime`, two archived date-times are equal if they
+/// represent the same instant.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "strict", repr(C))]
+pub struct ArchivedOffsetDateTime {
+    time: ArchivedTime,
+    date: ArchivedDate,
+    offset: Archived<i32>,
+}
+
+impl ArchivedOffsetDateTime {
+    /// Returns the date in the stored offset.
+    #[inline]
+    pub const fn date(&self) -> &ArchivedDate {
+        &self.date
+    }
+
+    /// Returns the time in the stored offset.
+    #[inline]
+    pub const fn time(&self) -> &ArchivedTime {
+        &self.time
+    }
+
+    /// Returns the UTC offset in whole seconds.
+    #[inline]
+    pub const fn offset_seconds(&self) -> i32 {
+        from_archived!(self.offset)
+    }
+
+    /// Converts this `ArchivedOffsetDateTime` to an
+    /// [`OffsetDateTime`](::time::OffsetDateTime).
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the archived components are out of range. This can only happen if the
+    /// archive was not validated.
+    #[inline]
+    pub fn to_offset_datetime(&self) -> OffsetDateTime {
+        let offset =
+            UtcOffset::from_whole_seconds(self.offset_seconds()).expect("invalid archived offset");
+        self.date
+            .to_date()
+            .with_time(self.time.to_time())
+            .assume_offset(offset)
+    }
+
+    /// Constructs an archived date-time at the given position.
+    ///
+    /// # Safety
+    ///
+    /// `out` must point to memory suitable for holding an `ArchivedOffsetDateTime`.
+    #[inline]
+    pub unsafe fn emplace(value: &OffsetDateTime, out: *mut ArchivedOffsetDateTime) {
+        use core::ptr::addr_of_mut;
+
+        ArchivedDate::emplace(value.year(), value.ordinal(), addr_of_mut!((*out).date));
+        ArchivedTime::emplace(time_to_nanos(value.time()), addr_of_mut!((*out).time));
+        addr_of_mut!((*out).offset).write(to_archived!(value.offset().whole_seconds()));
+    }
+}
+
+impl PartialEq for ArchivedOffsetDateTime {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_offset_datetime() == other.to_offset_datetime()
+    }
+}
+
+impl Eq for ArchivedOffsetDateTime {}
+
+impl PartialEq<OffsetDateTime> for ArchivedOffsetDateTime {
+    #[inline]
+    fn eq(&self, other: &OffsetDateTime) -> bool {
+        self.to_offset_datetime() == *other
+    }
+}
+
+#[cfg(feature = "validation")]
+pub use self::validation::*;
+
+#[cfg(feature = "validation")]
+mod validation {
+    use super::{ArchivedDate, ArchivedOffsetDateTime, ArchivedTime, NANOS_PER_DAY};
+    use crate::Fallible;
+    use ::time::{Date, UtcOffset};
+    use bytecheck::CheckBytes;
+    use core::fmt;
+
+    /// An error resulting from an invalid archived date or time component.
+    #[derive(Debug)]
+    pub enum DateTimeError {
+        /// The ordinal was not a valid day of the year.
+        InvalidDate {
+            /// The archived year
+            year: i32,
+            /// The archived day of the year
+            ordinal: u16,
+        },
+        /// The time was not less than one day past midnight.
+        InvalidTime(u64),
+        /// The UTC offset was out of range.
+        InvalidOffset(i32),
+    }
+
+    impl fmt::Display for DateTimeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                DateTimeError::InvalidDate { year, ordinal } => {
+                    write!(f, "invalid date: day {} of year {}", ordinal, year)
+                }
+                DateTimeError::InvalidTime(nanos) => {
+                    write!(f, "invalid time: {} nanoseconds since midnight", nanos)
+                }
+                DateTimeError::InvalidOffset(seconds) => {
+                    write!(f, "invalid UTC offset: {} seconds", seconds)
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for DateTimeError {}
+
+    impl<C: Fallible + ?Sized> CheckBytes<C> for ArchivedDate {
+        type Error = DateTimeError;
+
+        #[inline]
+        unsafe fn check_bytes<'a>(value: *const Self, _: &mut C) -> Result<&'a Self, Self::Error> {
+            // The fields of `ArchivedDate` are always valid integers
+            let date = &*value;
+            if Date::from_ordinal_date(date.year(), date.ordinal()).is_err() {
+                Err(DateTimeError::InvalidDate {
+                    year: date.year(),
+                    ordinal: date.ordinal(),
+                })
+            } else {
+                Ok(date)
+            }
+        }
+    }
+
+    impl<C: Fallible + ?Sized> CheckBytes<C> for ArchivedTime {
+        type Error = DateTimeError;
+
+        #[inline]
+        unsafe fn check_bytes<'a>(value: *const Self, _: &mut C) -> Result<&'a Self, Self::Error> {
+            // The fields of `ArchivedTime` are always valid integers
+            let time = &*value;
+            if time.nanos_since_midnight() >= NANOS_PER_DAY {
+                Err(DateTimeError::InvalidTime(time.nanos_since_midnight()))
+            } else {
+                Ok(time)
+            }
+        }
+    }
+
+    impl<C: Fallible + ?Sized> CheckBytes<C> for ArchivedOffsetDateTime {
+        type Error = DateTimeError;
+
+        #[inline]
+        unsafe fn check_bytes<'a>(
+            value: *const Self,
+            context: &mut C,
+        ) -> Result<&'a Self, Self::Error> {
+            use core::ptr::addr_of;
+
+            ArchivedDate::check_bytes(addr_of!((*value).date), context)?;
+            ArchivedTime::check_bytes(addr_of!((*value).time), context)?;
+            let date_time = &*value;
+            if UtcOffset::from_whole_seconds(date_time.offset_seconds()).is_err() {
+                Err(DateTimeError::InvalidOffset(date_time.offset_seconds()))
+            } else {
+                Ok(date_time)
+            }
+        }
+    }
+}
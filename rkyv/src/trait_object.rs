@@ -0,0 +1,463 @@
+//! Archiving trait objects through a registry of concrete types.
+//!
+//! Trait objects can't be archived directly because their vtables are only valid in the process
+//! that created them. Instead, every concrete type that will be archived behind a trait object is
+//! given a stable [`TYPE_ID`](DynType::TYPE_ID). The ID is archived alongside the concrete data in
+//! an [`ArchivedDyn`], and a [`DynRegistry`] maps IDs back to trait objects when the archive is
+//! accessed.
+//!
+//! # Example
+//!
+//! ```
+//! use rkyv::{
+//!     archive_dyn, archived_root,
+//!     ser::{serializers::AllocSerializer, Serializer},
+//!     trait_object::{DynRegistry, DynSerializer, DynType},
+//!     with::AsDyn,
+//!     Archive, Serialize,
+//! };
+//!
+//! #[archive_dyn]
+//! trait Shape {
+//!     fn area(&self) -> f32;
+//! }
+//!
+//! #[derive(Archive, Serialize)]
+//! struct Square {
+//!     size: f32,
+//! }
+//!
+//! unsafe impl DynType for Square {
+//!     const TYPE_ID: u32 = 1;
+//! }
+//!
+//! impl Shape for Square {
+//!     fn area(&self) -> f32 {
+//!         self.size * self.size
+//!     }
+//! }
+//!
+//! impl Shape for ArchivedSquare {
+//!     fn area(&self) -> f32 {
+//!         self.size * self.size
+//!     }
+//! }
+//!
+//! #[derive(Archive, Serialize)]
+//! struct Scene {
+//!     // `SerializeShape` is generated by `#[archive_dyn]`
+//!     #[with(AsDyn)]
+//!     shape: Box<dyn SerializeShape>,
+//! }
+//!
+//! let mut registry = DynRegistry::<dyn Shape>::new();
+//! registry.register::<Square>(|square| square);
+//!
+//! let value = Scene {
+//!     shape: Box::new(Square { size: 2.0 }),
+//! };
+//! // Serializers that can't hold a `DynError` serialize trait objects through a `DynSerializer`
+//! let mut serializer = AllocSerializer::<256>::default();
+//! let dyn_serializer: &mut dyn DynSerializer = &mut serializer;
+//! dyn_serializer.serialize_value(&value).unwrap();
+//! let bytes = serializer.into_serializer().into_inner();
+//! let archived = unsafe { archived_root::<Scene>(&bytes) };
+//! assert_eq!(archived.shape.type_id(), Square::TYPE_ID);
+//! assert_eq!(archived.shape.get(&registry).unwrap().area(), 4.0);
+//! ```
+
+use crate::{
+    ser::{ScratchSpace, Serializer, SharedSerializeRegistry},
+    with::{ArchiveWith, AsDyn, SerializeWith},
+    Archive, Archived, Fallible, RawRelPtr, Serialize,
+};
+use core::{alloc::Layout, fmt, marker::PhantomData, ptr::NonNull};
+use std::{boxed::Box, collections::HashMap, error::Error};
+
+/// The error type returned by [`DynSerializer`].
+pub type DynError = Box<dyn Error>;
+
+/// A type that can be archived behind a trait object.
+///
+/// # Safety
+///
+/// [`TYPE_ID`](DynType::TYPE_ID) is used to cast archived values back to their concrete type, so
+/// it must be unique among all the types that are archived behind the same trait and must not
+/// change between the program that writes an archive and the program that reads it. Two types
+/// with the same ID would let [`ArchivedDyn::get`] and [`ArchivedDyn::downcast`] reinterpret one
+/// archived type as the other.
+pub unsafe trait DynType {
+    /// The ID that identifies the archived type.
+    const TYPE_ID: u32;
+}
+
+/// An object-safe version of [`Serializer`], [`ScratchSpace`], and [`SharedSerializeRegistry`].
+///
+/// This is implemented for all serializers that implement those traits with an error type that
+/// implements [`Error`]. The serializer itself implements those traits when used as
+/// `dyn DynSerializer`, so trait objects can be serialized without knowing the concrete
+/// serializer.
+pub trait DynSerializer {
+    /// Returns the current position of the serializer.
+    fn pos_dyn(&self) -> usize;
+
+    /// Attempts to write the given bytes to the serializer.
+    fn write_dyn(&mut self, bytes: &[u8]) -> Result<(), DynError>;
+
+    /// Allocates scratch space of the requested size.
+    ///
+    /// # Safety
+    ///
+    /// See [`ScratchSpace::push_scratch`].
+    unsafe fn push_scratch_dyn(&mut self, layout: Layout) -> Result<NonNull<[u8]>, DynError>;
+
+    /// Deallocates previously allocated scratch space.
+    ///
+    /// # Safety
+    ///
+    /// See [`ScratchSpace::pop_scratch`].
+    unsafe fn pop_scratch_dyn(&mut self, ptr: NonNull<u8>, layout: Layout) -> Result<(), DynError>;
+
+    /// Gets the position of a previously-added shared pointer.
+    fn get_shared_ptr_dyn(&self, value: *const u8) -> Option<usize>;
+
+    /// Adds the position of a shared pointer to the registry.
+    fn add_shared_ptr_dyn(&mut self, value: *const u8, pos: usize) -> Result<(), DynError>;
+}
+
+impl<S> DynSerializer for S
+where
+    S: Serializer + ScratchSpace + SharedSerializeRegistry,
+    S::Error: Error + 'static,
+{
+    #[inline]
+    fn pos_dyn(&self) -> usize {
+        self.pos()
+    }
+
+    #[inline]
+    fn write_dyn(&mut self, bytes: &[u8]) -> Result<(), DynError> {
+        Ok(self.write(bytes)?)
+    }
+
+    #[inline]
+    unsafe fn push_scratch_dyn(&mut self, layout: Layout) -> Result<NonNull<[u8]>, DynError> {
+        Ok(self.push_scratch(layout)?)
+    }
+
+    #[inline]
+    unsafe fn pop_scratch_dyn(&mut self, ptr: NonNull<u8>, layout: Layout) -> Result<(), DynError> {
+        Ok(self.pop_scratch(ptr, layout)?)
+    }
+
+    #[inline]
+    fn get_shared_ptr_dyn(&self, value: *const u8) -> Option<usize> {
+        self.get_shared_ptr(value)
+    }
+
+    #[inline]
+    fn add_shared_ptr_dyn(&mut self, value: *const u8, pos: usize) -> Result<(), DynError> {
+        Ok(self.add_shared_ptr(value, pos)?)
+    }
+}
+
+impl<'a> Fallible for dyn DynSerializer + 'a {
+    type Error = DynError;
+}
+
+impl<'a> Serializer for dyn DynSerializer + 'a {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.pos_dyn()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.write_dyn(bytes)
+    }
+}
+
+impl<'a> ScratchSpace for dyn DynSerializer + 'a {
+    #[inline]
+    unsafe fn push_scratch(&mut self, layout: Layout) -> Result<NonNull<[u8]>, Self::Error> {
+        self.push_scratch_dyn(layout)
+    }
+
+    #[inline]
+    unsafe fn pop_scratch(&mut self, ptr: NonNull<u8>, layout: Layout) -> Result<(), Self::Error> {
+        self.pop_scratch_dyn(ptr, layout)
+    }
+}
+
+impl<'a> SharedSerializeRegistry for dyn DynSerializer + 'a {
+    #[inline]
+    fn get_shared_ptr(&self, value: *const u8) -> Option<usize> {
+        self.get_shared_ptr_dyn(value)
+    }
+
+    #[inline]
+    fn add_shared_ptr(&mut self, value: *const u8, pos: usize) -> Result<(), Self::Error> {
+        self.add_shared_ptr_dyn(value, pos)
+    }
+}
+
+/// A serializer that can be used as a [`DynSerializer`].
+///
+/// This is implemented for `dyn DynSerializer` itself, so trait objects can be nested inside each
+/// other, and for sized serializers that implement `DynSerializer` and whose error type can hold a
+/// [`DynError`]. Serializers with other error types can archive trait objects by serializing
+/// through `&mut dyn DynSerializer`.
+pub trait AsDynSerializer: Fallible {
+    /// Gets the serializer as a `DynSerializer`.
+    fn as_dyn_serializer(&mut self) -> &mut dyn DynSerializer;
+
+    /// Converts an error returned from a `DynSerializer` back into an error for this serializer.
+    fn from_dyn_error(error: DynError) -> Self::Error;
+}
+
+impl<S> AsDynSerializer for S
+where
+    S: DynSerializer + Fallible,
+    S::Error: Error + From<DynError> + 'static,
+{
+    #[inline]
+    fn as_dyn_serializer(&mut self) -> &mut dyn DynSerializer {
+        self
+    }
+
+    #[inline]
+    fn from_dyn_error(error: DynError) -> Self::Error {
+        // Errors from the serializer itself are unboxed, but serialization may also fail with errors
+        // that came from somewhere else
+        match error.downcast::<S::Error>() {
+            Ok(error) => *error,
+            Err(error) => S::Error::from(error),
+        }
+    }
+}
+
+impl<'a> AsDynSerializer for dyn DynSerializer + 'a {
+    #[inline]
+    fn as_dyn_serializer(&mut self) -> &mut dyn DynSerializer {
+        self
+    }
+
+    #[inline]
+    fn from_dyn_error(error: DynError) -> Self::Error {
+        error
+    }
+}
+
+/// An object-safe version of [`Serialize`] for types that can be archived behind a trait object.
+///
+/// This is implemented for all types that implement [`DynType`] and can be serialized with a
+/// [`DynSerializer`]. Traits marked with [`#[archive_dyn]`](macro@crate::archive_dyn) get a
+/// companion trait with this as a supertrait.
+pub trait SerializeDyn {
+    /// Serializes the value and returns the position of the archived value.
+    fn serialize_dyn(&self, serializer: &mut dyn DynSerializer) -> Result<usize, DynError>;
+
+    /// Gets the [`TYPE_ID`](DynType::TYPE_ID) of the concrete type of the value.
+    fn archived_type_id(&self) -> u32;
+}
+
+impl<T> SerializeDyn for T
+where
+    T: DynType + for<'a> Serialize<dyn DynSerializer + 'a>,
+{
+    #[inline]
+    fn serialize_dyn(&self, serializer: &mut dyn DynSerializer) -> Result<usize, DynError> {
+        serializer.serialize_value(self)
+    }
+
+    #[inline]
+    fn archived_type_id(&self) -> u32 {
+        T::TYPE_ID
+    }
+}
+
+/// A trait object type that can be archived with [`AsDyn`].
+///
+/// This is implemented by [`#[archive_dyn]`](macro@crate::archive_dyn) for the generated
+/// serialization trait object, and names the trait object that its archived values are accessed
+/// as.
+pub trait ArchiveDynTrait {
+    /// The trait object that archived values are accessed as.
+    type Archived: ?Sized;
+}
+
+/// An archived trait object.
+///
+/// This stores the [`TYPE_ID`](DynType::TYPE_ID) of the concrete type along with a relative
+/// pointer to the archived concrete value. It can be accessed as `&T` through a [`DynRegistry`],
+/// or as a concrete archived type with [`downcast`](ArchivedDyn::downcast).
+///
+/// `ArchivedDyn` does not support validation because the concrete types that it may point to are
+/// only known to the registry.
+#[cfg_attr(feature = "strict", repr(C))]
+pub struct ArchivedDyn<T: ?Sized> {
+    ptr: RawRelPtr,
+    type_id: Archived<u32>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: ?Sized> ArchivedDyn<T> {
+    /// Gets the [`TYPE_ID`](DynType::TYPE_ID) of the concrete type of the archived value.
+    #[inline]
+    pub fn type_id(&self) -> u32 {
+        from_archived!(self.type_id)
+    }
+
+    /// Gets a pointer to the archived concrete value.
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr().cast()
+    }
+
+    /// Gets the archived value as a trait object, or `None` if its type is not in the registry.
+    #[inline]
+    pub fn get<'a>(&'a self, registry: &DynRegistry<T>) -> Option<&'a T> {
+        let cast = registry.casts.get(&self.type_id())?;
+        unsafe { Some(&*cast(self.as_ptr())) }
+    }
+
+    /// Gets the archived value as the archived version of `U`, or `None` if its concrete type is
+    /// not `U`.
+    #[inline]
+    pub fn downcast<U: DynType + Archive>(&self) -> Option<&U::Archived> {
+        if self.type_id() == U::TYPE_ID {
+            unsafe { Some(&*self.as_ptr().cast::<U::Archived>()) }
+        } else {
+            None
+        }
+    }
+
+    /// Resolves an archived trait object from the given resolver.
+    ///
+    /// # Safety
+    ///
+    /// - `pos` must be the position of `out` within the archive
+    /// - `resolver` must be the result of serializing a trait object
+    #[inline]
+    pub unsafe fn resolve(pos: usize, resolver: DynResolver, out: *mut Self) {
+        let (fp, fo) = out_field!(out.ptr);
+        RawRelPtr::emplace(pos + fp, resolver.pos, fo);
+        let (fp, fo) = out_field!(out.type_id);
+        resolver.type_id.resolve(pos + fp, (), fo);
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for ArchivedDyn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArchivedDyn")
+            .field("type_id", &self.type_id())
+            .field("ptr", &self.as_ptr())
+            .finish()
+    }
+}
+
+/// The resolver for an [`ArchivedDyn`].
+pub struct DynResolver {
+    pos: usize,
+    type_id: u32,
+}
+
+/// A registry that maps the type IDs of archived trait objects to their trait object type.
+///
+/// Every concrete type that may be archived behind a `T` needs to be registered before the archived
+/// trait objects can be accessed through the registry.
+pub struct DynRegistry<T: ?Sized> {
+    casts: HashMap<u32, Box<dyn Fn(*const u8) -> *const T>>,
+}
+
+impl<T: ?Sized + 'static> DynRegistry<T> {
+    /// Creates a new, empty registry.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            casts: HashMap::new(),
+        }
+    }
+
+    /// Registers `U` with the given function to convert its archived type to a trait object.
+    ///
+    /// The conversion is usually just an unsizing coercion, like `|value| value`. Returns `false`
+    /// and keeps the existing registration if a type with the same ID was already registered.
+    pub fn register<U>(&mut self, cast: fn(&U::Archived) -> &T) -> bool
+    where
+        U: DynType + Archive,
+        U::Archived: 'static,
+    {
+        if self.casts.contains_key(&U::TYPE_ID) {
+            return false;
+        }
+        self.casts.insert(
+            U::TYPE_ID,
+            Box::new(move |ptr| cast(unsafe { &*ptr.cast::<U::Archived>() }) as *const T),
+        );
+        true
+    }
+
+    /// Returns whether a type with the given ID has been registered.
+    #[inline]
+    pub fn contains(&self, type_id: u32) -> bool {
+        self.casts.contains_key(&type_id)
+    }
+
+    /// Returns the number of registered types.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.casts.len()
+    }
+
+    /// Returns whether no types have been registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.casts.is_empty()
+    }
+}
+
+impl<T: ?Sized + 'static> Default for DynRegistry<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for DynRegistry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.casts.keys()).finish()
+    }
+}
+
+impl<T: ?Sized + ArchiveDynTrait + SerializeDyn> ArchiveWith<Box<T>> for AsDyn {
+    type Archived = ArchivedDyn<T::Archived>;
+    type Resolver = DynResolver;
+
+    #[inline]
+    unsafe fn resolve_with(
+        _: &Box<T>,
+        pos: usize,
+        resolver: Self::Resolver,
+        out: *mut Self::Archived,
+    ) {
+        ArchivedDyn::resolve(pos, resolver, out);
+    }
+}
+
+impl<T, S> SerializeWith<Box<T>, S> for AsDyn
+where
+    T: ?Sized + ArchiveDynTrait + SerializeDyn,
+    S: AsDynSerializer + ?Sized,
+{
+    #[inline]
+    fn serialize_with(field: &Box<T>, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        let pos = (**field)
+            .serialize_dyn(serializer.as_dyn_serializer())
+            .map_err(S::from_dyn_error)?;
+        Ok(DynResolver {
+            pos,
+            type_id: (**field).archived_type_id(),
+        })
+    }
+}
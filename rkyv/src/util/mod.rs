@@ -56,6 +56,11 @@ fn check_alignment<T>(ptr: *const u8) {
 /// This helps avoid situations where lifetimes get inappropriately assigned and allow buffer
 /// mutation after getting archived value references.
 ///
+/// The position of a value is returned by [`Serializer::serialize_value`], so multiple values can
+/// be serialized into the same buffer and accessed individually.
+///
+/// [`Serializer::serialize_value`]: crate::ser::Serializer::serialize_value
+///
 /// # Safety
 ///
 /// A `T::Archived` must be archived at the given position in the byte slice.
@@ -334,3 +339,30 @@ where
 {
     archived_root::<T>(bytes).deserialize(&mut SharedDeserializeMap::default())
 }
+
+/// Decompresses a zstd stream into an [`AlignedVec`] so that the archive it contains can be
+/// accessed.
+///
+/// This is the counterpart to [`ZstdSerializer`](crate::ser::serializers::ZstdSerializer).
+/// Decompressed archives are always copied into a freshly-allocated aligned buffer, so this costs
+/// one pass over the data and one allocation.
+///
+/// # Examples
+/// ```
+/// use rkyv::ser::{serializers::ZstdSerializer, Serializer};
+///
+/// let mut serializer = ZstdSerializer::new(Vec::new()).unwrap();
+/// serializer.serialize_value(&42u32).unwrap();
+/// let compressed = serializer.finish().unwrap();
+///
+/// let bytes = rkyv::zstd_decompress_to_aligned(compressed.as_slice()).unwrap();
+/// let archived = unsafe { rkyv::archived_root::<u32>(&bytes) };
+/// assert_eq!(*archived, 42);
+/// ```
+#[cfg(all(feature = "zstd", feature = "std"))]
+pub fn zstd_decompress_to_aligned<R: std::io::Read>(reader: R) -> std::io::Result<AlignedVec> {
+    let mut decoder = ::zstd::stream::Decoder::new(reader)?;
+    let mut result = AlignedVec::new();
+    std::io::copy(&mut decoder, &mut result)?;
+    Ok(result)
+}
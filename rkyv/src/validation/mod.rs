@@ -3,6 +3,8 @@
 pub mod owned;
 pub mod validators;
 
+#[cfg(feature = "debug_rel_ptr")]
+use crate::rel_ptr::{Offset, RawRelPtr};
 use crate::{Archive, ArchivePointee, Fallible, RelPtr};
 use bytecheck::CheckBytes;
 use core::{alloc::Layout, any::TypeId, fmt};
@@ -134,10 +136,26 @@ pub trait ArchiveContext: Fallible {
         &mut self,
         rel_ptr: &RelPtr<T>,
     ) -> Result<*const T, Self::Error> {
+        #[cfg(feature = "debug_rel_ptr")]
+        self.check_rel_ptr_tag(rel_ptr.raw_ptr())?;
         let metadata = T::pointer_metadata(rel_ptr.metadata());
         self.check_ptr(rel_ptr.base(), rel_ptr.offset(), metadata)
     }
 
+    /// Checks that the tag of the given relative pointer is intact.
+    ///
+    /// This is called by [`check_rel_ptr`](ArchiveContext::check_rel_ptr) and must be called
+    /// before resolving any other relative pointer that was checked with `manual_check_bytes`.
+    ///
+    /// # Safety
+    ///
+    /// - `raw_ptr` must be inside the archive this validator was created for.
+    #[cfg(feature = "debug_rel_ptr")]
+    unsafe fn check_rel_ptr_tag<O: Offset>(
+        &mut self,
+        raw_ptr: &RawRelPtr<O>,
+    ) -> Result<(), Self::Error>;
+
     /// Checks that the given data address and layout is located completely within the subtree
     /// range.
     ///
@@ -348,6 +366,78 @@ where
     internal_check_archived_value_with_context::<T, C>(buf, pos as isize, context)
 }
 
+/// Checks the given archive for an archived header at the start of the archive with an additional
+/// context.
+///
+/// See [`check_archived_prefix`](crate::validation::validators::check_archived_prefix) for more
+/// details.
+#[inline]
+pub fn check_archived_prefix_with_context<'a, T, C>(
+    buf: &'a [u8],
+    context: &mut C,
+) -> Result<&'a T::Archived, CheckTypeError<T::Archived, C>>
+where
+    T: Archive,
+    T::Archived: CheckBytes<C> + Pointee<Metadata = ()>,
+    C: ArchiveContext + ?Sized,
+{
+    check_archived_value_with_context::<T, C>(buf, 0, context)
+}
+
+/// Checks a single field of the archived root at the given position with an additional context.
+///
+/// See [`check_archived_field`](crate::validation::validators::check_archived_field) for more
+/// details.
+///
+/// # Panics
+///
+/// Panics if the pointer returned by `accessor` does not point to a value located inside the root.
+#[inline]
+pub fn check_archived_field_with_context<'a, T, U, C, F>(
+    buf: &'a [u8],
+    root_pos: usize,
+    context: &mut C,
+    accessor: F,
+) -> Result<&'a U, CheckTypeError<U, C>>
+where
+    T: Archive,
+    T::Archived: Pointee<Metadata = ()>,
+    U: CheckBytes<C>,
+    C: ArchiveContext + ?Sized,
+    F: FnOnce(*const T::Archived) -> *const U,
+{
+    unsafe {
+        let root = context
+            .check_subtree_ptr::<T::Archived>(buf.as_ptr(), root_pos as isize, ())
+            .map_err(CheckArchiveError::ContextError)?;
+
+        let field = accessor(root);
+        let root_start = root as usize;
+        let root_end = root_start + core::mem::size_of::<T::Archived>();
+        let field_start = field as usize;
+        let field_end = field_start + core::mem::size_of::<U>();
+        assert!(
+            root_start <= field_start && field_end <= root_end,
+            "the accessor must return a pointer to a value located inside the root",
+        );
+        context
+            .bounds_check_layout(field.cast(), &Layout::new::<U>())
+            .map_err(CheckArchiveError::ContextError)?;
+
+        let range = context
+            .push_prefix_subtree(field)
+            .map_err(CheckArchiveError::ContextError)?;
+        let result =
+            CheckBytes::check_bytes(field, context).map_err(CheckArchiveError::CheckBytesError)?;
+        context
+            .pop_prefix_range(range)
+            .map_err(CheckArchiveError::ContextError)?;
+
+        context.finish().map_err(CheckArchiveError::ContextError)?;
+        Ok(result)
+    }
+}
+
 /// Checks the given archive with an additional context.
 ///
 /// See [`check_archived_value`](crate::validation::validators::check_archived_value) for more details.
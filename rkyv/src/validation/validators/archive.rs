@@ -1,6 +1,8 @@
 //! The provided implementation for `ArchiveContext`.
 
 use crate::{validation::ArchiveContext, Fallible};
+#[cfg(not(feature = "std"))]
+use ::alloc::vec::Vec;
 use core::{alloc::Layout, fmt, ops::Range};
 
 /// Errors that can occur when checking archive memory.
@@ -12,6 +14,8 @@ pub enum ArchiveError {
         base: *const u8,
         /// The offset
         offset: isize,
+        /// The position of the base pointer in the archive
+        position: ArchivePosition,
     },
     /// The archive is under-aligned for one of the types inside
     Underaligned {
@@ -28,6 +32,8 @@ pub enum ArchiveError {
         offset: isize,
         /// The pointer range of the archive
         range: Range<*const u8>,
+        /// The position of the base of the relative pointer in the archive
+        position: ArchivePosition,
     },
     /// There wasn't enough space for the desired type at the pointed location
     Overrun {
@@ -37,6 +43,8 @@ pub enum ArchiveError {
         size: usize,
         /// The pointer range of the archive
         range: Range<*const u8>,
+        /// The position of the type in the archive
+        position: ArchivePosition,
     },
     /// The pointer wasn't aligned properly for the desired type
     Unaligned {
@@ -44,6 +52,8 @@ pub enum ArchiveError {
         ptr: *const u8,
         /// The required alignment of the type
         align: usize,
+        /// The position of the type in the archive
+        position: ArchivePosition,
     },
     /// The pointer wasn't within the subtree range
     SubtreePointerOutOfBounds {
@@ -51,6 +61,8 @@ pub enum ArchiveError {
         ptr: *const u8,
         /// The subtree range
         subtree_range: Range<*const u8>,
+        /// The position of the subtree in the archive
+        position: ArchivePosition,
     },
     /// There wasn't enough space in the subtree range for the desired type at the pointed location
     SubtreePointerOverrun {
@@ -60,6 +72,8 @@ pub enum ArchiveError {
         size: usize,
         /// The subtree range
         subtree_range: Range<*const u8>,
+        /// The position of the subtree type in the archive
+        position: ArchivePosition,
     },
     /// A subtree range was popped out of order.
     ///
@@ -80,6 +94,39 @@ pub enum ArchiveError {
         /// The maximum depth that subtrees may be validated down to
         max_subtree_depth: usize,
     },
+    /// A relative pointer's tag was corrupted
+    #[cfg(feature = "debug_rel_ptr")]
+    InvalidRelPtrTag {
+        /// The position of the relative pointer in the archive
+        position: ArchivePosition,
+    },
+    /// A pointer pointed back into a subtree that was still being validated.
+    ///
+    /// This is only returned by validators created with cycle detection enabled.
+    CyclicPointer {
+        /// The position of the pointed-to value in the archive
+        position: ArchivePosition,
+    },
+}
+
+impl ArchiveError {
+    /// Returns the position in the archive where the error occurred, if it occurred at a specific
+    /// position.
+    #[inline]
+    pub fn position(&self) -> Option<&ArchivePosition> {
+        match self {
+            ArchiveError::Overflow { position, .. }
+            | ArchiveError::OutOfBounds { position, .. }
+            | ArchiveError::Overrun { position, .. }
+            | ArchiveError::Unaligned { position, .. }
+            | ArchiveError::SubtreePointerOutOfBounds { position, .. }
+            | ArchiveError::SubtreePointerOverrun { position, .. }
+            | ArchiveError::CyclicPointer { position } => Some(position),
+            #[cfg(feature = "debug_rel_ptr")]
+            ArchiveError::InvalidRelPtrTag { position } => Some(position),
+            _ => None,
+        }
+    }
 }
 
 // SAFETY: ArchiveError is safe to send to another thread
@@ -93,10 +140,14 @@ unsafe impl Sync for ArchiveError {}
 impl fmt::Display for ArchiveError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ArchiveError::Overflow { base, offset } => write!(
+            ArchiveError::Overflow {
+                base,
+                offset,
+                position,
+            } => write!(
                 f,
-                "relative pointer overflowed: base {:p} offset {}",
-                base, offset
+                "relative pointer overflowed: base {:p} offset {} at {}",
+                base, offset, position
             ),
             ArchiveError::Underaligned {
                 expected_align,
@@ -110,34 +161,49 @@ impl fmt::Display for ArchiveError {
                 base,
                 offset,
                 range,
+                position,
             } => write!(
                 f,
-                "pointer out of bounds: base {:p} offset {} not in range {:p}..{:p}",
-                base, offset, range.start, range.end
+                "pointer out of bounds: base {:p} offset {} not in range {:p}..{:p} at {}",
+                base, offset, range.start, range.end, position
             ),
-            ArchiveError::Overrun { ptr, size, range } => write!(
+            ArchiveError::Overrun {
+                ptr,
+                size,
+                range,
+                position,
+            } => write!(
                 f,
-                "pointer overran buffer: ptr {:p} size {} in range {:p}..{:p}",
-                ptr, size, range.start, range.end
+                "pointer overran buffer: ptr {:p} size {} in range {:p}..{:p} at {}",
+                ptr, size, range.start, range.end, position
             ),
-            ArchiveError::Unaligned { ptr, align } => write!(
+            ArchiveError::Unaligned {
+                ptr,
+                align,
+                position,
+            } => write!(
                 f,
-                "unaligned pointer: ptr {:p} unaligned for alignment {}",
-                ptr, align
+                "unaligned pointer: ptr {:p} unaligned for alignment {} at {}",
+                ptr, align, position
             ),
-            ArchiveError::SubtreePointerOutOfBounds { ptr, subtree_range } => write!(
+            ArchiveError::SubtreePointerOutOfBounds {
+                ptr,
+                subtree_range,
+                position,
+            } => write!(
                 f,
-                "subtree pointer out of bounds: ptr {:p} not in range {:p}..{:p}",
-                ptr, subtree_range.start, subtree_range.end
+                "subtree pointer out of bounds: ptr {:p} not in range {:p}..{:p} at {}",
+                ptr, subtree_range.start, subtree_range.end, position
             ),
             ArchiveError::SubtreePointerOverrun {
                 ptr,
                 size,
                 subtree_range,
+                position,
             } => write!(
                 f,
-                "subtree pointer overran range: ptr {:p} size {} in range {:p}..{:p}",
-                ptr, size, subtree_range.start, subtree_range.end
+                "subtree pointer overran range: ptr {:p} size {} in range {:p}..{:p} at {}",
+                ptr, size, subtree_range.start, subtree_range.end, position
             ),
             ArchiveError::RangePoppedOutOfOrder {
                 expected_depth,
@@ -155,6 +221,17 @@ impl fmt::Display for ArchiveError {
                 "pushed a subtree range that exceeded the maximum subtree depth of {}",
                 max_subtree_depth
             ),
+            #[cfg(feature = "debug_rel_ptr")]
+            ArchiveError::InvalidRelPtrTag { position } => {
+                write!(f, "relative pointer tag is corrupted at {}", position)
+            }
+            ArchiveError::CyclicPointer { position } => {
+                write!(
+                    f,
+                    "cyclic pointer to a subtree being validated at {}",
+                    position
+                )
+            }
         }
     }
 }
@@ -162,6 +239,110 @@ impl fmt::Display for ArchiveError {
 #[cfg(feature = "std")]
 impl std::error::Error for ArchiveError {}
 
+/// The number of bytes before and after an error position that are included in its hex context.
+#[cfg(feature = "hex_context")]
+const HEX_CONTEXT_RADIUS: usize = 8;
+
+/// A position in an archive where validation failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArchivePosition {
+    /// The byte offset from the start of the archive
+    pub offset: usize,
+    /// The bytes surrounding the offset
+    #[cfg(feature = "hex_context")]
+    pub context: HexContext,
+}
+
+impl ArchivePosition {
+    /// Creates the position of `ptr` in `bytes`.
+    ///
+    /// `ptr` does not need to point into `bytes`. If it doesn't, the offset is the wrapping
+    /// difference between the two pointers.
+    #[inline]
+    pub fn new(bytes: &[u8], ptr: *const u8) -> Self {
+        let offset = (ptr as usize).wrapping_sub(bytes.as_ptr() as usize);
+        Self {
+            offset,
+            #[cfg(feature = "hex_context")]
+            context: HexContext::new(bytes, offset),
+        }
+    }
+}
+
+impl fmt::Display for ArchivePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "offset {:#x}", self.offset)?;
+        #[cfg(feature = "hex_context")]
+        write!(f, " (bytes: {})", self.context)?;
+        Ok(())
+    }
+}
+
+/// The bytes surrounding a position in an archive.
+///
+/// Displays as a hex dump with the byte at the position in brackets.
+#[cfg(feature = "hex_context")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HexContext {
+    /// The offset of the first byte in the context
+    pub start: usize,
+    /// The offset of the position within `bytes`, if it is inside the archive
+    pub index: Option<usize>,
+    len: usize,
+    bytes: [u8; 2 * HEX_CONTEXT_RADIUS + 1],
+}
+
+#[cfg(feature = "hex_context")]
+impl HexContext {
+    /// Collects the bytes surrounding `offset` in `bytes`.
+    pub fn new(bytes: &[u8], offset: usize) -> Self {
+        let center = usize::min(offset, bytes.len());
+        let start = center.saturating_sub(HEX_CONTEXT_RADIUS);
+        let end = usize::min(center.saturating_add(HEX_CONTEXT_RADIUS + 1), bytes.len());
+        let mut context = Self {
+            start,
+            index: if offset < bytes.len() {
+                Some(offset - start)
+            } else {
+                None
+            },
+            len: end - start,
+            bytes: [0; 2 * HEX_CONTEXT_RADIUS + 1],
+        };
+        context.bytes[..context.len].copy_from_slice(&bytes[start..end]);
+        context
+    }
+
+    /// Returns the bytes of the context.
+    #[inline]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+#[cfg(feature = "hex_context")]
+impl fmt::Display for HexContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start > 0 {
+            write!(f, "... ")?;
+        }
+        for (i, byte) in self.bytes().iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            if Some(i) == self.index {
+                write!(f, "[{:02x}]", byte)?;
+            } else {
+                write!(f, "{:02x}", byte)?;
+            }
+        }
+        if self.index.is_none() {
+            write!(f, " [end]")?;
+        }
+        Ok(())
+    }
+}
+
 /// A prefix range from an [`ArchiveValidator`].
 #[derive(Debug)]
 pub struct PrefixRange {
@@ -199,6 +380,7 @@ pub struct ArchiveValidator<'a> {
     subtree_range: Range<*const u8>,
     subtree_depth: usize,
     max_subtree_depth: usize,
+    ancestors: Option<Vec<Range<*const u8>>>,
 }
 
 // SAFETY: ArchiveValidator is safe to send to another thread
@@ -224,6 +406,55 @@ impl<'a> ArchiveValidator<'a> {
             subtree_range: bytes.as_ptr_range(),
             subtree_depth: 0,
             max_subtree_depth,
+            ancestors: None,
+        }
+    }
+
+    /// Creates a new bounds validator for the given bytes that detects pointer cycles.
+    ///
+    /// The validator keeps track of the subtrees that are currently being validated. Pointers back
+    /// into one of those subtrees are already rejected because they fall outside of the subtree
+    /// range, but with cycle detection enabled they are reported as
+    /// [`CyclicPointer`](ArchiveError::CyclicPointer) errors instead. Shared pointers are only
+    /// followed the first time they are registered, so DAGs of shared pointers are still allowed.
+    #[inline]
+    pub fn with_cycle_detection(bytes: &'a [u8]) -> Self {
+        Self {
+            ancestors: Some(Vec::new()),
+            ..Self::new(bytes)
+        }
+    }
+
+    /// Creates a new bounds validator for the given bytes that only allows subtrees to be located
+    /// in the given range.
+    #[cfg(all(feature = "rayon", feature = "std"))]
+    #[inline]
+    pub(crate) fn with_subtree_range(bytes: &'a [u8], subtree_range: Range<*const u8>) -> Self {
+        Self {
+            bytes,
+            subtree_range,
+            subtree_depth: 0,
+            max_subtree_depth: usize::MAX,
+            ancestors: None,
+        }
+    }
+
+    /// Returns the range that subtrees may currently be located in.
+    #[cfg(all(feature = "rayon", feature = "std"))]
+    #[inline]
+    pub(crate) fn subtree_range(&self) -> Range<*const u8> {
+        self.subtree_range.clone()
+    }
+
+    /// Returns whether `data_address` points into a subtree that is currently being validated.
+    #[inline]
+    fn is_ancestor(&self, data_address: *const u8) -> bool {
+        if let Some(ancestors) = self.ancestors.as_ref() {
+            ancestors
+                .iter()
+                .any(|range| range.start == data_address || range.contains(&data_address))
+        } else {
+            false
         }
     }
 
@@ -260,18 +491,38 @@ impl<'a> ArchiveContext for ArchiveValidator<'a> {
         let base_pos = base.offset_from(self.bytes.as_ptr());
         let target_pos = base_pos
             .checked_add(offset)
-            .ok_or(ArchiveError::Overflow { base, offset })?;
+            .ok_or_else(|| ArchiveError::Overflow {
+                base,
+                offset,
+                position: ArchivePosition::new(self.bytes, base),
+            })?;
         if target_pos < 0 || target_pos as usize > self.bytes.len() {
             Err(ArchiveError::OutOfBounds {
                 base,
                 offset,
                 range: self.bytes.as_ptr_range(),
+                position: ArchivePosition::new(self.bytes, base),
             })
         } else {
             Ok(base.offset(offset))
         }
     }
 
+    #[cfg(feature = "debug_rel_ptr")]
+    #[inline]
+    unsafe fn check_rel_ptr_tag<O: crate::rel_ptr::Offset>(
+        &mut self,
+        raw_ptr: &crate::rel_ptr::RawRelPtr<O>,
+    ) -> Result<(), Self::Error> {
+        if raw_ptr.is_tag_valid() {
+            Ok(())
+        } else {
+            Err(ArchiveError::InvalidRelPtrTag {
+                position: ArchivePosition::new(self.bytes, raw_ptr.base()),
+            })
+        }
+    }
+
     #[inline]
     unsafe fn bounds_check_layout(
         &mut self,
@@ -287,6 +538,7 @@ impl<'a> ArchiveContext for ArchiveValidator<'a> {
             Err(ArchiveError::Unaligned {
                 ptr: data_address,
                 align: layout.align(),
+                position: ArchivePosition::new(self.bytes, data_address),
             })
         } else {
             let available_space = self.bytes.as_ptr_range().end.offset_from(data_address) as usize;
@@ -295,6 +547,7 @@ impl<'a> ArchiveContext for ArchiveValidator<'a> {
                     ptr: data_address,
                     size: layout.size(),
                     range: self.bytes.as_ptr_range(),
+                    position: ArchivePosition::new(self.bytes, data_address),
                 })
             } else {
                 Ok(())
@@ -308,11 +561,16 @@ impl<'a> ArchiveContext for ArchiveValidator<'a> {
         data_address: *const u8,
         layout: &Layout,
     ) -> Result<(), Self::Error> {
-        if layout.size() == 0 {
+        if layout.size() != 0 && self.is_ancestor(data_address) {
+            Err(ArchiveError::CyclicPointer {
+                position: ArchivePosition::new(self.bytes, data_address),
+            })
+        } else if layout.size() == 0 {
             if data_address < self.subtree_range.start || data_address > self.subtree_range.end {
                 Err(ArchiveError::SubtreePointerOutOfBounds {
                     ptr: data_address,
                     subtree_range: self.subtree_range.clone(),
+                    position: ArchivePosition::new(self.bytes, data_address),
                 })
             } else {
                 Ok(())
@@ -321,6 +579,7 @@ impl<'a> ArchiveContext for ArchiveValidator<'a> {
             Err(ArchiveError::SubtreePointerOutOfBounds {
                 ptr: data_address,
                 subtree_range: self.subtree_range.clone(),
+                position: ArchivePosition::new(self.bytes, data_address),
             })
         } else {
             let available_space = self.subtree_range.end.offset_from(data_address) as usize;
@@ -329,6 +588,7 @@ impl<'a> ArchiveContext for ArchiveValidator<'a> {
                     ptr: data_address,
                     size: layout.size(),
                     subtree_range: self.subtree_range.clone(),
+                    position: ArchivePosition::new(self.bytes, data_address),
                 })
             } else {
                 Ok(())
@@ -356,6 +616,9 @@ impl<'a> ArchiveContext for ArchiveValidator<'a> {
             };
             self.subtree_depth += 1;
             self.subtree_range.end = root;
+            if let Some(ancestors) = self.ancestors.as_mut() {
+                ancestors.push(root..end);
+            }
             Ok(result)
         }
     }
@@ -370,6 +633,9 @@ impl<'a> ArchiveContext for ArchiveValidator<'a> {
         } else {
             self.subtree_range = range.range;
             self.subtree_depth = range.depth;
+            if let Some(ancestors) = self.ancestors.as_mut() {
+                ancestors.pop();
+            }
             Ok(())
         }
     }
@@ -387,6 +653,9 @@ impl<'a> ArchiveContext for ArchiveValidator<'a> {
         self.subtree_depth += 1;
         self.subtree_range.start = start;
         self.subtree_range.end = root;
+        if let Some(ancestors) = self.ancestors.as_mut() {
+            ancestors.push(root..root);
+        }
         Ok(result)
     }
 
@@ -401,6 +670,9 @@ impl<'a> ArchiveContext for ArchiveValidator<'a> {
             self.subtree_range.end = self.subtree_range.start;
             self.subtree_range.start = range.start;
             self.subtree_depth = range.depth;
+            if let Some(ancestors) = self.ancestors.as_mut() {
+                ancestors.pop();
+            }
             Ok(())
         }
     }
@@ -1,11 +1,14 @@
 //! Validators that can check archived types.
 
 mod archive;
+#[cfg(all(feature = "rayon", feature = "std"))]
+mod parallel;
 mod shared;
 mod util;
 
 use crate::{
     validation::{
+        check_archived_field_with_context, check_archived_prefix_with_context,
         check_archived_root_with_context, check_archived_value_with_context, ArchiveContext,
         CheckTypeError, SharedContext,
     },
@@ -13,7 +16,9 @@ use crate::{
 };
 pub use archive::*;
 use bytecheck::CheckBytes;
-use core::{alloc::Layout, any::TypeId, fmt};
+use core::{alloc::Layout, any::TypeId, fmt, mem};
+#[cfg(all(feature = "rayon", feature = "std"))]
+pub use parallel::*;
 pub use shared::*;
 pub use util::*;
 
@@ -65,6 +70,39 @@ impl<'a> DefaultValidator<'a> {
             shared: SharedValidator::new(),
         }
     }
+
+    /// Creates a new validator from a byte range that reports pointer cycles as
+    /// [`ArchiveError::CyclicPointer`] errors.
+    ///
+    /// See [`ArchiveValidator::with_cycle_detection`] for more details.
+    ///
+    /// # Examples
+    /// ```
+    /// use rkyv::{
+    ///     ser::{Serializer, serializers::AllocSerializer},
+    ///     validation::{check_archived_root_with_context, validators::DefaultValidator},
+    ///     Archive,
+    /// };
+    ///
+    /// let mut serializer = AllocSerializer::<256>::default();
+    /// serializer.serialize_value(&vec![Box::new(1), Box::new(2)]).unwrap();
+    /// let buf = serializer.into_serializer().into_inner();
+    ///
+    /// let mut validator = DefaultValidator::with_cycle_detection(buf.as_ref());
+    /// let archived = check_archived_root_with_context::<Vec<Box<i32>>, _>(
+    ///     buf.as_ref(),
+    ///     &mut validator,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(*archived[1], 2);
+    /// ```
+    #[inline]
+    pub fn with_cycle_detection(bytes: &'a [u8]) -> Self {
+        Self {
+            archive: ArchiveValidator::with_cycle_detection(bytes),
+            shared: SharedValidator::new(),
+        }
+    }
 }
 
 impl<'a> Fallible for DefaultValidator<'a> {
@@ -86,6 +124,17 @@ impl<'a> ArchiveContext for DefaultValidator<'a> {
             .map_err(DefaultValidatorError::ArchiveError)
     }
 
+    #[cfg(feature = "debug_rel_ptr")]
+    #[inline]
+    unsafe fn check_rel_ptr_tag<O: crate::rel_ptr::Offset>(
+        &mut self,
+        raw_ptr: &crate::rel_ptr::RawRelPtr<O>,
+    ) -> Result<(), Self::Error> {
+        self.archive
+            .check_rel_ptr_tag(raw_ptr)
+            .map_err(DefaultValidatorError::ArchiveError)
+    }
+
     #[inline]
     unsafe fn bounds_check_layout(
         &mut self,
@@ -227,3 +276,181 @@ where
     let mut validator = DefaultValidator::new(bytes);
     check_archived_root_with_context::<T, DefaultValidator>(bytes, &mut validator)
 }
+
+/// Checks the given archive for an archived header at the start of the archive without checking
+/// the rest of the archive.
+///
+/// The header must be the first value serialized into the archive so that it's located at position
+/// 0. Only the header and the bytes reachable from it are checked, which makes it possible to read
+/// something like a version number before deciding how to check the rest of the archive. This
+/// two-stage validation is common for formats that need to stay forward or backward compatible.
+///
+/// # Examples
+/// ```
+/// use rkyv::{
+///     check_archived_prefix,
+///     ser::{Serializer, serializers::AllocSerializer},
+///     Archive,
+///     Serialize,
+/// };
+/// use bytecheck::CheckBytes;
+///
+/// #[derive(Archive, Serialize)]
+/// #[archive_attr(derive(CheckBytes))]
+/// struct Header {
+///     version: u32,
+/// }
+///
+/// let mut serializer = AllocSerializer::<256>::default();
+/// serializer.serialize_value(&Header { version: 3 }).unwrap();
+/// serializer.serialize_value(&vec![1, 2, 3]).unwrap();
+/// let buf = serializer.into_serializer().into_inner();
+///
+/// let header = check_archived_prefix::<Header>(buf.as_ref()).unwrap();
+/// assert_eq!(header.version, 3);
+/// ```
+#[inline]
+pub fn check_archived_prefix<'a, T: Archive>(
+    bytes: &'a [u8],
+) -> Result<&'a T::Archived, CheckTypeError<T::Archived, DefaultValidator<'a>>>
+where
+    T::Archived: CheckBytes<DefaultValidator<'a>>,
+{
+    let mut validator = DefaultValidator::new(bytes);
+    check_archived_prefix_with_context::<T, DefaultValidator>(bytes, &mut validator)
+}
+
+/// Checks a single field of the archived root at the given position without checking the rest of
+/// the archive.
+///
+/// `accessor` is given a pointer to the unchecked root and must return a pointer to a field located
+/// inside of it, typically with [`ptr::addr_of!`](core::ptr::addr_of). Only the field and the
+/// bytes reachable from it are checked, so fields that are never accessed don't have to be checked.
+/// The root itself must be located entirely inside the archive.
+///
+/// # Examples
+/// ```
+/// use rkyv::{
+///     check_archived_field,
+///     ser::{Serializer, serializers::AllocSerializer},
+///     Archive,
+///     Serialize,
+/// };
+/// use bytecheck::CheckBytes;
+/// use core::ptr;
+///
+/// #[derive(Archive, Serialize)]
+/// #[archive_attr(derive(CheckBytes))]
+/// struct Example {
+///     name: String,
+///     value: i32,
+/// }
+///
+/// let value = Example {
+///     name: "pi".to_string(),
+///     value: 31415926,
+/// };
+///
+/// let mut serializer = AllocSerializer::<256>::default();
+/// let pos = serializer.serialize_value(&value).unwrap();
+/// let buf = serializer.into_serializer().into_inner();
+///
+/// let name = check_archived_field::<Example, _, _>(buf.as_ref(), pos, |root| unsafe {
+///     ptr::addr_of!((*root).name)
+/// })
+/// .unwrap();
+/// assert_eq!(name, "pi");
+/// ```
+///
+/// # Panics
+///
+/// Panics if the pointer returned by `accessor` does not point to a value located inside the root.
+#[inline]
+pub fn check_archived_field<'a, T, U, F>(
+    bytes: &'a [u8],
+    root_pos: usize,
+    accessor: F,
+) -> Result<&'a U, CheckTypeError<U, DefaultValidator<'a>>>
+where
+    T: Archive,
+    U: CheckBytes<DefaultValidator<'a>>,
+    F: FnOnce(*const T::Archived) -> *const U,
+{
+    let mut validator = DefaultValidator::new(bytes);
+    check_archived_field_with_context::<T, U, DefaultValidator, F>(
+        bytes,
+        root_pos,
+        &mut validator,
+        accessor,
+    )
+}
+
+/// An error that can occur while checking a possibly-unaligned archive.
+#[derive(Debug)]
+pub enum CheckUnalignedError<E> {
+    /// The bytes were not aligned enough to access the archive in place
+    Unaligned {
+        /// The alignment required by the root of the archive
+        required: usize,
+        /// The actual alignment of the bytes
+        actual: usize,
+    },
+    /// An error occurred while checking the archive
+    CheckError(E),
+}
+
+impl<E: fmt::Display> fmt::Display for CheckUnalignedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unaligned { required, actual } => write!(
+                f,
+                "archive bytes unaligned: need alignment {} but have alignment {}; copy the bytes \
+                into an AlignedVec to access them",
+                required, actual
+            ),
+            Self::CheckError(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use std::error::Error;
+
+    impl<E: Error + 'static> Error for CheckUnalignedError<E> {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                Self::Unaligned { .. } => None,
+                Self::CheckError(e) => Some(e as &dyn Error),
+            }
+        }
+    }
+};
+
+/// Checks the given possibly-unaligned bytes for an archived version of the given type at the end
+/// of the bytes.
+///
+/// Bytes that come from sources like memory-mapped files may not be aligned for the archive they
+/// contain. This explicitly checks that the bytes are aligned for the root of the archive before
+/// checking the archive in place, so no bytes are copied. If the bytes are not aligned enough, this
+/// returns [`CheckUnalignedError::Unaligned`] and the bytes should be copied into an
+/// [`AlignedVec`](crate::AlignedVec) instead.
+///
+/// Any values in the archive that require a greater alignment than the root are still checked and
+/// return an [`ArchiveError::Underaligned`] error if the bytes are not aligned enough for them.
+///
+/// See [`check_archived_root`] for more details.
+#[inline]
+pub fn check_archived_root_unaligned<'a, T: Archive>(
+    bytes: &'a [u8],
+) -> Result<&'a T::Archived, CheckUnalignedError<CheckTypeError<T::Archived, DefaultValidator<'a>>>>
+where
+    T::Archived: CheckBytes<DefaultValidator<'a>>,
+{
+    let required = mem::align_of::<T::Archived>();
+    let actual = 1 << (bytes.as_ptr() as usize).trailing_zeros();
+    if actual < required {
+        return Err(CheckUnalignedError::Unaligned { required, actual });
+    }
+    check_archived_root::<T>(bytes).map_err(CheckUnalignedError::CheckError)
+}
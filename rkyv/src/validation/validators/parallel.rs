@@ -0,0 +1,360 @@
+use crate::{
+    validation::{
+        validators::{ArchiveError, ArchiveValidator, PrefixRange, SuffixRange},
+        ArchiveContext, CheckArchiveError, CheckTypeError,
+    },
+    vec::ArchivedVec,
+    Archive, Fallible,
+};
+use bytecheck::{CheckBytes, SliceCheckError};
+use core::{alloc::Layout, fmt, mem, ops::Range};
+use rayon::prelude::*;
+use std::{error::Error, vec::Vec};
+
+/// Errors that can occur when checking an archive in parallel.
+#[derive(Debug)]
+pub enum ParallelValidatorError {
+    /// An archive validator error occurred.
+    ArchiveError(ArchiveError),
+    /// An element claimed memory that was already claimed by a previous element.
+    OverlappingClaims {
+        /// The index of the element
+        index: usize,
+    },
+}
+
+impl fmt::Display for ParallelValidatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ArchiveError(e) => write!(f, "{}", e),
+            Self::OverlappingClaims { index } => write!(
+                f,
+                "element {} claimed memory that overlaps with a previous element",
+                index
+            ),
+        }
+    }
+}
+
+impl Error for ParallelValidatorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ArchiveError(e) => Some(e as &dyn Error),
+            Self::OverlappingClaims { .. } => None,
+        }
+    }
+}
+
+/// A validator that checks the elements of an archived root `Vec` in parallel.
+///
+/// Each element is checked with its own validator, which records the range of memory claimed by
+/// the subtrees of that element. Once every element has been checked, the claimed ranges are
+/// required to be in order and not overlap, which matches the guarantees of the
+/// [`DefaultValidator`](super::DefaultValidator).
+///
+/// This validator does not implement [`SharedContext`](crate::validation::SharedContext), so types
+/// that contain shared pointers like `Rc` and `Arc` can't be checked with it.
+#[derive(Debug)]
+pub struct ParallelValidator<'a> {
+    archive: ArchiveValidator<'a>,
+    claimed: Option<Range<usize>>,
+}
+
+impl<'a> ParallelValidator<'a> {
+    /// Creates a new validator from a byte range.
+    #[inline]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            archive: ArchiveValidator::new(bytes),
+            claimed: None,
+        }
+    }
+
+    #[inline]
+    fn with_subtree_range(bytes: &'a [u8], subtree_range: Range<*const u8>) -> Self {
+        Self {
+            archive: ArchiveValidator::with_subtree_range(bytes, subtree_range),
+            claimed: None,
+        }
+    }
+
+    #[inline]
+    fn claim(&mut self, start: *const u8, end: *const u8) {
+        let (start, end) = (start as usize, end as usize);
+        self.claimed = Some(match self.claimed.take() {
+            Some(claimed) => claimed.start.min(start)..claimed.end.max(end),
+            None => start..end,
+        });
+    }
+}
+
+impl<'a> Fallible for ParallelValidator<'a> {
+    type Error = ParallelValidatorError;
+}
+
+impl<'a> ArchiveContext for ParallelValidator<'a> {
+    type PrefixRange = PrefixRange;
+    type SuffixRange = SuffixRange;
+
+    #[inline]
+    unsafe fn bounds_check_ptr(
+        &mut self,
+        base: *const u8,
+        offset: isize,
+    ) -> Result<*const u8, Self::Error> {
+        self.archive
+            .bounds_check_ptr(base, offset)
+            .map_err(ParallelValidatorError::ArchiveError)
+    }
+
+    #[cfg(feature = "debug_rel_ptr")]
+    #[inline]
+    unsafe fn check_rel_ptr_tag<O: crate::rel_ptr::Offset>(
+        &mut self,
+        raw_ptr: &crate::rel_ptr::RawRelPtr<O>,
+    ) -> Result<(), Self::Error> {
+        self.archive
+            .check_rel_ptr_tag(raw_ptr)
+            .map_err(ParallelValidatorError::ArchiveError)
+    }
+
+    #[inline]
+    unsafe fn bounds_check_layout(
+        &mut self,
+        data_address: *const u8,
+        layout: &Layout,
+    ) -> Result<(), Self::Error> {
+        self.archive
+            .bounds_check_layout(data_address, layout)
+            .map_err(ParallelValidatorError::ArchiveError)
+    }
+
+    #[inline]
+    unsafe fn bounds_check_subtree_ptr_layout(
+        &mut self,
+        data_address: *const u8,
+        layout: &Layout,
+    ) -> Result<(), Self::Error> {
+        self.archive
+            .bounds_check_subtree_ptr_layout(data_address, layout)
+            .map_err(ParallelValidatorError::ArchiveError)?;
+        self.claim(data_address, data_address.add(layout.size()));
+        Ok(())
+    }
+
+    #[inline]
+    unsafe fn push_prefix_subtree_range(
+        &mut self,
+        root: *const u8,
+        end: *const u8,
+    ) -> Result<PrefixRange, Self::Error> {
+        let range = self
+            .archive
+            .push_prefix_subtree_range(root, end)
+            .map_err(ParallelValidatorError::ArchiveError)?;
+        self.claim(root, end);
+        Ok(range)
+    }
+
+    #[inline]
+    fn pop_prefix_range(&mut self, range: PrefixRange) -> Result<(), Self::Error> {
+        self.archive
+            .pop_prefix_range(range)
+            .map_err(ParallelValidatorError::ArchiveError)
+    }
+
+    #[inline]
+    unsafe fn push_suffix_subtree_range(
+        &mut self,
+        start: *const u8,
+        root: *const u8,
+    ) -> Result<SuffixRange, Self::Error> {
+        let range = self
+            .archive
+            .push_suffix_subtree_range(start, root)
+            .map_err(ParallelValidatorError::ArchiveError)?;
+        self.claim(start, root);
+        Ok(range)
+    }
+
+    #[inline]
+    fn pop_suffix_range(&mut self, range: SuffixRange) -> Result<(), Self::Error> {
+        self.archive
+            .pop_suffix_range(range)
+            .map_err(ParallelValidatorError::ArchiveError)
+    }
+
+    #[inline]
+    fn finish(&mut self) -> Result<(), Self::Error> {
+        self.archive
+            .finish()
+            .map_err(ParallelValidatorError::ArchiveError)
+    }
+}
+
+struct ElementsPtr<T>(*const T);
+
+// SAFETY: ElementsPtr is only used to read the elements being checked, which are never mutated
+unsafe impl<T> Send for ElementsPtr<T> {}
+
+// SAFETY: ElementsPtr is only used to read the elements being checked, which are never mutated
+unsafe impl<T> Sync for ElementsPtr<T> {}
+
+impl<T> ElementsPtr<T> {
+    #[inline]
+    fn get(&self) -> *const T {
+        self.0
+    }
+}
+
+enum ElementError<E> {
+    CheckBytes(E),
+    Context(ParallelValidatorError),
+}
+
+unsafe fn check_element<'a, T>(
+    bytes: &'a [u8],
+    element: *const T,
+    subtree_range: Range<usize>,
+) -> Result<Option<Range<usize>>, ElementError<T::Error>>
+where
+    T: CheckBytes<ParallelValidator<'a>>,
+{
+    let mut validator = ParallelValidator::with_subtree_range(
+        bytes,
+        subtree_range.start as *const u8..subtree_range.end as *const u8,
+    );
+    T::check_bytes(element, &mut validator).map_err(ElementError::CheckBytes)?;
+    validator.finish().map_err(ElementError::Context)?;
+    Ok(validator.claimed)
+}
+
+unsafe fn check_elements_parallel<'a, T>(
+    bytes: &'a [u8],
+    elements: *const [T],
+    subtree_range: Range<*const u8>,
+    context_error: &mut Option<ParallelValidatorError>,
+) -> Result<(), SliceCheckError<T::Error>>
+where
+    T: CheckBytes<ParallelValidator<'a>>,
+{
+    let data = ElementsPtr(elements.cast::<T>());
+    let len = ptr_meta::metadata(elements);
+    let subtree_range = subtree_range.start as usize..subtree_range.end as usize;
+
+    // Errors may not be sendable between threads, so only the claimed ranges of valid elements are
+    // collected. The first invalid element is checked again afterward to get its error.
+    let results = (0..len)
+        .into_par_iter()
+        .map(|index| check_element(bytes, data.get().add(index), subtree_range.clone()).ok())
+        .collect::<Vec<_>>();
+
+    let mut claimed_end = subtree_range.start;
+    for (index, result) in results.into_iter().enumerate() {
+        let claimed = match result {
+            Some(claimed) => claimed,
+            None => match check_element(bytes, data.get().add(index), subtree_range.clone()) {
+                Ok(claimed) => claimed,
+                Err(ElementError::CheckBytes(error)) => {
+                    return Err(SliceCheckError::CheckBytes { index, error })
+                }
+                Err(ElementError::Context(error)) => {
+                    *context_error = Some(error);
+                    return Ok(());
+                }
+            },
+        };
+        if let Some(claimed) = claimed {
+            if claimed.start < claimed_end {
+                *context_error = Some(ParallelValidatorError::OverlappingClaims { index });
+                return Ok(());
+            }
+            claimed_end = claimed.end;
+        }
+    }
+
+    Ok(())
+}
+
+/// The error type for [`check_archived_root_parallel`].
+pub type CheckParallelError<'a, T> =
+    CheckTypeError<ArchivedVec<<T as Archive>::Archived>, ParallelValidator<'a>>;
+
+/// Checks the given archive for an archived root `Vec<T>`, checking its elements in parallel.
+///
+/// The elements are checked across the global rayon thread pool. This accepts exactly the same
+/// archives as calling [`check_archived_root`](super::check_archived_root) with `Vec<T>`, but may
+/// report a different error for invalid archives. If multiple elements are invalid, the error for
+/// the element with the lowest index is returned.
+///
+/// Types that contain shared pointers like `Rc` and `Arc` can't be checked in parallel.
+///
+/// # Examples
+/// ```
+/// use rkyv::{check_archived_root_parallel, Archive, Serialize};
+/// use bytecheck::CheckBytes;
+///
+/// #[derive(Archive, Serialize)]
+/// #[archive_attr(derive(CheckBytes))]
+/// struct Example {
+///     name: String,
+///     value: i32,
+/// }
+///
+/// let values = (0..100)
+///     .map(|i| Example {
+///         name: format!("example {}", i),
+///         value: i,
+///     })
+///     .collect::<Vec<_>>();
+///
+/// let bytes = rkyv::to_bytes::<_, 256>(&values).unwrap();
+/// let archived = check_archived_root_parallel::<Example>(&bytes).unwrap();
+/// assert_eq!(archived[42].name, "example 42");
+/// ```
+pub fn check_archived_root_parallel<'a, T>(
+    bytes: &'a [u8],
+) -> Result<&'a ArchivedVec<T::Archived>, CheckParallelError<'a, T>>
+where
+    T: Archive,
+    T::Archived: CheckBytes<ParallelValidator<'a>>,
+{
+    let mut validator = ParallelValidator::new(bytes);
+    let mut context_error = None;
+    let pos = bytes.len() as isize - mem::size_of::<ArchivedVec<T::Archived>>() as isize;
+
+    unsafe {
+        let ptr = validator
+            .check_subtree_ptr::<ArchivedVec<T::Archived>>(bytes.as_ptr(), pos, ())
+            .map_err(CheckArchiveError::ContextError)?;
+
+        let range = validator
+            .push_prefix_subtree(ptr)
+            .map_err(CheckArchiveError::ContextError)?;
+        let result = ArchivedVec::check_bytes_with::<ParallelValidator, _>(
+            ptr,
+            &mut validator,
+            |elements, context| {
+                check_elements_parallel(
+                    bytes,
+                    elements,
+                    context.archive.subtree_range(),
+                    &mut context_error,
+                )
+            },
+        )
+        .map_err(CheckArchiveError::CheckBytesError)?;
+        validator
+            .pop_prefix_range(range)
+            .map_err(CheckArchiveError::ContextError)?;
+
+        if let Some(error) = context_error {
+            return Err(CheckArchiveError::ContextError(error));
+        }
+
+        validator
+            .finish()
+            .map_err(CheckArchiveError::ContextError)?;
+        Ok(result)
+    }
+}
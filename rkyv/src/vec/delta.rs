@@ -0,0 +1,408 @@
+#[cfg(feature = "alloc")]
+use crate::ser::{ScratchSpace, Serializer};
+use crate::{
+    vec::{ArchivedVec, VecResolver},
+    Archive, Archived,
+};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use ::alloc::vec::Vec;
+use core::{fmt, iter::FusedIterator};
+
+/// An archived `Vec<u64>` that stores the differences between successive values.
+///
+/// Each value after the first is stored as the difference from the previous value, encoded as a
+/// zigzag varint. Sequences of values that are close together (like timestamps) take only a byte or
+/// two per value, and sequences that are not monotonic are still supported.
+///
+/// Every [`BLOCK_SIZE`](ArchivedDeltaVec::BLOCK_SIZE) values, a checkpoint is stored with the
+/// absolute value and the position of the following deltas. Random access starts from the nearest
+/// checkpoint, so [`get`](ArchivedDeltaVec::get) only has to sum at most `BLOCK_SIZE - 1` deltas.
+///
+/// This is the archived type for the [`DeltaEncoded`](crate::with::DeltaEncoded) wrapper.
+#[cfg_attr(feature = "strict", repr(C))]
+pub struct ArchivedDeltaVec {
+    checkpoints: ArchivedVec<Archived<u64>>,
+    offsets: ArchivedVec<Archived<usize>>,
+    deltas: ArchivedVec<u8>,
+    len: Archived<usize>,
+}
+
+#[inline]
+fn block_count(len: usize) -> usize {
+    // Equivalent to `len.div_ceil(BLOCK_SIZE)`, which isn't available on the MSRV
+    if len == 0 {
+        0
+    } else {
+        (len - 1) / ArchivedDeltaVec::BLOCK_SIZE + 1
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+fn write_delta(bytes: &mut Vec<u8>, delta: u64) {
+    let delta = delta as i64;
+    let mut zigzag = ((delta << 1) ^ (delta >> 63)) as u64;
+    while zigzag >= 0x80 {
+        bytes.push(zigzag as u8 | 0x80);
+        zigzag >>= 7;
+    }
+    bytes.push(zigzag as u8);
+}
+
+/// Reads the delta starting at `pos`, advancing `pos` past it. Returns `None` if the delta is
+/// truncated or does not fit in a `u64`.
+#[inline]
+fn read_delta(bytes: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut zigzag = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes.get(*pos)?;
+        *pos += 1;
+        if shift == 63 && byte > 1 {
+            return None;
+        }
+        zigzag |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some((zigzag >> 1) ^ (zigzag & 1).wrapping_neg());
+        }
+        shift += 7;
+        if shift > 63 {
+            return None;
+        }
+    }
+}
+
+impl ArchivedDeltaVec {
+    /// The number of values between each checkpoint.
+    pub const BLOCK_SIZE: usize = 32;
+
+    /// Returns the number of values in the archived vec.
+    #[inline]
+    pub fn len(&self) -> usize {
+        from_archived!(self.len) as usize
+    }
+
+    /// Returns whether the archived vec is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value at the given index, or `None` if the index is out of bounds.
+    ///
+    /// This sums the deltas from the checkpoint before `index`, so it takes at most
+    /// [`BLOCK_SIZE`](ArchivedDeltaVec::BLOCK_SIZE) steps.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<u64> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let block = index / Self::BLOCK_SIZE;
+        let mut value = from_archived!(*self.checkpoints.get(block)?);
+        let mut pos = from_archived!(*self.offsets.get(block)?) as usize;
+        for _ in 0..index % Self::BLOCK_SIZE {
+            value = value.wrapping_add(read_delta(&self.deltas, &mut pos)?);
+        }
+        Some(value)
+    }
+
+    /// Returns an iterator over the values of the archived vec.
+    #[inline]
+    pub fn iter(&self) -> DeltaIter<'_> {
+        DeltaIter {
+            inner: self,
+            index: 0,
+            value: 0,
+            pos: 0,
+        }
+    }
+
+    /// Resolves an archived delta vec from a given slice.
+    ///
+    /// # Safety
+    ///
+    /// - `pos` must be the position of `out` within the archive
+    /// - `resolver` must be the result of serializing `values` with
+    ///   [`serialize_from_slice`](ArchivedDeltaVec::serialize_from_slice).
+    #[inline]
+    pub unsafe fn resolve_from_slice(
+        values: &[u64],
+        pos: usize,
+        resolver: DeltaVecResolver,
+        out: *mut Self,
+    ) {
+        let block_count = block_count(values.len());
+
+        let (fp, fo) = out_field!(out.checkpoints);
+        ArchivedVec::resolve_from_len(block_count, pos + fp, resolver.checkpoints, fo);
+        let (fp, fo) = out_field!(out.offsets);
+        ArchivedVec::resolve_from_len(block_count, pos + fp, resolver.offsets, fo);
+        let (fp, fo) = out_field!(out.deltas);
+        ArchivedVec::resolve_from_len(resolver.deltas_len, pos + fp, resolver.deltas, fo);
+        let (fp, fo) = out_field!(out.len);
+        values.len().resolve(pos + fp, (), fo);
+    }
+
+    /// Serializes an archived delta vec from a given slice.
+    #[cfg(feature = "alloc")]
+    pub fn serialize_from_slice<S>(
+        values: &[u64],
+        serializer: &mut S,
+    ) -> Result<DeltaVecResolver, S::Error>
+    where
+        S: ScratchSpace + Serializer + ?Sized,
+    {
+        let block_count = block_count(values.len());
+        let mut checkpoints = Vec::with_capacity(block_count);
+        let mut offsets = Vec::with_capacity(block_count);
+        let mut deltas = Vec::new();
+
+        for block in values.chunks(Self::BLOCK_SIZE) {
+            checkpoints.push(block[0]);
+            offsets.push(deltas.len());
+            for pair in block.windows(2) {
+                write_delta(&mut deltas, pair[1].wrapping_sub(pair[0]));
+            }
+        }
+
+        Ok(DeltaVecResolver {
+            checkpoints: ArchivedVec::serialize_from_slice(&checkpoints, serializer)?,
+            offsets: ArchivedVec::serialize_from_slice(&offsets, serializer)?,
+            // SAFETY: u8 is copy-safe
+            deltas: unsafe { ArchivedVec::serialize_copy_from_slice(&deltas, serializer)? },
+            deltas_len: deltas.len(),
+        })
+    }
+}
+
+impl fmt::Debug for ArchivedDeltaVec {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> IntoIterator for &'a ArchivedDeltaVec {
+    type Item = u64;
+    type IntoIter = DeltaIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl PartialEq for ArchivedDeltaVec {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for ArchivedDeltaVec {}
+
+impl PartialEq<[u64]> for ArchivedDeltaVec {
+    #[inline]
+    fn eq(&self, other: &[u64]) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter().copied())
+    }
+}
+
+impl PartialEq<ArchivedDeltaVec> for [u64] {
+    #[inline]
+    fn eq(&self, other: &ArchivedDeltaVec) -> bool {
+        other.eq(self)
+    }
+}
+
+/// An iterator over the values of an [`ArchivedDeltaVec`].
+pub struct DeltaIter<'a> {
+    inner: &'a ArchivedDeltaVec,
+    index: usize,
+    value: u64,
+    pos: usize,
+}
+
+impl Iterator for DeltaIter<'_> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.inner.len() {
+            return None;
+        }
+
+        let block = self.index / ArchivedDeltaVec::BLOCK_SIZE;
+        if self.index == block * ArchivedDeltaVec::BLOCK_SIZE {
+            self.value = from_archived!(*self.inner.checkpoints.get(block)?);
+            self.pos = from_archived!(*self.inner.offsets.get(block)?) as usize;
+        } else {
+            let delta = read_delta(&self.inner.deltas, &mut self.pos)?;
+            self.value = self.value.wrapping_add(delta);
+        }
+        self.index += 1;
+        Some(self.value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.inner.len().saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for DeltaIter<'_> {}
+
+impl FusedIterator for DeltaIter<'_> {}
+
+/// The resolver for [`ArchivedDeltaVec`].
+pub struct DeltaVecResolver {
+    checkpoints: VecResolver,
+    offsets: VecResolver,
+    deltas: VecResolver,
+    deltas_len: usize,
+}
+
+#[cfg(feature = "validation")]
+pub use self::validation::*;
+
+#[cfg(feature = "validation")]
+mod validation {
+    use super::{block_count, read_delta, ArchivedDeltaVec};
+    use crate::{
+        validation::{owned::OwnedPointerError, ArchiveContext},
+        vec::ArchivedVec,
+        Archived,
+    };
+    use bytecheck::{CheckBytes, Error, SliceCheckError};
+    use core::{cmp, convert::Infallible, fmt, ptr};
+
+    /// Errors that can occur while checking an archived delta vec.
+    #[derive(Debug)]
+    pub enum DeltaVecError<C> {
+        /// The number of checkpoints did not match the length of the archived vec
+        InvalidCheckpointCount {
+            /// The number of checkpoints required for the length of the archived vec
+            expected: usize,
+            /// The number of checkpoints and offsets found
+            actual: (usize, usize),
+        },
+        /// A checkpoint offset did not point to the start of the deltas for its block
+        InvalidOffset {
+            /// The index of the checkpoint with the invalid offset
+            index: usize,
+        },
+        /// A delta was truncated or did not fit in a `u64`
+        InvalidDelta {
+            /// The index of the value with the invalid delta
+            index: usize,
+        },
+        /// There were bytes left over after the last delta
+        TrailingBytes,
+        /// A bounds error occurred
+        ContextError(C),
+    }
+
+    impl<C> From<Infallible> for DeltaVecError<C> {
+        #[inline]
+        fn from(_: Infallible) -> Self {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
+
+    impl<C> From<OwnedPointerError<Infallible, SliceCheckError<Infallible>, C>> for DeltaVecError<C> {
+        #[inline]
+        fn from(e: OwnedPointerError<Infallible, SliceCheckError<Infallible>, C>) -> Self {
+            match e {
+                OwnedPointerError::ContextError(e) => Self::ContextError(e),
+                _ => unsafe { core::hint::unreachable_unchecked() },
+            }
+        }
+    }
+
+    impl<C: fmt::Display> fmt::Display for DeltaVecError<C> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                DeltaVecError::InvalidCheckpointCount { expected, actual } => write!(
+                    f,
+                    "invalid checkpoint count: expected {} but found {} checkpoints and {} offsets",
+                    expected, actual.0, actual.1,
+                ),
+                DeltaVecError::InvalidOffset { index } => {
+                    write!(f, "invalid offset for checkpoint {}", index)
+                }
+                DeltaVecError::InvalidDelta { index } => {
+                    write!(f, "invalid delta for value {}", index)
+                }
+                DeltaVecError::TrailingBytes => write!(f, "trailing bytes after the last delta"),
+                DeltaVecError::ContextError(e) => e.fmt(f),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    const _: () = {
+        use std::error::Error;
+
+        impl<C: Error + 'static> Error for DeltaVecError<C> {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                match self {
+                    DeltaVecError::ContextError(e) => Some(e as &dyn Error),
+                    _ => None,
+                }
+            }
+        }
+    };
+
+    impl<C: ArchiveContext + ?Sized> CheckBytes<C> for ArchivedDeltaVec
+    where
+        C::Error: Error,
+    {
+        type Error = DeltaVecError<C::Error>;
+
+        unsafe fn check_bytes<'a>(
+            value: *const Self,
+            context: &mut C,
+        ) -> Result<&'a Self, Self::Error> {
+            let len = from_archived!(*Archived::<usize>::check_bytes(
+                ptr::addr_of!((*value).len),
+                context,
+            )?) as usize;
+            let checkpoints = ArchivedVec::<Archived<u64>>::check_bytes(
+                ptr::addr_of!((*value).checkpoints),
+                context,
+            )?;
+            let offsets = ArchivedVec::<Archived<usize>>::check_bytes(
+                ptr::addr_of!((*value).offsets),
+                context,
+            )?;
+            let deltas = ArchivedVec::<u8>::check_bytes(ptr::addr_of!((*value).deltas), context)?;
+
+            let expected = block_count(len);
+            if checkpoints.len() != expected || offsets.len() != expected {
+                return Err(DeltaVecError::InvalidCheckpointCount {
+                    expected,
+                    actual: (checkpoints.len(), offsets.len()),
+                });
+            }
+
+            let mut pos = 0;
+            for (block, offset) in offsets.iter().enumerate() {
+                if from_archived!(*offset) as usize != pos {
+                    return Err(DeltaVecError::InvalidOffset { index: block });
+                }
+                let start = block * ArchivedDeltaVec::BLOCK_SIZE;
+                let end = cmp::min(start + ArchivedDeltaVec::BLOCK_SIZE, len);
+                for index in start + 1..end {
+                    read_delta(deltas, &mut pos).ok_or(DeltaVecError::InvalidDelta { index })?;
+                }
+            }
+            if pos != deltas.len() {
+                return Err(DeltaVecError::TrailingBytes);
+            }
+
+            Ok(&*value)
+        }
+    }
+}
@@ -1,20 +1,28 @@
 //! An archived version of `Vec`.
 
+mod delta;
 mod raw;
 
 use crate::{
     ser::{ScratchSpace, Serializer},
     Archive, Archived, RelPtr, Serialize, SerializeUnsized,
 };
+#[cfg(feature = "alloc")]
+use crate::{Deserialize, Fallible};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use ::alloc::vec::Vec;
 use core::{
     borrow::Borrow,
     cmp, fmt, hash,
     ops::{Deref, Index, IndexMut},
     pin::Pin,
-    slice::SliceIndex,
+    slice::{self, SliceIndex},
 };
 
-pub use self::raw::*;
+pub use self::{delta::*, raw::*};
+
+/// A slice of an archived vec along with its position in the archive.
+type SliceWithPosition<'a, T> = (&'a [T], usize);
 
 /// An archived [`Vec`].
 ///
@@ -51,6 +59,139 @@ impl<T> ArchivedVec<T> {
         unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len()) }
     }
 
+    /// Binary searches this sorted archived vec with a comparator function.
+    ///
+    /// The comparator should return an order code that indicates whether its argument is `Less`,
+    /// `Equal` or `Greater` than the desired target. If the value is found then `Ok` is returned
+    /// with the index of the matching element. If it is not found then `Err` is returned with the
+    /// index where a matching element could be inserted while maintaining sorted order.
+    ///
+    /// See [`slice::binary_search_by`] for more details.
+    #[inline]
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> cmp::Ordering,
+    {
+        self.as_slice().binary_search_by(f)
+    }
+
+    /// Binary searches this sorted archived vec with a key extraction function.
+    ///
+    /// The archived vec must be sorted by the extracted key. The return value has the same
+    /// meaning as for [`binary_search_by`](ArchivedVec::binary_search_by).
+    ///
+    /// See [`slice::binary_search_by_key`] for more details.
+    #[inline]
+    pub fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.as_slice().binary_search_by_key(b, f)
+    }
+
+    /// Returns the index of the first element whose extracted key is greater than or equal to
+    /// `key`.
+    ///
+    /// The archived vec must be sorted by the extracted key. If every key is less than `key`, the
+    /// length of the vec is returned. Unlike
+    /// [`binary_search_by_key`](ArchivedVec::binary_search_by_key), the first of several equal keys
+    /// is always found, which makes this suitable for finding the start of a range.
+    ///
+    /// See [`slice::partition_point`] for more details.
+    #[inline]
+    pub fn partition_point_by_key<B, F>(&self, key: &B, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.as_slice().partition_point(|x| f(x) < *key)
+    }
+
+    /// Returns an iterator over the first element of each run of consecutive equal elements.
+    ///
+    /// If the archived vec is sorted, this yields each distinct element exactly once. Unlike
+    /// [`Vec::dedup`], the archived vec is not modified and nothing is allocated.
+    #[inline]
+    pub fn dedup_iter(&self) -> impl Iterator<Item = &T>
+    where
+        T: PartialEq,
+    {
+        let mut prev = None;
+        self.iter().filter(move |&element| {
+            let is_new = prev != Some(element);
+            prev = Some(element);
+            is_new
+        })
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the archived vec at a time, starting at
+    /// the beginning of the vec.
+    ///
+    /// The chunks are borrowed directly from the archive, so no elements are copied and each
+    /// chunk keeps its position in the archive. If `chunk_size` does not divide the length of the
+    /// vec, then the last up to `chunk_size - 1` elements are omitted and can be retrieved from
+    /// the `remainder` function of the iterator.
+    ///
+    /// See [`slice::chunks_exact`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    #[inline]
+    pub fn chunks_exact(&self, chunk_size: usize) -> slice::ChunksExact<'_, T> {
+        self.as_slice().chunks_exact(chunk_size)
+    }
+
+    /// Returns the byte offset of the element at `index`, relative to the first element of the
+    /// archived vec.
+    ///
+    /// Adding this offset to the position of the vec's elements in the archive yields the position
+    /// of the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn position_of(&self, index: usize) -> usize {
+        assert!(
+            index < self.len(),
+            "index out of bounds: the len is {} but the index is {}",
+            self.len(),
+            index,
+        );
+        index * core::mem::size_of::<T>()
+    }
+
+    /// Divides the archived vec into two slices at an index, and returns each slice along with its
+    /// position in the archive.
+    ///
+    /// `pos` is the position of the archived vec itself in the archive, like the position returned
+    /// by [`Serializer::serialize_value`](crate::ser::Serializer::serialize_value). The first slice
+    /// contains all elements in `[0, mid)` and starts at the position of the vec's elements. The
+    /// second slice contains all elements in `[mid, len)` and starts `mid * size_of::<T>()` bytes
+    /// later.
+    ///
+    /// See [`slice::split_at`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    #[inline]
+    pub fn split_at_with_position(
+        &self,
+        pos: usize,
+        mid: usize,
+    ) -> (SliceWithPosition<'_, T>, SliceWithPosition<'_, T>) {
+        let (left, right) = self.as_slice().split_at(mid);
+        // The elements may be located before or after the vec, so the distance between them wraps
+        let left_pos = pos
+            .wrapping_add(self.as_ptr() as usize)
+            .wrapping_sub(self as *const Self as usize);
+        let right_pos = left_pos + mid * core::mem::size_of::<T>();
+        ((left, left_pos), (right, right_pos))
+    }
+
     /// Gets the elements of the archived vec as a pinned mutable slice.
     #[inline]
     pub fn pin_mut_slice(self: Pin<&mut Self>) -> Pin<&mut [T]> {
@@ -59,6 +200,22 @@ impl<T> ArchivedVec<T> {
         }
     }
 
+    /// Returns an iterator over pinned mutable references to the elements of the archived vec.
+    ///
+    /// The elements stay pinned so that elements which contain relative pointers can be mutated in
+    /// place but can't be moved.
+    #[inline]
+    pub fn iter_pin_mut(
+        self: Pin<&mut Self>,
+    ) -> impl ExactSizeIterator<Item = Pin<&mut T>> + DoubleEndedIterator {
+        unsafe {
+            self.pin_mut_slice()
+                .get_unchecked_mut()
+                .iter_mut()
+                .map(|element| Pin::new_unchecked(element))
+        }
+    }
+
     // This method can go away once pinned slices have indexing support
     // https://github.com/rust-lang/rust/pull/78370
 
@@ -71,6 +228,22 @@ impl<T> ArchivedVec<T> {
         unsafe { self.pin_mut_slice().map_unchecked_mut(|s| &mut s[index]) }
     }
 
+    /// Sorts the elements of the archived vec in place with a comparator function.
+    ///
+    /// Sorting moves elements around in the archive, so it is only available for elements that are
+    /// [`Unpin`]. Every archived type that contains a relative pointer is `!Unpin`, so elements
+    /// that point to other parts of the archive can't be sorted this way.
+    ///
+    /// See [`slice::sort_unstable_by`] for more details.
+    #[inline]
+    pub fn sort_unstable_by<F>(self: Pin<&mut Self>, compare: F)
+    where
+        T: Unpin,
+        F: FnMut(&T, &T) -> cmp::Ordering,
+    {
+        self.pin_mut_slice().get_mut().sort_unstable_by(compare);
+    }
+
     /// Resolves an archived `Vec` from a given slice.
     ///
     /// # Safety
@@ -180,6 +353,102 @@ impl<T> ArchivedVec<T> {
     }
 }
 
+impl<T: ArchivedPrimitive> ArchivedVec<T> {
+    /// Gets the elements of the archived vec as raw bytes.
+    ///
+    /// Multibyte elements are returned as they are stored in the archive, so their byte order
+    /// depends on the `archive_le` and `archive_be` features.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        let slice = self.as_slice();
+        unsafe { slice::from_raw_parts(slice.as_ptr().cast::<u8>(), core::mem::size_of_val(slice)) }
+    }
+}
+
+/// An archived primitive type that can be viewed as raw bytes.
+///
+/// This is implemented for the archived versions of `bool`, `char`, and the fixed-size integer and
+/// floating-point types.
+///
+/// # Safety
+///
+/// Types implementing `ArchivedPrimitive` must not contain any padding or other uninitialized
+/// bytes.
+pub unsafe trait ArchivedPrimitive {}
+
+macro_rules! impl_archived_primitive {
+    ($($type:ty),* $(,)?) => {
+        $(
+            unsafe impl ArchivedPrimitive for Archived<$type> {}
+        )*
+    };
+}
+
+impl_archived_primitive!(bool, i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, f32, f64, char);
+
+#[cfg(feature = "alloc")]
+impl<T> ArchivedVec<T> {
+    /// Deserializes the archived vec into `target`, reusing its allocation.
+    ///
+    /// The previous contents of `target` are cleared, and `target` only grows if it does not have
+    /// enough capacity to hold all of the elements of the archived vec.
+    #[inline]
+    pub fn deserialize_in_place<U, D>(
+        &self,
+        target: &mut Vec<U>,
+        deserializer: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        T: Deserialize<U, D>,
+        D: Fallible + ?Sized,
+    {
+        target.clear();
+        target.reserve(self.len());
+        for item in self.iter() {
+            target.push(item.deserialize(deserializer)?);
+        }
+        Ok(())
+    }
+
+    /// Applies a fallible function to each archived element in order, collecting the results into
+    /// a `Vec`.
+    ///
+    /// `f` is given each element along with the deserializer, so it can deserialize the element
+    /// and then check or convert the deserialized value. This stops at the first element that `f`
+    /// returns an error for and returns that error without calling `f` on any later elements.
+    #[inline]
+    pub fn try_deserialize_map<U, D, E, F>(
+        &self,
+        deserializer: &mut D,
+        mut f: F,
+    ) -> Result<Vec<U>, E>
+    where
+        D: Fallible + ?Sized,
+        F: FnMut(&T, &mut D) -> Result<U, E>,
+    {
+        let mut result = Vec::with_capacity(self.len());
+        for item in self.iter() {
+            result.push(f(item, deserializer)?);
+        }
+        Ok(result)
+    }
+
+    /// Copies the elements of the archived vec into `target`, reusing its allocation.
+    ///
+    /// This can be used when the archived elements are also the unarchived elements (for example,
+    /// `u8`) to copy all of the elements at once instead of deserializing them one at a time. The
+    /// previous contents of `target` are cleared, and `target` only grows if it does not have enough
+    /// capacity to hold all of the elements of the archived vec.
+    #[inline]
+    pub fn copy_in_place(&self, target: &mut Vec<T>)
+    where
+        T: Copy,
+    {
+        target.clear();
+        target.extend_from_slice(self.as_slice());
+    }
+}
+
 impl<T> AsRef<[T]> for ArchivedVec<T> {
     #[inline]
     fn as_ref(&self) -> &[T] {
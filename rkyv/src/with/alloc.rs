@@ -4,10 +4,10 @@ use crate::{
     niche::option_box::{ArchivedOptionBox, OptionBoxResolver},
     ser::{ScratchSpace, Serializer},
     string::{ArchivedString, StringResolver},
-    vec::{ArchivedVec, RawArchivedVec, VecResolver},
+    vec::{ArchivedDeltaVec, ArchivedVec, DeltaVecResolver, RawArchivedVec, VecResolver},
     with::{
-        ArchiveWith, AsOwned, AsVec, CopyOptimize, DeserializeWith, Map, Niche, Raw, RefAsBox,
-        SerializeWith, With,
+        ArchiveWith, AsOwned, AsVec, CopyOptimize, DeltaEncoded, DeserializeWith, Map, Niche, Raw,
+        RefAsBox, SerializeWith, With,
     },
     Archive, ArchiveUnsized, ArchivedMetadata, Deserialize, DeserializeUnsized, Fallible,
     MetadataResolver, Serialize, SerializeUnsized,
@@ -48,7 +48,7 @@ where
 
 impl<A, O, S> SerializeWith<Vec<O>, S> for Map<A>
 where
-    S: Fallible + ScratchSpace + Serializer,
+    S: Fallible + ScratchSpace + Serializer + ?Sized,
     A: ArchiveWith<O> + SerializeWith<O, S>,
 {
     fn serialize_with(field: &Vec<O>, s: &mut S) -> Result<Self::Resolver, S::Error> {
@@ -73,7 +73,7 @@ where
         impl<A, O, S> Serialize<S> for RefWrapper<'_, A, O>
         where
             A: ArchiveWith<O> + SerializeWith<O, S>,
-            S: Fallible + Serializer,
+            S: Fallible + Serializer + ?Sized,
         {
             fn serialize(&self, s: &mut S) -> Result<Self::Resolver, S::Error> {
                 A::serialize_with(self.0, s)
@@ -165,21 +165,6 @@ impl<'a, T: Serialize<S> + Clone, S: ScratchSpace + Serializer + ?Sized>
     }
 }
 
-impl<'a, T, D> DeserializeWith<ArchivedVec<T::Archived>, Cow<'a, [T]>, D> for AsOwned
-where
-    T: Archive + Clone,
-    T::Archived: Deserialize<T, D>,
-    D: Fallible + ?Sized,
-{
-    #[inline]
-    fn deserialize_with(
-        field: &ArchivedVec<T::Archived>,
-        deserializer: &mut D,
-    ) -> Result<Cow<'a, [T]>, D::Error> {
-        Ok(Cow::Owned(field.deserialize(deserializer)?))
-    }
-}
-
 impl<'a> ArchiveWith<Cow<'a, str>> for AsOwned {
     type Archived = ArchivedString;
     type Resolver = StringResolver;
@@ -205,16 +190,6 @@ impl<'a, S: Serializer + ?Sized> SerializeWith<Cow<'a, str>, S> for AsOwned {
     }
 }
 
-impl<'a, D: Fallible + ?Sized> DeserializeWith<ArchivedString, Cow<'a, str>, D> for AsOwned {
-    #[inline]
-    fn deserialize_with(
-        field: &ArchivedString,
-        deserializer: &mut D,
-    ) -> Result<Cow<'a, str>, D::Error> {
-        Ok(Cow::Owned(field.deserialize(deserializer)?))
-    }
-}
-
 #[cfg(feature = "std")]
 const _: () = {
     use crate::ffi::{ArchivedCString, CStringResolver};
@@ -244,16 +219,6 @@ const _: () = {
             ArchivedCString::serialize_from_c_str(field, serializer)
         }
     }
-
-    impl<'a, D: Fallible + ?Sized> DeserializeWith<ArchivedCString, Cow<'a, CStr>, D> for AsOwned {
-        #[inline]
-        fn deserialize_with(
-            field: &ArchivedCString,
-            deserializer: &mut D,
-        ) -> Result<Cow<'a, CStr>, D::Error> {
-            Ok(Cow::Owned(field.deserialize(deserializer)?))
-        }
-    }
 };
 
 // AsVec
@@ -602,3 +567,34 @@ where
         Ok(result)
     }
 }
+
+// DeltaEncoded
+
+impl ArchiveWith<Vec<u64>> for DeltaEncoded {
+    type Archived = ArchivedDeltaVec;
+    type Resolver = DeltaVecResolver;
+
+    #[inline]
+    unsafe fn resolve_with(
+        field: &Vec<u64>,
+        pos: usize,
+        resolver: Self::Resolver,
+        out: *mut Self::Archived,
+    ) {
+        ArchivedDeltaVec::resolve_from_slice(field.as_slice(), pos, resolver, out);
+    }
+}
+
+impl<S: ScratchSpace + Serializer + ?Sized> SerializeWith<Vec<u64>, S> for DeltaEncoded {
+    #[inline]
+    fn serialize_with(field: &Vec<u64>, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedDeltaVec::serialize_from_slice(field.as_slice(), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<ArchivedDeltaVec, Vec<u64>, D> for DeltaEncoded {
+    #[inline]
+    fn deserialize_with(field: &ArchivedDeltaVec, _: &mut D) -> Result<Vec<u64>, D::Error> {
+        Ok(field.iter().collect())
+    }
+}
@@ -590,7 +590,8 @@ pub struct CopyOptimize;
 /// A wrapper that converts a [`SystemTime`](::std::time::SystemTime) to a
 /// [`Duration`](::std::time::Duration) since [`UNIX_EPOCH`](::std::time::UNIX_EPOCH).
 ///
-/// If the serialized time occurs before the UNIX epoch, serialization will panic during `resolve`.
+/// If the serialized time occurs before the UNIX epoch, serialization will fail with
+/// [`UnixTimestampError::TimeBeforeUnixEpoch`].
 /// The resulting archived time will be an [`ArchivedDuration`](crate::time::ArchivedDuration)
 /// relative to the UNIX epoch.
 ///
@@ -663,6 +664,45 @@ impl ::std::error::Error for UnixTimestampError {}
 #[derive(Debug)]
 pub struct Raw;
 
+/// A wrapper that serializes a `Vec<u64>` as the differences between successive values.
+///
+/// Values that are close to the previous value (like the timestamps of a timeline) take up much
+/// less space than in a regular archived `Vec`. The archived values are accessed through an
+/// [`ArchivedDeltaVec`](crate::vec::ArchivedDeltaVec), which stores periodic checkpoints so that
+/// random access doesn't have to decode the entire vec.
+///
+/// # Example
+///
+/// ```
+/// use rkyv::{archived_root, with::DeltaEncoded, Archive, Serialize};
+///
+/// #[derive(Archive, Serialize)]
+/// struct Timeline {
+///     #[with(DeltaEncoded)]
+///     timestamps: Vec<u64>,
+/// }
+///
+/// let value = Timeline {
+///     timestamps: (0..100).map(|i| 1_650_000_000_000 + i * 15).collect(),
+/// };
+/// let bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+/// let archived = unsafe { archived_root::<Timeline>(&bytes) };
+/// assert_eq!(archived.timestamps.get(42), Some(1_650_000_000_630));
+/// ```
+#[derive(Debug)]
+pub struct DeltaEncoded;
+
+/// A wrapper that serializes a boxed trait object as an
+/// [`ArchivedDyn`](crate::trait_object::ArchivedDyn).
+///
+/// The trait must be marked with [`#[archive_dyn]`](macro@crate::archive_dyn), and the field must
+/// hold a box of the generated serialization trait. See [`trait_object`](crate::trait_object) for
+/// an example.
+#[cfg(feature = "archive_dyn")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "archive_dyn")))]
+#[derive(Debug)]
+pub struct AsDyn;
+
 /// A wrapper that allows serialize-unsafe types to be serialized.
 ///
 /// Types like `Cell` and `UnsafeCell` may contain serializable types, but have unsafe access
@@ -1,14 +1,14 @@
 use crate::{
     attributes::{parse_attributes, Attributes},
-    repr::{BaseRepr, IntRepr, Repr},
-    util::{add_bounds, strip_raw},
+    repr::{explicit_discriminants, int_repr_of, BaseRepr, IntRepr, Repr},
+    util::{add_bounds, as_native_field, strip_raw, to_snake_case},
     with::{make_with_cast, make_with_ty},
 };
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{
     parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Error, Field, Fields, Ident,
-    Index, Meta, NestedMeta, Type,
+    Index, Meta, NestedMeta, Path, Type,
 };
 
 pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
@@ -34,6 +34,79 @@ fn field_archive_attrs(field: &Field) -> impl '_ + Iterator<Item = NestedMeta> {
         .flatten()
 }
 
+fn with_breadcrumb(rkyv_path: &Path, name: &str, ty: &Type, resolve: TokenStream) -> TokenStream {
+    if cfg!(feature = "debug_serialize") {
+        quote! {
+            {
+                let _breadcrumb = #rkyv_path::ser::breadcrumb::enter(#name, ::core::any::type_name::<#ty>());
+                #resolve
+            }
+        }
+    } else {
+        resolve
+    }
+}
+
+fn derive_as_native_impl(
+    input: &DeriveInput,
+    attributes: &Attributes,
+    as_native: &Path,
+) -> Result<TokenStream, Error> {
+    if attributes.archived.is_some()
+        || attributes.resolver.is_some()
+        || attributes.archive_as.is_some()
+        || !attributes.attrs.is_empty()
+        || attributes.archived_repr.base_repr.is_some()
+        || attributes.archived_repr.modifier.is_some()
+        || attributes.compares.is_some()
+        || attributes.copy_safe.is_some()
+    {
+        return Err(Error::new_spanned(
+            as_native,
+            "as_native may not be used with archived, resolver, as, repr, compare, copy_safe, or \
+            archive_attr because no archived or resolver type is generated",
+        ));
+    }
+
+    let (field, member) = as_native_field(input, as_native)?;
+
+    let default_rkyv_path = parse_quote! { ::rkyv };
+    let rkyv_path = attributes.rkyv_path.as_ref().unwrap_or(&default_rkyv_path);
+    let with_ty = make_with_ty(rkyv_path);
+    let with_cast = make_with_cast(rkyv_path);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut archive_where = where_clause.unwrap().clone();
+
+    let ty = with_ty(field)?;
+    if !field.attrs.iter().any(|a| a.path.is_ident("omit_bounds")) {
+        archive_where
+            .predicates
+            .push(parse_quote! { #ty: #rkyv_path::Archive });
+    }
+    let value = with_cast(field, parse_quote! { (&self.#member) })?;
+
+    Ok(quote! {
+        #[automatically_derived]
+        const _: () = {
+            use #rkyv_path::Archive;
+
+            impl #impl_generics Archive for #name #ty_generics #archive_where {
+                type Archived = #rkyv_path::Archived<#ty>;
+                type Resolver = #rkyv_path::Resolver<#ty>;
+
+                // Some resolvers will be (), this allow is to prevent clippy from complaining
+                #[allow(clippy::unit_arg)]
+                #[inline]
+                unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+                    Archive::resolve(#value, pos, resolver, out);
+                }
+            }
+        };
+    })
+}
+
 fn derive_archive_impl(
     mut input: DeriveInput,
     attributes: &Attributes,
@@ -98,6 +171,41 @@ fn derive_archive_impl(
         }
     }
 
+    if let Some(ref accessors) = attributes.accessors {
+        if !matches!(input.data, Data::Enum(_)) {
+            return Err(Error::new_spanned(
+                accessors,
+                "accessors may only be used on enums",
+            ));
+        }
+        if attributes.archive_as.is_some() {
+            return Err(Error::new_spanned(
+                accessors,
+                "accessors may not be used with as = \"...\"",
+            ));
+        }
+    }
+
+    if let Some(ref discriminants) = attributes.discriminants {
+        if !matches!(input.data, Data::Enum(_)) {
+            return Err(Error::new_spanned(
+                discriminants,
+                "discriminants may only be used on enums",
+            ));
+        }
+        if attributes.archived_repr.base_repr.is_none() && int_repr_of(&input.attrs).is_none() {
+            return Err(Error::new_spanned(
+                discriminants,
+                "discriminants requires the enum to have a repr(i*) or repr(u*) attribute or an \
+                archived repr",
+            ));
+        }
+    }
+
+    if let Some(ref as_native) = attributes.as_native {
+        return derive_as_native_impl(&input, attributes, as_native);
+    }
+
     let archived_name = attributes.archived.as_ref().map_or_else(
         || Ident::new(&format!("Archived{}", strip_raw(name)), name.span()),
         |value| value.clone(),
@@ -191,9 +299,15 @@ fn derive_archive_impl(
                     let resolve_fields = fields.named.iter().map(|f| {
                         let name = &f.ident;
                         let field = with_cast(f, parse_quote! { (&self.#name) }).unwrap();
+                        let resolve = with_breadcrumb(
+                            rkyv_path,
+                            &strip_raw(name.as_ref().unwrap()),
+                            &f.ty,
+                            quote! { #rkyv_path::Archive::resolve(#field, pos + fp, resolver.#name, fo); },
+                        );
                         quote! {
                             let (fp, fo) = out_field!(out.#name);
-                            #rkyv_path::Archive::resolve(#field, pos + fp, resolver.#name, fo);
+                            #resolve
                         }
                     });
 
@@ -251,7 +365,7 @@ fn derive_archive_impl(
                                             #(
                                                 match other.#field_names.partial_cmp(&self.#field_names) {
                                                     Some(::core::cmp::Ordering::Equal) => (),
-                                                    x => return x,
+                                                    x => return x.map(::core::cmp::Ordering::reverse),
                                                 }
                                             )*
                                             Some(::core::cmp::Ordering::Equal)
@@ -261,7 +375,7 @@ fn derive_archive_impl(
                                     impl #impl_generics PartialOrd<#name #ty_generics> for #archived_type #partial_ord_where {
                                         #[inline]
                                         fn partial_cmp(&self, other: &#name #ty_generics) -> Option<::core::cmp::Ordering> {
-                                            other.partial_cmp(self)
+                                            other.partial_cmp(self).map(::core::cmp::Ordering::reverse)
                                         }
                                     }
                                 });
@@ -370,9 +484,15 @@ fn derive_archive_impl(
                     let resolve_fields = fields.unnamed.iter().enumerate().map(|(i, f)| {
                         let index = Index::from(i);
                         let field = with_cast(f, parse_quote! { (&self.#index) }).unwrap();
+                        let resolve = with_breadcrumb(
+                            rkyv_path,
+                            &i.to_string(),
+                            &f.ty,
+                            quote! { #rkyv_path::Archive::resolve(#field, pos + fp, resolver.#index, fo); },
+                        );
                         quote! {
                             let (fp, fo) = out_field!(out.#index);
-                            #rkyv_path::Archive::resolve(#field, pos + fp, resolver.#index, fo);
+                            #resolve
                         }
                     });
 
@@ -438,7 +558,7 @@ fn derive_archive_impl(
                                             #(
                                                 match other.#field_names.partial_cmp(&self.#field_names) {
                                                     Some(::core::cmp::Ordering::Equal) => (),
-                                                    x => return x,
+                                                    x => return x.map(::core::cmp::Ordering::reverse),
                                                 }
                                             )*
                                             Some(::core::cmp::Ordering::Equal)
@@ -448,7 +568,7 @@ fn derive_archive_impl(
                                     impl #impl_generics PartialOrd<#name #ty_generics> for #archived_type #partial_ord_where {
                                         #[inline]
                                         fn partial_cmp(&self, other: &#name #ty_generics) -> Option<::core::cmp::Ordering> {
-                                            other.partial_cmp(self)
+                                            other.partial_cmp(self).map(::core::cmp::Ordering::reverse)
                                         }
                                     }
                                 });
@@ -707,9 +827,15 @@ fn derive_archive_impl(
                             let self_binding = Ident::new(&format!("self_{}", strip_raw(name.as_ref().unwrap())), name.span());
                             let resolver_binding = Ident::new(&format!("resolver_{}", strip_raw(name.as_ref().unwrap())), name.span());
                             let value = with_cast(f, parse_quote! { #self_binding }).unwrap();
+                            let resolve = with_breadcrumb(
+                                rkyv_path,
+                                &strip_raw(name.as_ref().unwrap()),
+                                &f.ty,
+                                quote! { #rkyv_path::Archive::resolve(#value, pos + fp, #resolver_binding, fo); },
+                            );
                             quote! {
                                 let (fp, fo) = out_field!(out.#name);
-                                #rkyv_path::Archive::resolve(#value, pos + fp, #resolver_binding, fo);
+                                #resolve
                             }
                         });
                         quote! {
@@ -741,9 +867,15 @@ fn derive_archive_impl(
                             let self_binding = Ident::new(&format!("self_{}", i), f.span());
                             let resolver_binding = Ident::new(&format!("resolver_{}", i), f.span());
                             let value = with_cast(f, parse_quote! { #self_binding }).unwrap();
+                            let resolve = with_breadcrumb(
+                                rkyv_path,
+                                &i.to_string(),
+                                &f.ty,
+                                quote! { #rkyv_path::Archive::resolve(#value, pos + fp, #resolver_binding, fo); },
+                            );
                             quote! {
                                 let (fp, fo) = out_field!(out.#index);
-                                #rkyv_path::Archive::resolve(#value, pos + fp, #resolver_binding, fo);
+                                #resolve
                             }
                         });
                         quote! {
@@ -768,6 +900,13 @@ fn derive_archive_impl(
                 }
             });
 
+            // Explicit discriminants are only honored with `#[archive(discriminants)]` so that the
+            // layout of existing archived enums doesn't change
+            let source_int_repr = if attributes.discriminants.is_some() {
+                int_repr_of(&input.attrs)
+            } else {
+                None
+            };
             let (int_repr, int_repr_span) = match attributes.archived_repr.base_repr {
                 // The base repr for enums may not be Rust, transparent, or C
                 Some((BaseRepr::Transparent | BaseRepr::C, span)) => {
@@ -775,6 +914,9 @@ fn derive_archive_impl(
                 }
                 // The base repr for enums may be i*/u*
                 Some((BaseRepr::Int(int_repr), span)) => (int_repr, span),
+                // If the archived discriminants must match the source discriminants, the base
+                // repr defaults to the integer repr of the enum
+                None if source_int_repr.is_some() => source_int_repr.unwrap(),
                 // If unspecified, the base repr is set to u* with the smallest unsigned integer
                 // that can represent the number of variants
                 None => {
@@ -788,6 +930,11 @@ fn derive_archive_impl(
                     (int_repr, Span::call_site())
                 }
             };
+            let explicit_discriminants = if attributes.discriminants.is_some() {
+                Some(explicit_discriminants(data.variants.iter(), int_repr)?)
+            } else {
+                None
+            };
             let repr = Repr {
                 base_repr: Some((BaseRepr::Int(int_repr), int_repr_span)),
                 modifier: attributes.archived_repr.modifier.clone(),
@@ -814,12 +961,15 @@ fn derive_archive_impl(
             let archived_def = if attributes.archive_as.is_none() {
                 let archived_variants = data.variants.iter().enumerate().map(|(i, v)| {
                     let variant = &v.ident;
-                    let discriminant =
-                        if is_fieldless || cfg!(feature = "arbitrary_enum_discriminant") {
-                            Some(int_repr.enum_discriminant(i))
-                        } else {
-                            None
-                        };
+                    let discriminant = if let Some(ref values) = explicit_discriminants {
+                        let discriminant = int_repr.explicit_discriminant(values[i]);
+                        quote! { #discriminant }
+                    } else if is_fieldless || cfg!(feature = "arbitrary_enum_discriminant") {
+                        let discriminant = int_repr.enum_discriminant(i);
+                        quote! { #discriminant }
+                    } else {
+                        quote! {}
+                    };
                     match v.fields {
                         Fields::Named(ref fields) => {
                             let fields = fields.named.iter().map(|f| {
@@ -899,8 +1049,13 @@ fn derive_archive_impl(
 
             let archived_variant_tags = data.variants.iter().enumerate().map(|(i, v)| {
                 let variant = &v.ident;
-                let discriminant = int_repr.enum_discriminant(i);
-                quote! { #variant #discriminant }
+                if let Some(ref values) = explicit_discriminants {
+                    let discriminant = int_repr.explicit_discriminant(values[i]);
+                    quote! { #variant #discriminant }
+                } else {
+                    let discriminant = int_repr.enum_discriminant(i);
+                    quote! { #variant #discriminant }
+                }
             });
 
             let archived_variant_structs = data.variants.iter().map(|v| {
@@ -1119,7 +1274,7 @@ fn derive_archive_impl(
                                                 #(
                                                     match #other_bindings.partial_cmp(#self_bindings) {
                                                         Some(::core::cmp::Ordering::Equal) => (),
-                                                        cmp => return cmp,
+                                                        cmp => return cmp.map(::core::cmp::Ordering::reverse),
                                                     }
                                                 )*
                                                 Some(::core::cmp::Ordering::Equal)
@@ -1142,7 +1297,7 @@ fn derive_archive_impl(
                                                 #(
                                                     match #other_bindings.partial_cmp(#self_bindings) {
                                                         Some(::core::cmp::Ordering::Equal) => (),
-                                                        cmp => return cmp,
+                                                        cmp => return cmp.map(::core::cmp::Ordering::reverse),
                                                     }
                                                 )*
                                                 Some(::core::cmp::Ordering::Equal)
@@ -1181,20 +1336,157 @@ fn derive_archive_impl(
                             impl #impl_generics PartialOrd<#name #ty_generics> for #archived_type #partial_ord_where {
                                 #[inline]
                                 fn partial_cmp(&self, other: &#name #ty_generics) -> Option<::core::cmp::Ordering> {
-                                    match other.partial_cmp(self) {
-                                        Some(::core::cmp::Ordering::Less) => Some(::core::cmp::Ordering::Greater),
-                                        Some(::core::cmp::Ordering::Greater) => Some(::core::cmp::Ordering::Less),
-                                        cmp => cmp,
-                                    }
+                                    other.partial_cmp(self).map(::core::cmp::Ordering::reverse)
                                 }
                             }
                         });
                     } else {
-                        return Err(Error::new_spanned(compare, "unrecognized compare argument, supported compares are PartialEq (PartialOrd is not supported for enums)"));
+                        return Err(Error::new_spanned(compare, "unrecognized compare argument, supported compares are PartialEq and PartialOrd"));
                     }
                 }
             }
 
+            let (accessor_types, accessor_impl) = if attributes.accessors.is_some() {
+                let mut accessor_names = Vec::<(String, &Ident)>::new();
+                for v in data.variants.iter() {
+                    let accessor = format!("as_{}", to_snake_case(&v.ident));
+                    let shadowed = match accessor.as_str() {
+                        "as_ref" => Some("AsRef::as_ref"),
+                        "as_mut" => Some("AsMut::as_mut"),
+                        _ => None,
+                    };
+                    if let Some(shadowed) = shadowed {
+                        return Err(Error::new_spanned(
+                            &v.ident,
+                            format!(
+                                "the accessor for variant `{}` would be named `{}`, which shadows `{}`",
+                                v.ident, accessor, shadowed,
+                            ),
+                        ));
+                    }
+                    if let Some((_, other)) = accessor_names.iter().find(|(a, _)| *a == accessor) {
+                        return Err(Error::new_spanned(
+                            &v.ident,
+                            format!(
+                                "variants `{}` and `{}` would both have an accessor named `{}`",
+                                other, v.ident, accessor,
+                            ),
+                        ));
+                    }
+                    accessor_names.push((accessor, &v.ident));
+                }
+
+                let mut ref_generics = generics.clone();
+                ref_generics.params.insert(0, parse_quote! { '__a });
+                let (_, ref_ty_generics, _) = ref_generics.split_for_impl();
+
+                let mut accessor_types = Vec::new();
+                let accessors = data.variants.iter().enumerate().map(|(i, v)| {
+                    let variant = &v.ident;
+                    let accessor = Ident::new(&accessor_names[i].0, v.span());
+                    let accessor_doc = format!(
+                        "Returns the fields of the archived [`{}::{}`] if this is that variant, or `None` otherwise.",
+                        name,
+                        variant,
+                    );
+                    match v.fields {
+                        Fields::Named(ref fields) => {
+                            let ref_name = Ident::new(&format!("{}{}Ref", strip_raw(&archived_name), strip_raw(variant)), v.span());
+                            let ref_doc = format!("References to the fields of an archived [`{}::{}`]", name, variant);
+                            let ref_fields = fields.named.iter().map(|f| {
+                                let field_name = f.ident.as_ref();
+                                let ty = with_ty(f).unwrap();
+                                let field_doc = format!(
+                                    "The archived counterpart of [`{}::{}::{}`]",
+                                    name,
+                                    variant,
+                                    field_name.unwrap(),
+                                );
+                                quote! {
+                                    #[doc = #field_doc]
+                                    pub #field_name: &'__a #rkyv_path::Archived<#ty>
+                                }
+                            });
+                            accessor_types.push(quote! {
+                                #[automatically_derived]
+                                #[doc = #ref_doc]
+                                #vis struct #ref_name #ref_generics #archive_where {
+                                    #(#ref_fields,)*
+                                    _phantom: ::core::marker::PhantomData<&'__a #archived_type>,
+                                }
+                            });
+
+                            let bindings = fields.named.iter().map(|f| &f.ident);
+                            let values = fields.named.iter().map(|f| &f.ident);
+                            quote! {
+                                #[doc = #accessor_doc]
+                                #[allow(dead_code)]
+                                #[inline]
+                                #vis fn #accessor<'__a>(&'__a self) -> Option<#ref_name #ref_ty_generics> {
+                                    match self {
+                                        Self::#variant { #(#bindings,)* } => Some(#ref_name {
+                                            #(#values,)*
+                                            _phantom: PhantomData,
+                                        }),
+                                        #[allow(unreachable_patterns)]
+                                        _ => None,
+                                    }
+                                }
+                            }
+                        }
+                        Fields::Unnamed(ref fields) => {
+                            let bindings = fields.unnamed.iter().enumerate().map(|(i, f)| {
+                                Ident::new(&format!("_{}", i), f.span())
+                            }).collect::<Vec<_>>();
+                            let tys = fields.unnamed.iter().map(|f| {
+                                let ty = with_ty(f).unwrap();
+                                quote! { &'__a #rkyv_path::Archived<#ty> }
+                            }).collect::<Vec<_>>();
+                            let (output, value) = if tys.len() == 1 {
+                                (quote! { #(#tys)* }, quote! { #(#bindings)* })
+                            } else {
+                                (quote! { (#(#tys,)*) }, quote! { (#(#bindings,)*) })
+                            };
+                            quote! {
+                                #[doc = #accessor_doc]
+                                #[allow(dead_code)]
+                                #[inline]
+                                #vis fn #accessor<'__a>(&'__a self) -> Option<#output> {
+                                    match self {
+                                        Self::#variant(#(#bindings,)*) => Some(#value),
+                                        #[allow(unreachable_patterns)]
+                                        _ => None,
+                                    }
+                                }
+                            }
+                        }
+                        Fields::Unit => quote! {
+                            #[doc = #accessor_doc]
+                            #[allow(dead_code)]
+                            #[inline]
+                            #vis fn #accessor(&self) -> Option<()> {
+                                match self {
+                                    Self::#variant => Some(()),
+                                    #[allow(unreachable_patterns)]
+                                    _ => None,
+                                }
+                            }
+                        },
+                    }
+                }).collect::<Vec<_>>();
+
+                (
+                    Some(quote! { #(#accessor_types)* }),
+                    Some(quote! {
+                        impl #impl_generics #archived_type #archive_where {
+                            #(#accessors)*
+                        }
+                    }),
+                )
+            } else {
+                (None, None)
+            };
+
             let copy_safe_impl = if cfg!(feature = "copy") && attributes.copy_safe.is_some() {
                 let mut copy_safe_where = where_clause.clone();
                 for variant in data.variants.iter() {
@@ -1238,6 +1530,8 @@ fn derive_archive_impl(
                 quote! {
                     #archived_def
 
+                    #accessor_types
+
                     #[automatically_derived]
                     #[doc = #resolver_doc]
                     #vis enum #resolver #generics #archive_where {
@@ -1266,6 +1560,7 @@ fn derive_archive_impl(
                         }
                     }
 
+                    #accessor_impl
                     #partial_eq_impl
                     #partial_ord_impl
                     #copy_safe_impl
@@ -0,0 +1,59 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, AttributeArgs, Error, Ident, ItemTrait, Lit, Meta, NestedMeta, Path};
+
+pub fn archive_dyn(args: AttributeArgs, input: ItemTrait) -> Result<TokenStream, Error> {
+    let mut rkyv_path: Path = parse_quote! { ::rkyv };
+    let mut serialize_trait = None;
+
+    for arg in args {
+        match arg {
+            NestedMeta::Meta(Meta::NameValue(meta)) => {
+                let value = match meta.lit {
+                    Lit::Str(ref lit) => lit,
+                    ref lit => return Err(Error::new_spanned(lit, "expected string literal")),
+                };
+                if meta.path.is_ident("crate") {
+                    rkyv_path = value.parse()?;
+                } else if meta.path.is_ident("serialize") {
+                    serialize_trait = Some(value.parse::<Ident>()?);
+                } else {
+                    return Err(Error::new_spanned(
+                        meta.path,
+                        "unrecognized archive_dyn argument",
+                    ));
+                }
+            }
+            arg => return Err(Error::new_spanned(arg, "unrecognized archive_dyn argument")),
+        }
+    }
+
+    if !input.generics.params.is_empty() || input.generics.where_clause.is_some() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "archive_dyn does not support generic traits",
+        ));
+    }
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let serialize_trait =
+        serialize_trait.unwrap_or_else(|| Ident::new(&format!("Serialize{}", name), name.span()));
+    let doc = format!(
+        "A [`{}`] that can be archived behind a trait object with `AsDyn`.",
+        name
+    );
+
+    Ok(quote! {
+        #input
+
+        #[doc = #doc]
+        #vis trait #serialize_trait: #name + #rkyv_path::trait_object::SerializeDyn {}
+
+        impl<__T: #name + #rkyv_path::trait_object::SerializeDyn + ?Sized> #serialize_trait for __T {}
+
+        impl #rkyv_path::trait_object::ArchiveDynTrait for dyn #serialize_trait {
+            type Archived = dyn #name;
+        }
+    })
+}
@@ -1,10 +1,11 @@
 use crate::repr::Repr;
 use quote::ToTokens;
-use syn::{AttrStyle, DeriveInput, Error, Ident, Lit, LitStr, Meta, NestedMeta, Path};
+use syn::{AttrStyle, DeriveInput, Error, Field, Ident, Lit, LitStr, Meta, NestedMeta, Path};
 
 #[derive(Default)]
 pub struct Attributes {
     pub archive_as: Option<LitStr>,
+    pub as_native: Option<Path>,
     pub archived: Option<Ident>,
     pub resolver: Option<Ident>,
     pub attrs: Vec<Meta>,
@@ -14,6 +15,8 @@ pub struct Attributes {
     pub serialize_bound: Option<LitStr>,
     pub deserialize_bound: Option<LitStr>,
     pub copy_safe: Option<Path>,
+    pub accessors: Option<Path>,
+    pub discriminants: Option<Path>,
     pub rkyv_path: Option<Path>,
 }
 
@@ -38,6 +41,12 @@ fn parse_archive_attributes(attributes: &mut Attributes, meta: &Meta) -> Result<
         Meta::Path(path) => {
             if path.is_ident("copy_safe") {
                 try_set_attribute(&mut attributes.copy_safe, path.clone(), "copy_safe")
+            } else if path.is_ident("as_native") {
+                try_set_attribute(&mut attributes.as_native, path.clone(), "as_native")
+            } else if path.is_ident("accessors") {
+                try_set_attribute(&mut attributes.accessors, path.clone(), "accessors")
+            } else if path.is_ident("discriminants") {
+                try_set_attribute(&mut attributes.discriminants, path.clone(), "discriminants")
             } else {
                 Err(Error::new_spanned(meta, "unrecognized archive argument"))
             }
@@ -86,7 +95,7 @@ fn parse_archive_attributes(attributes: &mut Attributes, meta: &Meta) -> Result<
                             } else {
                                 return Err(Error::new_spanned(
                                     bound,
-                                    "bound must be either serialize or deserialize",
+                                    "bound must be either archive, serialize, or deserialize",
                                 ));
                             }
                         } else {
@@ -98,7 +107,7 @@ fn parse_archive_attributes(attributes: &mut Attributes, meta: &Meta) -> Result<
                     } else {
                         return Err(Error::new_spanned(
                             bound,
-                            "bound arguments must be serialize or deserialize bounds to apply",
+                            "bound arguments must be archive, serialize, or deserialize bounds to apply",
                         ));
                     }
                 }
@@ -204,3 +213,46 @@ pub fn parse_attributes(input: &DeriveInput) -> Result<Attributes, Error> {
     }
     Ok(result)
 }
+
+/// Parses the `#[archive(deserialize_with = "...")]` attribute of a field.
+pub fn parse_deserialize_with(field: &Field) -> Result<Option<Path>, Error> {
+    let mut result = None;
+    for attr in field.attrs.iter() {
+        if attr.path.is_ident("archive") {
+            if let Meta::List(list) = attr.parse_meta()? {
+                for nested in list.nested.iter() {
+                    match nested {
+                        NestedMeta::Meta(Meta::NameValue(meta))
+                            if meta.path.is_ident("deserialize_with") =>
+                        {
+                            if let Lit::Str(ref lit_str) = meta.lit {
+                                try_set_attribute(
+                                    &mut result,
+                                    lit_str.parse::<Path>()?,
+                                    "deserialize_with",
+                                )?;
+                            } else {
+                                return Err(Error::new_spanned(
+                                    meta,
+                                    "deserialize_with must be a string",
+                                ));
+                            }
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                nested,
+                                "unrecognized archive field argument",
+                            ))
+                        }
+                    }
+                }
+            } else {
+                return Err(Error::new_spanned(
+                    attr,
+                    "archive may only be a structured list attribute",
+                ));
+            }
+        }
+    }
+    Ok(result)
+}
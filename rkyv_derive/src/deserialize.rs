@@ -1,20 +1,49 @@
 use crate::{
-    attributes::{parse_attributes, Attributes},
-    util::add_bounds,
+    attributes::{parse_attributes, parse_deserialize_with, Attributes},
+    util::{add_bounds, as_native_field},
     with::{make_with_ty, with_inner},
 };
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
-    parse_quote, punctuated::Punctuated, spanned::Spanned, Data, DeriveInput, Error, Fields,
-    Generics, Ident, Index,
+    parse_quote, punctuated::Punctuated, spanned::Spanned, Data, DeriveInput, Error, Expr, Field,
+    Fields, Generics, Ident, Index, Type,
 };
 
 pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
     let attributes = parse_attributes(&input)?;
+    let fields: Vec<&Field> = match input.data {
+        Data::Struct(ref data) => data.fields.iter().collect(),
+        Data::Enum(ref data) => data.variants.iter().flat_map(|v| v.fields.iter()).collect(),
+        Data::Union(_) => Vec::new(),
+    };
+    for field in fields {
+        parse_deserialize_with(field)?;
+    }
     derive_deserialize_impl(input, &attributes)
 }
 
+/// Returns whether `Deserialize` bounds should be added for a field.
+///
+/// Fields with `deserialize_with` are deserialized by the given function, so they don't need to
+/// implement `Deserialize`.
+fn needs_deserialize_bounds(field: &Field) -> bool {
+    !field.attrs.iter().any(|a| a.path.is_ident("omit_bounds"))
+        && matches!(parse_deserialize_with(field), Ok(None))
+}
+
+/// Makes the expression that deserializes a field from the given archived field.
+fn deserialize_field(field: &Field, ty: &Type, archived: Expr) -> Result<Expr, Error> {
+    if let Some(path) = parse_deserialize_with(field)? {
+        Ok(parse_quote! { #path(#archived, deserializer)? })
+    } else {
+        with_inner(
+            field,
+            parse_quote! { Deserialize::<#ty, __D>::deserialize(#archived, deserializer)? },
+        )
+    }
+}
+
 fn derive_deserialize_impl(
     mut input: DeriveInput,
     attributes: &Attributes,
@@ -48,15 +77,40 @@ fn derive_deserialize_impl(
     let (_, ty_generics, where_clause) = input.generics.split_for_impl();
     let where_clause = where_clause.unwrap();
 
+    if let Some(ref as_native) = attributes.as_native {
+        let (field, member) = as_native_field(&input, as_native)?;
+        let mut deserialize_where = where_clause.clone();
+        let ty = with_ty(field)?;
+        if needs_deserialize_bounds(field) {
+            deserialize_where
+                .predicates
+                .push(parse_quote! { #ty: Archive });
+            deserialize_where
+                .predicates
+                .push(parse_quote! { Archived<#ty>: Deserialize<#ty, __D> });
+        }
+        let value = deserialize_field(field, &ty, parse_quote! { self })?;
+
+        return Ok(quote! {
+            #[automatically_derived]
+            const _: () = {
+                use #rkyv_path::{Archive, Archived, Deserialize, Fallible};
+
+                impl #impl_generics Deserialize<#name #ty_generics, __D> for Archived<#name #ty_generics> #deserialize_where {
+                    #[inline]
+                    fn deserialize(&self, deserializer: &mut __D) -> ::core::result::Result<#name #ty_generics, __D::Error> {
+                        Ok(#name { #member: #value })
+                    }
+                }
+            };
+        });
+    }
+
     let deserialize_impl = match input.data {
         Data::Struct(ref data) => match data.fields {
             Fields::Named(ref fields) => {
                 let mut deserialize_where = where_clause.clone();
-                for field in fields
-                    .named
-                    .iter()
-                    .filter(|f| !f.attrs.iter().any(|a| a.path.is_ident("omit_bounds")))
-                {
+                for field in fields.named.iter().filter(|f| needs_deserialize_bounds(f)) {
                     let ty = with_ty(field)?;
                     deserialize_where
                         .predicates
@@ -69,16 +123,7 @@ fn derive_deserialize_impl(
                 let deserialize_fields = fields.named.iter().map(|f| {
                     let name = &f.ident;
                     let ty = with_ty(f).unwrap();
-                    let value = with_inner(
-                        f,
-                        parse_quote! {
-                            Deserialize::<#ty, __D>::deserialize(
-                                &self.#name,
-                                deserializer,
-                            )?
-                        },
-                    )
-                    .unwrap();
+                    let value = deserialize_field(f, &ty, parse_quote! { &self.#name }).unwrap();
                     quote! { #name: #value }
                 });
 
@@ -98,7 +143,7 @@ fn derive_deserialize_impl(
                 for field in fields
                     .unnamed
                     .iter()
-                    .filter(|f| !f.attrs.iter().any(|a| a.path.is_ident("omit_bounds")))
+                    .filter(|f| needs_deserialize_bounds(f))
                 {
                     let ty = with_ty(field)?;
                     deserialize_where
@@ -112,16 +157,7 @@ fn derive_deserialize_impl(
                 let deserialize_fields = fields.unnamed.iter().enumerate().map(|(i, f)| {
                     let index = Index::from(i);
                     let ty = with_ty(f).unwrap();
-                    let value = with_inner(
-                        f,
-                        parse_quote! {
-                            Deserialize::<#ty, __D>::deserialize(
-                                &self.#index,
-                                deserializer,
-                            )?
-                        },
-                    )
-                    .unwrap();
+                    let value = deserialize_field(f, &ty, parse_quote! { &self.#index }).unwrap();
                     quote! { #value }
                 });
 
@@ -150,11 +186,7 @@ fn derive_deserialize_impl(
             for variant in data.variants.iter() {
                 match variant.fields {
                     Fields::Named(ref fields) => {
-                        for field in fields
-                            .named
-                            .iter()
-                            .filter(|f| !f.attrs.iter().any(|a| a.path.is_ident("omit_bounds")))
-                        {
+                        for field in fields.named.iter().filter(|f| needs_deserialize_bounds(f)) {
                             let ty = with_ty(field)?;
                             deserialize_where
                                 .predicates
@@ -168,7 +200,7 @@ fn derive_deserialize_impl(
                         for field in fields
                             .unnamed
                             .iter()
-                            .filter(|f| !f.attrs.iter().any(|a| a.path.is_ident("omit_bounds")))
+                            .filter(|f| needs_deserialize_bounds(f))
                         {
                             let ty = with_ty(field)?;
                             deserialize_where
@@ -194,16 +226,7 @@ fn derive_deserialize_impl(
                         let fields = fields.named.iter().map(|f| {
                             let name = &f.ident;
                             let ty = with_ty(f).unwrap();
-                            let value = with_inner(
-                                f,
-                                parse_quote! {
-                                    Deserialize::<#ty, __D>::deserialize(
-                                        #name,
-                                        deserializer,
-                                    )?
-                                },
-                            )
-                            .unwrap();
+                            let value = deserialize_field(f, &ty, parse_quote! { #name }).unwrap();
                             quote! { #name: #value }
                         });
                         quote! {
@@ -218,16 +241,8 @@ fn derive_deserialize_impl(
                         let fields = fields.unnamed.iter().enumerate().map(|(i, f)| {
                             let binding = Ident::new(&format!("_{}", i), f.span());
                             let ty = with_ty(f).unwrap();
-                            let value = with_inner(
-                                f,
-                                parse_quote! {
-                                    Deserialize::<#ty, __D>::deserialize(
-                                        #binding,
-                                        deserializer,
-                                    )?
-                                },
-                            )
-                            .unwrap();
+                            let value =
+                                deserialize_field(f, &ty, parse_quote! { #binding }).unwrap();
                             quote! { #value }
                         });
                         quote! {
@@ -7,6 +7,8 @@
 )]
 
 mod archive;
+#[cfg(feature = "archive_dyn")]
+mod archive_dyn;
 mod attributes;
 mod deserialize;
 mod repr;
@@ -18,6 +20,8 @@ mod with;
 extern crate proc_macro;
 
 use syn::{parse_macro_input, DeriveInput};
+#[cfg(feature = "archive_dyn")]
+use syn::{AttributeArgs, ItemTrait};
 
 /// Derives `Archive` for the labeled type.
 ///
@@ -32,6 +36,9 @@ use syn::{parse_macro_input, DeriveInput};
 ///   default, archived types are named "Archived" + `the name of the type`.
 /// - `resolver = "..."`: Changes the name of the generated resolver type to the given value. By
 ///   default, resolver types are named `the name of the type` + "Resolver".
+///
+///   Both generated types have the same visibility as the original type, so these can be used to
+///   give other modules and crates a fixed name to refer to (e.g. to match an external ABI).
 /// - `repr(...)`: *Deprecated, use `#[archive_attr(repr(...))]` instead.* Sets the representation
 ///   for the archived type to the given representation. Available representation options may vary
 ///   depending on features and type layout.
@@ -47,11 +54,43 @@ use syn::{parse_macro_input, DeriveInput};
 ///   qualifies it for copy optimizations. (requires nightly)
 /// - `as = "..."`: Instead of generating a separate archived type, this type will archive as the
 ///   named type. This is useful for types which are generic over their parameters.
+/// - `as_native`: For structs with exactly one field, archives the type as the archived type of
+///   that field instead of generating a wrapper type. For example, a `Meters(f32)` with
+///   `#[archive(as_native)]` archives as `Archived<f32>`. No archived or resolver type is
+///   generated.
+/// - `accessors`: For enums, generates variant accessors on the archived type. See
+///   [Enum accessors](#enum-accessors).
+/// - `discriminants`: For enums, archives variants with the same discriminants as the source enum.
+///   See [Enum discriminants](#enum-discriminants).
 /// - `crate = "..."`: Chooses an alternative crate path to import rkyv from.
 ///
 /// `#[archive_attr(...)]` adds the attributes passed as arguments as attributes to the generated
 /// type. This is commonly used with attributes like `derive(...)` to derive trait implementations
-/// for the archived type.
+/// for the archived type. Multiple attributes can be passed at once, for example
+/// `#[archive_attr(derive(serde::Serialize), serde(rename_all = "camelCase"))]`.
+///
+/// # Enum accessors
+///
+/// With `#[archive(accessors)]`, archived enums get an `as_variant_name` method for each variant,
+/// which returns the archived fields of that variant if it is the active one. Unit variants return
+/// `Option<()>`, tuple variants return a reference to their single field or a tuple of references to
+/// their fields, and struct variants return a generated `ArchivedNameVariantRef` struct with a
+/// reference to each field. Variant names are converted to snake case, so `HTTPRequest` gets an
+/// `as_http_request` accessor.
+///
+/// It is an error for two variants to get the same accessor (e.g. `A` and `a`) or for an accessor
+/// to shadow `as_ref` or `as_mut`. Accessors can't be used with `as = "..."`.
+///
+/// # Enum discriminants
+///
+/// By default, archived enum variants are numbered in order regardless of the discriminants of the
+/// source enum. With `#[archive(discriminants)]`, the archived enum uses the same discriminants as
+/// the source enum so that its tag matches the source discriminant byte-for-byte, which is useful
+/// for matching an external format. This requires the enum to have a `#[repr(i*)]` or
+/// `#[repr(u*)]` attribute or an archived repr, and the archived enum uses the integer repr of the
+/// source enum if no archived repr is specified. Validation only accepts the declared
+/// discriminants. Explicit discriminants must be integer literals, and a discriminant that doesn't
+/// fit in the archived tag type is a compile error.
 ///
 /// # Recursive types
 ///
@@ -99,6 +138,13 @@ pub fn derive_serialize(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 ///
 /// This macro also supports the `#[archive]`, `#[omit_bounds]`, and `#[with]` attributes. See
 /// [`Archive`] for more information.
+///
+/// Fields can be marked with `#[archive(deserialize_with = "...")]` to deserialize them with the
+/// given function instead of their `Deserialize` implementation. The function is called with a
+/// reference to the archived field and the deserializer, and returns either the deserialized field
+/// or an error. This can be used to validate or normalize fields while deserializing. Any bounds
+/// that the function places on the deserializer must be added with
+/// `#[archive(bound(deserialize = "..."))]`, where the deserializer is named `__D`.
 #[proc_macro_derive(Deserialize, attributes(archive, omit_bounds, with))]
 pub fn derive_deserialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let mut derive_input = parse_macro_input!(input as DeriveInput);
@@ -109,3 +155,29 @@ pub fn derive_deserialize(input: proc_macro::TokenStream) -> proc_macro::TokenSt
         Err(e) => e.to_compile_error().into(),
     }
 }
+
+/// Marks a trait so that boxed trait objects of it can be archived.
+///
+/// This generates a companion trait named "Serialize" + `the name of the trait`, which has the
+/// marked trait and `SerializeDyn` as supertraits and is implemented for every type that implements
+/// both. Fields of type `Box<dyn SerializeTrait>` can then be archived with the `AsDyn` wrapper,
+/// and are accessed as `ArchivedDyn<dyn Trait>`.
+///
+/// # Arguments
+///
+/// - `serialize = "..."`: Changes the name of the generated serialization trait to the given value.
+/// - `crate = "..."`: Chooses an alternative crate path to import rkyv from.
+#[cfg(feature = "archive_dyn")]
+#[proc_macro_attribute]
+pub fn archive_dyn(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(attr as AttributeArgs);
+    let input = parse_macro_input!(item as ItemTrait);
+
+    match archive_dyn::archive_dyn(args, input) {
+        Ok(result) => result.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
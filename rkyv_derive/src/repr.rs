@@ -1,6 +1,9 @@
 use proc_macro2::{Literal, Punct, Spacing, Span, TokenStream};
 use quote::{quote, ToTokens, TokenStreamExt};
-use syn::{spanned::Spanned, Error, Lit, LitInt, Meta, NestedMeta};
+use syn::{
+    spanned::Spanned, Attribute, Error, Expr, ExprLit, ExprParen, ExprUnary, Lit, LitInt, Meta,
+    NestedMeta, Path, UnOp, Variant,
+};
 
 #[derive(Clone, Copy)]
 pub enum IntRepr {
@@ -34,6 +37,32 @@ impl ToTokens for IntRepr {
 }
 
 impl IntRepr {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        if path.is_ident("i8") {
+            Some(Self::I8)
+        } else if path.is_ident("i16") {
+            Some(Self::I16)
+        } else if path.is_ident("i32") {
+            Some(Self::I32)
+        } else if path.is_ident("i64") {
+            Some(Self::I64)
+        } else if path.is_ident("i128") {
+            Some(Self::I128)
+        } else if path.is_ident("u8") {
+            Some(Self::U8)
+        } else if path.is_ident("u16") {
+            Some(Self::U16)
+        } else if path.is_ident("u32") {
+            Some(Self::U32)
+        } else if path.is_ident("u64") {
+            Some(Self::U64)
+        } else if path.is_ident("u128") {
+            Some(Self::U128)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     #[cfg(not(feature = "arbitrary_enum_discriminant"))]
     pub fn enum_discriminant(&self, _: usize) -> Option<EnumDiscriminant> {
@@ -43,35 +72,126 @@ impl IntRepr {
     #[inline]
     #[cfg(feature = "arbitrary_enum_discriminant")]
     pub fn enum_discriminant(&self, index: usize) -> EnumDiscriminant {
-        #[cfg(not(any(
-            all(target_endian = "little", feature = "archive_be"),
-            all(target_endian = "big", feature = "archive_le"),
-        )))]
-        let value = index as u128;
+        self.explicit_discriminant(index as u128)
+    }
 
+    /// Returns the range of values that the integer type can hold, clamped to an `i128`.
+    fn range(&self) -> (i128, i128) {
+        match self {
+            Self::I8 => (i8::MIN as i128, i8::MAX as i128),
+            Self::I16 => (i16::MIN as i128, i16::MAX as i128),
+            Self::I32 => (i32::MIN as i128, i32::MAX as i128),
+            Self::I64 => (i64::MIN as i128, i64::MAX as i128),
+            Self::I128 => (i128::MIN, i128::MAX),
+            Self::U8 => (0, u8::MAX as i128),
+            Self::U16 => (0, u16::MAX as i128),
+            Self::U32 => (0, u32::MAX as i128),
+            Self::U64 => (0, u64::MAX as i128),
+            Self::U128 => (0, i128::MAX),
+        }
+    }
+
+    /// Returns the archived discriminant for a discriminant value.
+    ///
+    /// Negative values are passed as their two's complement bits.
+    #[inline]
+    pub fn explicit_discriminant(&self, value: u128) -> EnumDiscriminant {
         #[cfg(any(
             all(target_endian = "little", feature = "archive_be"),
             all(target_endian = "big", feature = "archive_le"),
         ))]
         let value = match self {
-            Self::I8 => (index as i8).swap_bytes() as u128,
-            Self::I16 => (index as i16).swap_bytes() as u128,
-            Self::I32 => (index as i32).swap_bytes() as u128,
-            Self::I64 => (index as i64).swap_bytes() as u128,
-            Self::I128 => (index as i128).swap_bytes() as u128,
-            Self::U8 => (index as u8).swap_bytes() as u128,
-            Self::U16 => (index as u16).swap_bytes() as u128,
-            Self::U32 => (index as u32).swap_bytes() as u128,
-            Self::U64 => (index as u64).swap_bytes() as u128,
-            Self::U128 => (index as u128).swap_bytes(),
+            Self::I8 => (value as i8).swap_bytes() as u128,
+            Self::I16 => (value as i16).swap_bytes() as u128,
+            Self::I32 => (value as i32).swap_bytes() as u128,
+            Self::I64 => (value as i64).swap_bytes() as u128,
+            Self::I128 => (value as i128).swap_bytes() as u128,
+            Self::U8 => (value as u8).swap_bytes() as u128,
+            Self::U16 => (value as u16).swap_bytes() as u128,
+            Self::U32 => (value as u32).swap_bytes() as u128,
+            Self::U64 => (value as u64).swap_bytes() as u128,
+            Self::U128 => value.swap_bytes(),
         };
 
         EnumDiscriminant { repr: *self, value }
     }
 }
 
-// None of these variants are constructed unless the arbitrary_enum_discriminant feature is enabled
-#[allow(dead_code)]
+/// Gets the integer repr of an item from its `#[repr(...)]` attributes, if it has one.
+pub fn int_repr_of(attrs: &[Attribute]) -> Option<(IntRepr, Span)> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list),
+            _ => None,
+        })
+        .flat_map(|list| list.nested.into_iter())
+        .find_map(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => {
+                IntRepr::from_path(&path).map(|int_repr| (int_repr, path.span()))
+            }
+            _ => None,
+        })
+}
+
+/// Gets the values of the discriminants of some enum variants, some of which have explicit
+/// discriminants.
+///
+/// Variants without an explicit discriminant take the value of the previous variant plus one, like
+/// Rust enums. Values that don't fit in `int_repr` are reported as errors. Negative values are
+/// returned as their two's complement bits.
+pub fn explicit_discriminants<'a>(
+    variants: impl Iterator<Item = &'a Variant>,
+    int_repr: IntRepr,
+) -> Result<Vec<u128>, Error> {
+    let (min, max) = int_repr.range();
+    let mut next = Some(0i128);
+    let mut values = Vec::new();
+    for variant in variants {
+        let (value, spanned): (_, &dyn ToTokens) = match variant.discriminant {
+            Some((_, ref expr)) => (parse_discriminant(expr)?, expr),
+            None => (next, &variant.ident),
+        };
+        match value {
+            Some(value) if min <= value && value <= max => {
+                values.push(value as u128);
+                next = value.checked_add(1);
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    spanned,
+                    format!(
+                        "discriminant does not fit in the archived tag type `{}`",
+                        int_repr.to_token_stream()
+                    ),
+                ))
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+/// Parses an integer literal discriminant, returning `None` if it doesn't fit in an `i128`.
+fn parse_discriminant(expr: &Expr) -> Result<Option<i128>, Error> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => Ok(int.base10_parse::<i128>().ok()),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => Ok(parse_discriminant(expr)?.and_then(i128::checked_neg)),
+        Expr::Paren(ExprParen { expr, .. }) => parse_discriminant(expr),
+        _ => Err(Error::new_spanned(
+            expr,
+            "archived enum discriminants must be integer literals",
+        )),
+    }
+}
+
 pub struct EnumDiscriminant {
     repr: IntRepr,
     value: u128,
@@ -180,26 +300,8 @@ impl Repr {
                                 BaseRepr::Transparent
                             } else if path.is_ident("C") {
                                 BaseRepr::C
-                            } else if path.is_ident("i8") {
-                                BaseRepr::Int(IntRepr::I8)
-                            } else if path.is_ident("i16") {
-                                BaseRepr::Int(IntRepr::I16)
-                            } else if path.is_ident("i32") {
-                                BaseRepr::Int(IntRepr::I32)
-                            } else if path.is_ident("i64") {
-                                BaseRepr::Int(IntRepr::I64)
-                            } else if path.is_ident("i128") {
-                                BaseRepr::Int(IntRepr::I128)
-                            } else if path.is_ident("u8") {
-                                BaseRepr::Int(IntRepr::U8)
-                            } else if path.is_ident("u16") {
-                                BaseRepr::Int(IntRepr::U16)
-                            } else if path.is_ident("u32") {
-                                BaseRepr::Int(IntRepr::U32)
-                            } else if path.is_ident("u64") {
-                                BaseRepr::Int(IntRepr::U64)
-                            } else if path.is_ident("u128") {
-                                BaseRepr::Int(IntRepr::U128)
+                            } else if let Some(int_repr) = IntRepr::from_path(path) {
+                                BaseRepr::Int(int_repr)
                             } else {
                                 return Err(Error::new_spanned(
                                     path,
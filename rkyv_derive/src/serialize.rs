@@ -1,6 +1,6 @@
 use crate::{
     attributes::{parse_attributes, Attributes},
-    util::{add_bounds, strip_raw},
+    util::{add_bounds, as_native_field, strip_raw},
     with::{make_with_cast, make_with_ty},
 };
 use proc_macro2::TokenStream;
@@ -54,6 +54,32 @@ fn derive_serialize_impl(
         |value| value.clone(),
     );
 
+    if let Some(ref as_native) = attributes.as_native {
+        let (field, member) = as_native_field(&input, as_native)?;
+        let mut serialize_where = where_clause.clone();
+        let ty = with_ty(field)?;
+        if !field.attrs.iter().any(|a| a.path.is_ident("omit_bounds")) {
+            serialize_where
+                .predicates
+                .push(parse_quote! { #ty: Serialize<__S> });
+        }
+        let value = with_cast(field, parse_quote! { &self.#member })?;
+
+        return Ok(quote! {
+            #[automatically_derived]
+            const _: () = {
+                use #rkyv_path::{Archive, Fallible, Serialize};
+
+                impl #impl_generics Serialize<__S> for #name #ty_generics #serialize_where {
+                    #[inline]
+                    fn serialize(&self, serializer: &mut __S) -> ::core::result::Result<Self::Resolver, __S::Error> {
+                        Serialize::<__S>::serialize(#value, serializer)
+                    }
+                }
+            };
+        });
+    }
+
     let serialize_impl = match input.data {
         Data::Struct(ref data) => match data.fields {
             Fields::Named(ref fields) => {
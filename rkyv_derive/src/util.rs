@@ -1,5 +1,8 @@
 use proc_macro2::Ident;
-use syn::{punctuated::Punctuated, Error, LitStr, Token, WhereClause, WherePredicate};
+use syn::{
+    punctuated::Punctuated, Data, DeriveInput, Error, Field, Fields, Index, LitStr, Member, Path,
+    Token, WhereClause, WherePredicate,
+};
 
 pub fn add_bounds(bounds: &LitStr, where_clause: &mut WhereClause) -> Result<(), Error> {
     let clauses = bounds.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
@@ -16,3 +19,52 @@ pub fn strip_raw(ident: &Ident) -> String {
         .map(ToString::to_string)
         .unwrap_or(as_string)
 }
+
+/// Converts an identifier to snake case, keeping acronyms together (e.g. `HTTPRequest` becomes
+/// `http_request`).
+pub fn to_snake_case(ident: &Ident) -> String {
+    let chars = strip_raw(ident).chars().collect::<Vec<_>>();
+    let mut result = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 && chars[i - 1] != '_' {
+                let prev_lower = chars[i - 1].is_lowercase() || chars[i - 1].is_numeric();
+                let next_lower = chars.get(i + 1).map_or(false, |c| c.is_lowercase());
+                if prev_lower || (chars[i - 1].is_uppercase() && next_lower) {
+                    result.push('_');
+                }
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Gets the single field of a struct marked with `#[archive(as_native)]`, along with how to access
+/// it.
+pub fn as_native_field<'a>(
+    input: &'a DeriveInput,
+    as_native: &Path,
+) -> Result<(&'a Field, Member), Error> {
+    let field = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) if fields.named.len() == 1 => fields.named.first(),
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => fields.unnamed.first(),
+            _ => None,
+        },
+        _ => None,
+    };
+    let field = field.ok_or_else(|| {
+        Error::new_spanned(
+            as_native,
+            "as_native may only be used on structs with exactly one field",
+        )
+    })?;
+    let member = match field.ident {
+        Some(ref ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(0)),
+    };
+    Ok((field, member))
+}
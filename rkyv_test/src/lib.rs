@@ -50,6 +50,30 @@ mod tests {
         test_archive(&Some(42));
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_char() {
+        use rkyv::{Archive, Deserialize, Serialize};
+
+        test_archive(&'a');
+        test_archive(&'\0');
+        test_archive(&'\u{1F980}');
+        test_archive(&char::MAX);
+
+        #[derive(Archive, Deserialize, Serialize, Debug, PartialEq)]
+        #[archive(compare(PartialEq))]
+        #[archive_attr(derive(Debug))]
+        struct Glyph {
+            ascii: char,
+            emoji: char,
+        }
+
+        test_archive(&Glyph {
+            ascii: 'z',
+            emoji: '\u{1F600}',
+        });
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn archive_refs() {
@@ -100,6 +124,355 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_nonzero_niche() {
+        use core::{
+            mem::size_of,
+            num::{NonZeroI16, NonZeroI32, NonZeroI64, NonZeroU16, NonZeroU32, NonZeroU64},
+        };
+        use rkyv::{
+            niche::option_nonzero::{ArchivedOptionNonZeroU32, ArchivedOptionNonZeroU64},
+            with::{Niche, With},
+            Archived,
+        };
+
+        // Archived nonzero integers keep the niche of their native counterparts
+        assert_eq!(
+            size_of::<Option<Archived<NonZeroU16>>>(),
+            size_of::<Archived<u16>>()
+        );
+        assert_eq!(
+            size_of::<Option<Archived<NonZeroU32>>>(),
+            size_of::<Archived<u32>>()
+        );
+        assert_eq!(
+            size_of::<Option<Archived<NonZeroU64>>>(),
+            size_of::<Archived<u64>>()
+        );
+        assert_eq!(
+            size_of::<Option<Archived<NonZeroI16>>>(),
+            size_of::<Archived<i16>>()
+        );
+        assert_eq!(
+            size_of::<Option<Archived<NonZeroI32>>>(),
+            size_of::<Archived<i32>>()
+        );
+        assert_eq!(
+            size_of::<Option<Archived<NonZeroI64>>>(),
+            size_of::<Archived<i64>>()
+        );
+
+        // `ArchivedOption` has a stable `repr(u8)` layout, which rules out niche optimization. The
+        // `Niche` wrapper archives `Option<NonZero*>` without a separate tag instead.
+        assert_eq!(
+            size_of::<Archived<With<Option<NonZeroU32>, Niche>>>(),
+            size_of::<Archived<u32>>()
+        );
+        assert_eq!(
+            size_of::<Archived<With<Option<NonZeroI64>, Niche>>>(),
+            size_of::<Archived<i64>>()
+        );
+        assert_eq!(
+            size_of::<ArchivedOptionNonZeroU32>(),
+            size_of::<Archived<u32>>()
+        );
+        assert_eq!(
+            size_of::<ArchivedOptionNonZeroU64>(),
+            size_of::<Archived<u64>>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn serializer_align() {
+        use rkyv::{
+            ser::{serializers::BufferSerializer, Serializer},
+            AlignedBytes,
+        };
+
+        let mut serializer = BufferSerializer::new(AlignedBytes([0xffu8; 256]));
+        serializer.serialize_value(&1u8).unwrap();
+        assert_eq!(serializer.pos(), 1);
+
+        // Padding is filled with zeroes
+        assert_eq!(serializer.align(8).unwrap(), 8);
+        assert_eq!(serializer.pos(), 8);
+        assert_eq!(serializer.align(8).unwrap(), 8);
+        assert_eq!(serializer.align_for::<u8>().unwrap(), 8);
+
+        serializer.write(&[2, 3]).unwrap();
+        assert_eq!(serializer.align_for::<u32>().unwrap(), 12);
+
+        // Alignments larger than the internal padding buffer are supported
+        assert_eq!(serializer.align(64).unwrap(), 64);
+        assert_eq!(serializer.align(128).unwrap(), 128);
+
+        let buf = serializer.into_inner();
+        assert_eq!(buf[0], 1);
+        assert_eq!(&buf[1..8], &[0; 7]);
+        assert_eq!(&buf[8..12], &[2, 3, 0, 0]);
+        assert!(buf[12..128].iter().all(|&b| b == 0));
+        assert_eq!(buf[128], 0xff);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn core_serializer_error() {
+        use rkyv::{
+            archived_root,
+            ser::{
+                serializers::{BufferSerializerError, CoreSerializer, CoreSerializerError},
+                Serializer,
+            },
+        };
+
+        fn serialize<const N: usize>(value: &[u32; 16]) -> Result<usize, CoreSerializerError> {
+            let mut serializer = CoreSerializer::<N, 0>::default();
+            serializer.serialize_value(value)?;
+            Ok(serializer.pos())
+        }
+
+        let value = [7u32; 16];
+
+        match serialize::<32>(&value) {
+            Err(CoreSerializerError::SerializerError(BufferSerializerError::Overflow {
+                pos,
+                bytes_needed,
+                archive_len,
+            })) => {
+                assert_eq!(pos, 0);
+                assert_eq!(bytes_needed, 64);
+                assert_eq!(archive_len, 32);
+            }
+            _ => panic!("expected the serializer buffer to overflow"),
+        }
+
+        let mut serializer = CoreSerializer::<64, 0>::default();
+        serializer.serialize_value(&value).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<[u32; 16]>(buf.as_ref()) };
+        assert_eq!(archived, &value);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_ranges() {
+        use core::ops::{Range, RangeInclusive};
+        use rkyv::{archived_root, ser::Serializer, Deserialize, Infallible};
+
+        test_archive(&(3u32..17));
+        test_archive(&(5u32..5));
+        test_archive(&(-40i64..=-2));
+        test_archive(&(i64::MIN..=i64::MAX));
+        test_archive(&RangeInclusive::new(8i64, 3));
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&(3u32..17)).unwrap();
+        let len = serializer.pos();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<Range<u32>>(&buf[0..len]) };
+        assert!(archived.contains(&3));
+        assert!(archived.contains(&16));
+        assert!(!archived.contains(&17));
+        assert!(!archived.is_empty());
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&(5u32..5)).unwrap();
+        let len = serializer.pos();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<Range<u32>>(&buf[0..len]) };
+        assert!(!archived.contains(&5));
+        assert!(archived.is_empty());
+
+        // Exhausting a `RangeInclusive` doesn't change its bounds, and the iteration state is not
+        // archived.
+        let mut exhausted = 7i64..=7;
+        assert_eq!(exhausted.next(), Some(7));
+        assert!(exhausted.is_empty());
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&exhausted).unwrap();
+        let len = serializer.pos();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<RangeInclusive<i64>>(&buf[0..len]) };
+        assert_eq!(archived.start, 7);
+        assert_eq!(archived.end, 7);
+        assert!(archived.contains(&7));
+        assert!(!archived.is_empty());
+
+        let deserialized: RangeInclusive<i64> = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, 7..=7);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_large_arrays() {
+        use rkyv::{Archive, Deserialize, Serialize};
+
+        #[derive(Archive, Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+        #[archive(compare(PartialEq))]
+        #[archive_attr(derive(Debug))]
+        struct MyStruct {
+            a: u8,
+            b: i16,
+        }
+
+        let mut values = [0u16; 100];
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = (i * 3) as u16;
+        }
+        test_archive(&values);
+
+        let mut structs = [MyStruct { a: 0, b: 0 }; 40];
+        for (i, value) in structs.iter_mut().enumerate() {
+            *value = MyStruct {
+                a: i as u8,
+                b: -(i as i16),
+            };
+        }
+        test_archive(&structs);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(feature = "absolute_pointers")]
+    fn absolute_rel_ptr() {
+        use core::mem::{size_of, MaybeUninit};
+        use rkyv::{
+            archived_root, rel_ptr::AbsoluteRelPtr, ser::Serializer, Archive, Archived, Serialize,
+        };
+
+        #[derive(Archive, Serialize)]
+        struct Target {
+            a: u32,
+            b: u32,
+        }
+
+        // Archive the target in its own buffer
+        let mut serializer = DefaultSerializer::default();
+        serializer
+            .serialize_value(&Target { a: 42, b: 24 })
+            .unwrap();
+        let len = serializer.pos();
+        let target_buf = serializer.into_serializer().into_inner();
+        let target = unsafe { archived_root::<Target>(&target_buf[0..len]) };
+
+        // Point at it from a separately-allocated buffer
+        let mut ptr = MaybeUninit::<AbsoluteRelPtr<ArchivedTarget>>::uninit();
+        unsafe {
+            AbsoluteRelPtr::emplace_absolute(target, (), ptr.as_mut_ptr());
+        }
+        let ptr = unsafe { ptr.assume_init() };
+        assert!(ptr.is_absolute());
+        assert!(core::ptr::eq(ptr.as_ptr(), target));
+        let resolved = unsafe { &*ptr.as_ptr() };
+        assert_eq!(resolved.a, 42);
+        assert_eq!(resolved.b, 24);
+
+        // Relative pointers still resolve relative to their own position
+        #[repr(C)]
+        struct Pair {
+            ptr: MaybeUninit<AbsoluteRelPtr<Archived<u32>>>,
+            value: Archived<u32>,
+        }
+        let mut pair = Pair {
+            ptr: MaybeUninit::uninit(),
+            value: 7,
+        };
+        unsafe {
+            AbsoluteRelPtr::try_emplace_relative(
+                0,
+                size_of::<AbsoluteRelPtr<Archived<u32>>>(),
+                (),
+                pair.ptr.as_mut_ptr(),
+            )
+            .unwrap();
+        }
+        let ptr = unsafe { pair.ptr.assume_init_ref() };
+        assert!(!ptr.is_absolute());
+        assert!(core::ptr::eq(ptr.as_ptr(), &pair.value));
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn deserialize_with() {
+        use rkyv::{
+            archived_root, ser::Serializer, Archive, Archived, Deserialize, Fallible, Serialize,
+        };
+
+        #[derive(Debug, PartialEq)]
+        struct OutOfRange(u8);
+
+        struct CheckedDeserializer;
+
+        impl Fallible for CheckedDeserializer {
+            type Error = OutOfRange;
+        }
+
+        fn check_percent<D: Fallible + ?Sized>(
+            value: &Archived<u8>,
+            _: &mut D,
+        ) -> Result<u8, D::Error>
+        where
+            D::Error: From<OutOfRange>,
+        {
+            if *value <= 100 {
+                Ok(*value)
+            } else {
+                Err(OutOfRange(*value).into())
+            }
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[archive(bound(deserialize = "__D::Error: From<OutOfRange>"))]
+        struct Progress {
+            step: u32,
+            #[archive(deserialize_with = "check_percent")]
+            percent: u8,
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[archive(bound(deserialize = "__D::Error: From<OutOfRange>"))]
+        enum Task {
+            Running(#[archive(deserialize_with = "check_percent")] u8),
+            Done,
+        }
+
+        let deserialize_progress = |value: &Progress| {
+            let mut serializer = DefaultSerializer::default();
+            serializer.serialize_value(value).unwrap();
+            let len = serializer.pos();
+            let buf = serializer.into_serializer().into_inner();
+            let archived = unsafe { archived_root::<Progress>(&buf[0..len]) };
+            archived.deserialize(&mut CheckedDeserializer)
+        };
+
+        let valid = Progress {
+            step: 3,
+            percent: 75,
+        };
+        assert_eq!(deserialize_progress(&valid), Ok(valid));
+        let invalid = Progress {
+            step: 4,
+            percent: 150,
+        };
+        assert_eq!(deserialize_progress(&invalid), Err(OutOfRange(150)));
+
+        let deserialize_task = |value: &Task| {
+            let mut serializer = DefaultSerializer::default();
+            serializer.serialize_value(value).unwrap();
+            let len = serializer.pos();
+            let buf = serializer.into_serializer().into_inner();
+            let archived = unsafe { archived_root::<Task>(&buf[0..len]) };
+            archived.deserialize(&mut CheckedDeserializer)
+        };
+
+        assert_eq!(deserialize_task(&Task::Running(10)), Ok(Task::Running(10)));
+        assert_eq!(deserialize_task(&Task::Done), Ok(Task::Done));
+        assert_eq!(deserialize_task(&Task::Running(101)), Err(OutOfRange(101)));
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn archive_zst() {
@@ -116,6 +489,321 @@ mod tests {
         test_archive_ref::<[MyZST]>(&[MyZST, MyZST, MyZST, MyZST]);
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_as_native() {
+        use core::mem::size_of;
+        use rkyv::{
+            archived_root, ser::Serializer, Archive, Archived, Deserialize, Infallible, Serialize,
+        };
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[archive(as_native)]
+        struct Meters(f32);
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[archive(as_native)]
+        struct Id {
+            value: u64,
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        struct Route {
+            id: Id,
+            length: Meters,
+        }
+
+        assert_eq!(size_of::<Archived<Meters>>(), size_of::<Archived<f32>>());
+        assert_eq!(size_of::<Archived<Id>>(), size_of::<Archived<u64>>());
+
+        let value = Route {
+            id: Id { value: 42 },
+            length: Meters(12.5),
+        };
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let len = serializer.pos();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<Route>(&buf[0..len]) };
+
+        let id: &Archived<u64> = &archived.id;
+        let length: &Archived<f32> = &archived.length;
+        assert_eq!(*id, 42);
+        assert_eq!(*length, 12.5);
+
+        let deserialized: Route = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, value);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_option_combinators() {
+        use rkyv::{archived_root, ser::Serializer, Archive, Archived, Serialize};
+
+        #[derive(Archive, Serialize)]
+        struct Leaf {
+            value: u32,
+        }
+
+        #[derive(Archive, Serialize)]
+        struct Branch {
+            leaf: Option<Leaf>,
+        }
+
+        #[derive(Archive, Serialize)]
+        struct Root {
+            branch: Option<Branch>,
+        }
+
+        fn leaf_value(root: &ArchivedRoot) -> Option<&Archived<u32>> {
+            root.branch.and_then(|b| b.leaf.as_ref()).map(|l| &l.value)
+        }
+
+        let values = [
+            (
+                Root {
+                    branch: Some(Branch {
+                        leaf: Some(Leaf { value: 42 }),
+                    }),
+                },
+                Some(42),
+            ),
+            (
+                Root {
+                    branch: Some(Branch { leaf: None }),
+                },
+                None,
+            ),
+            (Root { branch: None }, None),
+        ];
+
+        for (value, expected) in values.iter() {
+            let mut serializer = DefaultSerializer::default();
+            serializer.serialize_value(value).unwrap();
+            let len = serializer.pos();
+            let buf = serializer.into_serializer().into_inner();
+            let archived = unsafe { archived_root::<Root>(&buf[0..len]) };
+
+            let found = leaf_value(archived);
+            assert_eq!(found.copied(), *expected);
+            if let Some(found) = found {
+                // The reference points into the archive instead of a copy
+                let leaf = archived.branch.as_ref().unwrap().leaf.as_ref().unwrap();
+                assert!(core::ptr::eq(found, &leaf.value));
+            }
+
+            let default = ArchivedLeaf { value: 7 };
+            let leaf = archived
+                .branch
+                .and_then(|b| b.leaf.as_ref())
+                .unwrap_or(&default);
+            let leaf_or = match archived.branch.as_ref() {
+                Some(branch) => branch.leaf.get_or(&default),
+                None => &default,
+            };
+            assert!(core::ptr::eq(leaf, leaf_or));
+            assert_eq!(leaf.value, expected.unwrap_or(7));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_borrowed_str_and_slice() {
+        use rkyv::{archived_root, ser::Serializer};
+
+        let text = "a borrowed string that is too long to be inlined";
+        for &value in &["", "short", text] {
+            let mut serializer = DefaultSerializer::default();
+            serializer.serialize_value(&value).unwrap();
+            let len = serializer.pos();
+            let buf = serializer.into_serializer().into_inner();
+            let archived = unsafe { archived_root::<&str>(&buf[0..len]) };
+            assert_eq!(archived.as_str(), value);
+        }
+
+        let values = [1u32, 2, 3, 4];
+        let slice = &values[1..];
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&slice).unwrap();
+        let len = serializer.pos();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<&[u32]>(&buf[0..len]) };
+        assert_eq!(archived.as_slice(), slice);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_zero_sized_types() {
+        use core::{
+            marker::PhantomData,
+            mem::{align_of, size_of},
+        };
+        use rkyv::{
+            archived_root, ser::Serializer, Archive, Archived, Deserialize, Infallible, Serialize,
+        };
+
+        // Not Archive, and never touched through the PhantomData
+        struct Opaque;
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        struct Plain {
+            id: u16,
+            flag: bool,
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        struct Tagged<T, U> {
+            id: u16,
+            _opaque: PhantomData<T>,
+            flag: bool,
+            _wide: PhantomData<U>,
+            unit: (),
+        }
+
+        assert_eq!(size_of::<Archived<PhantomData<Opaque>>>(), 0);
+        assert_eq!(align_of::<Archived<PhantomData<u64>>>(), 1);
+        assert_eq!(size_of::<Archived<()>>(), 0);
+        assert_eq!(align_of::<Archived<()>>(), 1);
+        assert_eq!(
+            size_of::<ArchivedTagged<Opaque, u128>>(),
+            size_of::<ArchivedPlain>()
+        );
+        assert_eq!(
+            align_of::<ArchivedTagged<Opaque, u128>>(),
+            align_of::<ArchivedPlain>()
+        );
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&PhantomData::<Opaque>).unwrap();
+        serializer.serialize_value(&()).unwrap();
+        assert_eq!(serializer.pos(), 0);
+
+        let value = Tagged::<Opaque, u128> {
+            id: 42,
+            _opaque: PhantomData,
+            flag: true,
+            _wide: PhantomData,
+            unit: (),
+        };
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let len = serializer.pos();
+        assert_eq!(len, size_of::<ArchivedPlain>());
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<Tagged<Opaque, u128>>(&buf[0..len]) };
+        assert_eq!(archived.id, 42);
+        assert!(archived.flag);
+
+        let deserialized: Tagged<Opaque, u128> = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized.id, 42);
+        assert!(deserialized.flag);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_explicit_discriminants() {
+        use rkyv::{archived_root, ser::Serializer, Archive, Deserialize, Infallible, Serialize};
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[archive(compare(PartialEq), discriminants)]
+        #[archive_attr(derive(Debug))]
+        #[repr(u8)]
+        enum Color {
+            Red = 3,
+            Green = 7,
+            Blue,
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[archive(compare(PartialEq), discriminants)]
+        #[archive_attr(derive(Debug))]
+        #[repr(u8)]
+        enum Message {
+            Ping = 0x10,
+            Data(u8) = 0x20,
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[archive(discriminants)]
+        #[repr(u16)]
+        enum Wide {
+            A = 0x0102,
+            B = 0x0304,
+        }
+
+        fn tag_bytes<T: Serialize<DefaultSerializer>>(value: &T) -> [u8; 2] {
+            let mut serializer = DefaultSerializer::default();
+            serializer.serialize_value(value).unwrap();
+            let len = serializer.pos();
+            let buf = serializer.into_serializer().into_inner();
+            let pos = len - core::mem::size_of::<T::Archived>();
+            [buf[pos], buf.get(pos + 1).copied().unwrap_or(0)]
+        }
+
+        assert_eq!(ArchivedColor::Red as u8, 3);
+        assert_eq!(ArchivedColor::Green as u8, 7);
+        assert_eq!(ArchivedColor::Blue as u8, 8);
+        assert_eq!(tag_bytes(&Color::Red)[0], 3);
+        assert_eq!(tag_bytes(&Color::Green)[0], 7);
+        assert_eq!(tag_bytes(&Color::Blue)[0], 8);
+        assert_eq!(tag_bytes(&Message::Ping)[0], 0x10);
+        assert_eq!(tag_bytes(&Message::Data(0xff)), [0x20, 0xff]);
+
+        #[cfg(not(any(feature = "archive_le", feature = "archive_be")))]
+        assert_eq!(tag_bytes(&Wide::A), 0x0102u16.to_ne_bytes());
+        #[cfg(feature = "archive_le")]
+        assert_eq!(tag_bytes(&Wide::A), 0x0102u16.to_le_bytes());
+        #[cfg(feature = "archive_be")]
+        assert_eq!(tag_bytes(&Wide::B), 0x0304u16.to_be_bytes());
+
+        test_archive(&Color::Red);
+        test_archive(&Color::Blue);
+        test_archive(&Message::Ping);
+        test_archive(&Message::Data(42));
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&Wide::B).unwrap();
+        let len = serializer.pos();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<Wide>(&buf[0..len]) };
+        let deserialized: Wide = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, Wide::B);
+
+        // Without `#[archive(discriminants)]`, explicit discriminants are ignored
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[archive(compare(PartialEq))]
+        #[archive_attr(derive(Debug))]
+        #[repr(i16)]
+        enum Wire {
+            A = 1,
+            B = 257,
+            C = -3,
+        }
+
+        assert_eq!(ArchivedWire::A as u8, 0);
+        assert_eq!(ArchivedWire::B as u8, 1);
+        assert_eq!(ArchivedWire::C as u8, 2);
+        test_archive(&Wire::B);
+        test_archive(&Wire::C);
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[archive(compare(PartialEq), discriminants)]
+        #[archive_attr(derive(Debug))]
+        #[repr(i16)]
+        enum Signed {
+            Low = -300,
+            Mid,
+            High = 1000,
+        }
+
+        assert_eq!(ArchivedSigned::Low as i16, -300);
+        assert_eq!(ArchivedSigned::Mid as i16, -299);
+        assert_eq!(ArchivedSigned::High as i16, 1000);
+        test_archive(&Signed::Low);
+        test_archive(&Signed::High);
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     #[allow(non_camel_case_types)]
@@ -139,4 +827,599 @@ mod tests {
         test_archive::<r#virtual>(&r#virtual { r#virtual: 42 });
         test_archive::<r#try>(&r#try::r#try { r#try: 42 });
     }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_named_types() {
+        use core::mem::{align_of, size_of};
+        use rkyv::{archived_root, ser::Serializer, Archived, Resolver, Serialize};
+
+        mod abi {
+            use rkyv::{Archive, Deserialize, Serialize};
+
+            #[derive(Archive, Deserialize, Serialize, Debug, PartialEq)]
+            #[archive(
+                archived = "PointAbi",
+                resolver = "PointAbiResolver",
+                compare(PartialEq)
+            )]
+            #[archive_attr(derive(Debug), repr(C))]
+            pub struct Point {
+                pub x: i32,
+                pub y: i32,
+            }
+        }
+
+        fn sum(point: &abi::PointAbi) -> i32 {
+            point.x + point.y
+        }
+
+        assert_eq!(
+            size_of::<abi::PointAbi>(),
+            size_of::<Archived<abi::Point>>()
+        );
+        assert_eq!(size_of::<abi::PointAbi>(), 8);
+        assert_eq!(align_of::<abi::PointAbi>(), align_of::<Archived<i32>>());
+        assert_eq!(
+            size_of::<abi::PointAbiResolver>(),
+            size_of::<Resolver<abi::Point>>()
+        );
+
+        let value = abi::Point { x: 3, y: 4 };
+        let mut serializer = DefaultSerializer::default();
+        let resolver: abi::PointAbiResolver = value.serialize(&mut serializer).unwrap();
+        let pos = unsafe { serializer.resolve_aligned(&value, resolver).unwrap() };
+        let len = serializer.pos();
+        let buf = serializer.into_serializer().into_inner();
+
+        assert_eq!(pos + size_of::<abi::PointAbi>(), len);
+        let archived: &abi::PointAbi = unsafe { archived_root::<abi::Point>(&buf[0..len]) };
+        assert_eq!(archived, &value);
+        assert_eq!(sum(archived), 7);
+
+        test_archive(&value);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn varint_offset_boundaries() {
+        use rkyv::rel_ptr::{Offset, OffsetError, RawRelPtr, VarintOffset};
+
+        // One byte holds -64..=63
+        assert_eq!(VarintOffset::<1>::between(0, 63).unwrap().to_isize(), 63);
+        assert_eq!(VarintOffset::<1>::between(64, 0).unwrap().to_isize(), -64);
+        assert_eq!(
+            VarintOffset::<1>::between(0, 64),
+            Err(OffsetError::ExceedsStorageRange)
+        );
+        assert_eq!(
+            VarintOffset::<1>::between(65, 0),
+            Err(OffsetError::ExceedsStorageRange)
+        );
+
+        // Two bytes hold -8192..=8191
+        assert_eq!(VarintOffset::<2>::between(0, 64).unwrap().to_isize(), 64);
+        assert_eq!(
+            VarintOffset::<2>::between(0, 8191).unwrap().to_isize(),
+            8191
+        );
+        assert_eq!(
+            VarintOffset::<2>::between(8192, 0).unwrap().to_isize(),
+            -8192
+        );
+        assert_eq!(
+            VarintOffset::<2>::between(0, 8192),
+            Err(OffsetError::ExceedsStorageRange)
+        );
+        assert_eq!(
+            VarintOffset::<2>::between(8193, 0),
+            Err(OffsetError::ExceedsStorageRange)
+        );
+        assert_eq!(
+            VarintOffset::<3>::between(0, 8192).unwrap().to_isize(),
+            8192
+        );
+
+        assert_eq!(VarintOffset::<1>::MIN_OFFSET, -64);
+        assert_eq!(VarintOffset::<1>::MAX_OFFSET, 63);
+        assert_eq!(VarintOffset::<2>::MIN_OFFSET, -8192);
+        assert_eq!(VarintOffset::<2>::MAX_OFFSET, 8191);
+        assert_eq!(VarintOffset::<10>::MIN_OFFSET, isize::MIN);
+        assert_eq!(VarintOffset::<10>::MAX_OFFSET, isize::MAX);
+
+        // Short offsets zero the unused trailing bytes
+        assert_eq!(
+            VarintOffset::<4>::between(0, 1).unwrap().as_bytes(),
+            &[1, 0, 0, 0]
+        );
+
+        let mut buf = [0u8; 16];
+        let out = buf.as_mut_ptr().cast::<RawRelPtr<VarintOffset<2>>>();
+        unsafe {
+            RawRelPtr::emplace(0, 12, out);
+            assert_eq!((*out).offset(), 12);
+            assert_eq!((*out).as_ptr().cast::<u8>(), buf.as_ptr().add(12));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn define_offset() {
+        use rkyv::rel_ptr::{Offset, OffsetError};
+
+        rkyv::define_offset! {
+            struct U40([u8; 5], unsigned);
+            type RawRelPtrU40;
+        }
+
+        rkyv::define_offset! {
+            struct I8([u8; 1], signed);
+            type RawRelPtrI8;
+        }
+
+        assert_eq!(U40::MIN, 0);
+        assert_eq!(U40::MAX, (1 << 40) - 1);
+        assert_eq!(U40::new(U40::MAX).unwrap().get(), U40::MAX);
+        assert_eq!(U40::new(U40::MAX + 1), None);
+        assert_eq!(U40::between(1, 0), Err(OffsetError::ExceedsStorageRange));
+        assert_eq!(U40::MIN_OFFSET, 0);
+
+        // One-byte offsets behave like an `i8`
+        assert_eq!(I8::MIN, i8::MIN as i64);
+        assert_eq!(I8::MAX, i8::MAX as i64);
+        for value in i8::MIN..=i8::MAX {
+            let offset = I8::new(value as i64).unwrap();
+            assert_eq!(offset.get(), value as i64);
+            assert_eq!(offset.to_isize(), value as isize);
+        }
+        assert_eq!(I8::between(0, 128), Err(OffsetError::ExceedsStorageRange));
+        assert_eq!(I8::between(129, 0), Err(OffsetError::ExceedsStorageRange));
+
+        let mut buf = [0u8; 16];
+        let out = buf.as_mut_ptr().cast::<RawRelPtrU40>();
+        unsafe {
+            RawRelPtrU40::emplace(0, 12, out);
+            assert_eq!((*out).offset(), 12);
+            assert_eq!((*out).as_ptr().cast::<u8>(), buf.as_ptr().add(12));
+        }
+
+        let out = unsafe { buf.as_mut_ptr().add(8).cast::<RawRelPtrI8>() };
+        unsafe {
+            RawRelPtrI8::emplace(8, 2, out);
+            assert_eq!((*out).offset(), -6);
+            assert_eq!((*out).as_ptr().cast::<u8>(), buf.as_ptr().add(2));
+        }
+
+        #[cfg(feature = "validation")]
+        {
+            use bytecheck::CheckBytes;
+            use rkyv::validation::validators::DefaultValidator;
+
+            let mut validator = DefaultValidator::new(&buf);
+            let offset = I8::new(-6).unwrap();
+            unsafe {
+                let checked = I8::check_bytes(&offset, &mut validator).unwrap();
+                assert_eq!(checked.get(), -6);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn raw_rel_ptr_try_as_ptr() {
+        use rkyv::rel_ptr::RawRelPtrI8;
+
+        let mut buf = [0u8; 16];
+        let bounds = buf.as_ptr_range();
+        let out = unsafe { buf.as_mut_ptr().add(4).cast::<RawRelPtrI8>() };
+
+        unsafe {
+            RawRelPtrI8::emplace(4, 10, out);
+            assert_eq!((*out).try_as_ptr(bounds.clone()), Some((*out).as_ptr()));
+            RawRelPtrI8::emplace(4, 0, out);
+            assert_eq!((*out).try_as_ptr(bounds.clone()), Some((*out).as_ptr()));
+            RawRelPtrI8::emplace(4, 16, out);
+            assert_eq!((*out).try_as_ptr(bounds.clone()), Some((*out).as_ptr()));
+            RawRelPtrI8::emplace(4, 17, out);
+            assert_eq!((*out).try_as_ptr(bounds.clone()), None);
+            RawRelPtrI8::emplace(8, 0, out);
+            assert_eq!((*out).try_as_ptr(bounds), None);
+        }
+
+        let bounds = buf.as_mut_ptr_range();
+        let out = unsafe { buf.as_mut_ptr().add(4).cast::<RawRelPtrI8>() };
+        unsafe {
+            RawRelPtrI8::emplace(4, 12, out);
+            assert!((*out).try_as_mut_ptr(bounds.clone()).is_some());
+            RawRelPtrI8::emplace(4, 100, out);
+            assert!((*out).try_as_mut_ptr(bounds).is_none());
+        }
+    }
+
+    #[cfg(not(feature = "debug_rel_ptr"))]
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn endian_offsets() {
+        use rkyv::rel_ptr::{BigEndianOffset, LittleEndianOffset, RawRelPtr};
+
+        #[repr(C, align(4))]
+        struct Buffer([u8; 16]);
+
+        // Serialize with one endianness
+        let mut le = Buffer([0; 16]);
+        let mut be = Buffer([0; 16]);
+        unsafe {
+            RawRelPtr::<LittleEndianOffset<u32>>::emplace(0, 12, le.0.as_mut_ptr().cast());
+            RawRelPtr::<BigEndianOffset<u32>>::emplace(0, 12, be.0.as_mut_ptr().cast());
+        }
+        assert_eq!(&le.0[..4], &[12, 0, 0, 0]);
+        assert_eq!(&be.0[..4], &[0, 0, 0, 12]);
+
+        // Resolve bytes that were written with the other endianness
+        let mut le = Buffer([0; 16]);
+        le.0[4..8].copy_from_slice(&[0xfc, 0xff, 0xff, 0xff]);
+        let mut be = Buffer([0; 16]);
+        be.0[4..8].copy_from_slice(&[0x00, 0x00, 0x00, 0x08]);
+        unsafe {
+            let le_ptr = &*le
+                .0
+                .as_ptr()
+                .add(4)
+                .cast::<RawRelPtr<LittleEndianOffset<i32>>>();
+            assert_eq!(le_ptr.offset(), -4);
+            assert_eq!(le_ptr.as_ptr().cast::<u8>(), le.0.as_ptr());
+
+            let be_ptr = &*be
+                .0
+                .as_ptr()
+                .add(4)
+                .cast::<RawRelPtr<BigEndianOffset<u32>>>();
+            assert_eq!(be_ptr.offset(), 8);
+            assert_eq!(be_ptr.as_ptr().cast::<u8>(), be.0.as_ptr().add(12));
+        }
+
+        assert_eq!(
+            LittleEndianOffset::<u16>::from_bytes([0x34, 0x12]).to_bytes(),
+            [0x34, 0x12]
+        );
+        assert_eq!(
+            BigEndianOffset::<u16>::from_bytes([0x12, 0x34]).to_bytes(),
+            [0x12, 0x34]
+        );
+    }
+
+    #[cfg(not(feature = "debug_rel_ptr"))]
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn offset_24() {
+        use rkyv::rel_ptr::{Offset, OffsetError, RawRelPtrI24, I24, U24};
+
+        let min = 1usize << 23;
+        assert_eq!(I24::between(min, 0).unwrap().to_isize(), -(1 << 23));
+        assert_eq!(I24::between(0, min - 1).unwrap().to_isize(), (1 << 23) - 1);
+        assert_eq!(I24::between(0, 1).unwrap().to_isize(), 1);
+        assert_eq!(I24::between(1, 0).unwrap().to_isize(), -1);
+        assert_eq!(
+            I24::between(min + 1, 0),
+            Err(OffsetError::ExceedsStorageRange)
+        );
+        assert_eq!(I24::between(0, min), Err(OffsetError::ExceedsStorageRange));
+
+        let max = (1usize << 24) - 1;
+        assert_eq!(U24::between(0, max).unwrap().to_isize(), max as isize);
+        assert_eq!(
+            U24::between(0, max + 1),
+            Err(OffsetError::ExceedsStorageRange)
+        );
+        assert_eq!(U24::between(1, 0), Err(OffsetError::ExceedsStorageRange));
+
+        assert_eq!(I24::MIN_OFFSET, -(1 << 23));
+        assert_eq!(I24::MAX_OFFSET, (1 << 23) - 1);
+        assert_eq!(U24::MIN_OFFSET, 0);
+        assert_eq!(U24::MAX_OFFSET, (1 << 24) - 1);
+
+        assert_eq!(core::mem::size_of::<RawRelPtrI24>(), 3);
+        assert_eq!(core::mem::align_of::<RawRelPtrI24>(), 1);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn rel_ptr_iter() {
+        use core::mem::{size_of, MaybeUninit};
+        use rkyv::{rel_ptr::RelPtrIter, RelPtr};
+
+        #[repr(C)]
+        struct Node {
+            value: u32,
+            next: RelPtr<Node>,
+        }
+
+        fn sum(head: &RelPtr<Node>, max_steps: Option<usize>) -> u32 {
+            let iter = unsafe { RelPtrIter::new(head, |node| &node.next) };
+            match max_steps {
+                Some(max) => iter.with_max_steps(max).map(|n| n.value).sum(),
+                None => iter.map(|n| n.value).sum(),
+            }
+        }
+
+        let mut nodes = MaybeUninit::<[Node; 4]>::uninit();
+        let base = nodes.as_mut_ptr().cast::<Node>();
+        let pos = |i: usize| i * size_of::<Node>();
+        unsafe {
+            for i in 0..4 {
+                let node = base.add(i);
+                (*node).value = i as u32 + 1;
+                let next = core::ptr::addr_of_mut!((*node).next);
+                let next_pos = next as usize - base as usize;
+                if i < 3 {
+                    RelPtr::emplace(next_pos, pos(i + 1), next);
+                } else {
+                    RelPtr::emplace_null(next_pos, next);
+                }
+            }
+        }
+        let nodes = unsafe { nodes.assume_init() };
+
+        assert_eq!(sum(&nodes[0].next, None), 2 + 3 + 4);
+        assert_eq!(sum(&nodes[2].next, None), 4);
+        assert_eq!(sum(&nodes[3].next, None), 0);
+        assert_eq!(sum(&nodes[0].next, Some(2)), 2 + 3);
+        assert_eq!(sum(&nodes[0].next, Some(0)), 0);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn raw_rel_ptr_with_metadata() {
+        use rkyv::{Archived, RawRelPtr};
+
+        #[repr(C)]
+        struct Block {
+            ptr: RawRelPtr,
+            items: [u32; 4],
+        }
+
+        let mut block = Block {
+            ptr: unsafe { core::mem::zeroed() },
+            items: [1, 2, 3, 4],
+        };
+        let base = &block as *const Block as usize;
+        let items_pos = block.items.as_ptr() as usize - base;
+        unsafe {
+            RawRelPtr::emplace(0, items_pos, &mut block.ptr);
+        }
+
+        let len: Archived<usize> = rkyv::to_archived!(3 as rkyv::FixedUsize);
+        let slice = unsafe { &*block.ptr.with_metadata::<[u32]>(len) };
+        assert_eq!(slice, &[1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn rel_ptr_metadata_mut() {
+        use core::mem::MaybeUninit;
+        use rkyv::{Archived, RelPtr};
+
+        #[repr(C)]
+        struct Block {
+            ptr: RelPtr<[Archived<u32>]>,
+            items: [Archived<u32>; 4],
+        }
+
+        let items = [1u32, 2, 3, 4];
+        let mut block = MaybeUninit::<Block>::uninit();
+        let out = block.as_mut_ptr();
+        let (items_pos, items_out) = rkyv::out_field!(out.items);
+        let (ptr_pos, ptr_out) = rkyv::out_field!(out.ptr);
+        unsafe {
+            for (i, item) in items.iter().enumerate() {
+                items_out
+                    .cast::<Archived<u32>>()
+                    .add(i)
+                    .write(rkyv::to_archived!(*item));
+            }
+            RelPtr::resolve_emplace(ptr_pos, items_pos, items.as_ref(), (), ptr_out);
+        }
+        let mut block = unsafe { block.assume_init() };
+        assert_eq!(unsafe { &*block.ptr.as_ptr() }, &[1, 2, 3, 4]);
+
+        unsafe {
+            *block.ptr.metadata_mut() = rkyv::to_archived!(2 as rkyv::FixedUsize);
+        }
+        assert_eq!(unsafe { &*block.ptr.as_ptr() }, &[1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn rel_ptr_checked_as_ptr() {
+        use core::mem::size_of;
+        use rkyv::{Archived, FixedUsize, RelPtr};
+
+        #[repr(C, align(4))]
+        struct Buffer([u8; 32]);
+
+        let mut buf = Buffer([0; 32]);
+        let items = [1u32, 2, 3, 4];
+        let items_pos = size_of::<RelPtr<[Archived<u32>]>>();
+        let out = buf.0.as_mut_ptr().cast::<RelPtr<[Archived<u32>]>>();
+        unsafe {
+            RelPtr::resolve_emplace(0, items_pos, items.as_ref(), (), out);
+        }
+        let ptr = unsafe { &mut *out };
+
+        let checked = ptr.checked_as_ptr(&buf.0).unwrap();
+        assert_eq!(checked, ptr.as_ptr());
+
+        // The slice is cut off by the end of the archive
+        assert!(ptr.checked_as_ptr(&buf.0[..items_pos + 8]).is_none());
+        assert!(ptr
+            .checked_as_ptr(&buf.0[..items_pos + 4 * size_of::<u32>()])
+            .is_some());
+
+        // The slice length is too large for the archive
+        unsafe {
+            *ptr.metadata_mut() = rkyv::to_archived!(100 as FixedUsize);
+        }
+        assert!(ptr.checked_as_ptr(&buf.0).is_none());
+        unsafe {
+            *ptr.metadata_mut() = rkyv::to_archived!(FixedUsize::MAX);
+        }
+        assert!(ptr.checked_as_ptr(&buf.0).is_none());
+
+        // The relative pointer isn't located in the archive
+        assert!(ptr.checked_as_ptr(&buf.0[4..]).is_none());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn scaled_offset() {
+        use rkyv::{
+            rel_ptr::{Offset, OffsetError, RawRelPtr, ScaledOffset},
+            Archived,
+        };
+
+        type Scaled = ScaledOffset<Archived<u16>, 8>;
+
+        assert_eq!(Scaled::between(0, 8).unwrap().to_isize(), 8);
+        assert_eq!(Scaled::between(0, 8).unwrap().inner().to_isize(), 1);
+        assert_eq!(Scaled::between(0, 65535 * 8).unwrap().to_isize(), 65535 * 8);
+        assert_eq!(
+            Scaled::between(0, 65536 * 8),
+            Err(OffsetError::ExceedsStorageRange)
+        );
+        assert_eq!(
+            Scaled::between(0, 12),
+            Err(OffsetError::ExceedsStorageRange)
+        );
+        assert_eq!(Scaled::between(8, 0), Err(OffsetError::ExceedsStorageRange));
+
+        type SignedScaled = ScaledOffset<Archived<i8>, 4>;
+
+        assert_eq!(SignedScaled::between(512, 0).unwrap().to_isize(), -512);
+        assert_eq!(SignedScaled::between(0, 508).unwrap().to_isize(), 508);
+        assert_eq!(
+            SignedScaled::between(0, 512),
+            Err(OffsetError::ExceedsStorageRange)
+        );
+        assert_eq!(
+            SignedScaled::between(3, 0),
+            Err(OffsetError::ExceedsStorageRange)
+        );
+        assert_eq!(SignedScaled::MIN_OFFSET, -512);
+        assert_eq!(SignedScaled::MAX_OFFSET, 508);
+
+        let mut buf = [0u8; 64];
+        let out = buf.as_mut_ptr().cast::<RawRelPtr<SignedScaled>>();
+        unsafe {
+            RawRelPtr::emplace(0, 40, out);
+            assert_eq!((*out).as_ptr().cast::<u8>(), buf.as_ptr().add(40));
+        }
+    }
+
+    #[cfg(all(debug_assertions, target_pointer_width = "64"))]
+    #[test]
+    #[should_panic]
+    fn scaled_offset_overflow() {
+        use rkyv::{
+            rel_ptr::{Offset, ScaledOffset},
+            Archived,
+        };
+
+        let offset = unsafe {
+            core::mem::transmute::<Archived<i64>, ScaledOffset<Archived<i64>, 2>>(
+                rkyv::to_archived!(i64::MAX),
+            )
+        };
+        offset.to_isize();
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "not aligned")]
+    fn slice_resolve_unsized_misaligned() {
+        use core::mem::MaybeUninit;
+        use rkyv::{ArchiveUnsized, Archived, RelPtr};
+
+        let items = [1u32, 2, 3, 4];
+        let mut out = MaybeUninit::<RelPtr<[Archived<u32>]>>::uninit();
+        unsafe {
+            items[..].resolve_unsized(0, 3, (), out.as_mut_ptr());
+        }
+    }
+
+    #[cfg(all(feature = "validation", target_pointer_width = "64"))]
+    #[test]
+    fn scaled_offset_check_overflow() {
+        use bytecheck::CheckBytes;
+        use rkyv::{
+            rel_ptr::{ScaledOffset, ScaledOffsetError},
+            validation::validators::DefaultValidator,
+            Archived,
+        };
+
+        type Scaled = ScaledOffset<Archived<i64>, 2>;
+
+        let buf = [0u8; 8];
+        let mut validator = DefaultValidator::new(&buf);
+        let scaled = |offset: i64| unsafe {
+            core::mem::transmute::<Archived<i64>, Scaled>(rkyv::to_archived!(offset))
+        };
+        unsafe {
+            Scaled::check_bytes(&scaled(i64::MAX / 2), &mut validator).unwrap();
+            assert!(matches!(
+                Scaled::check_bytes(&scaled(i64::MAX), &mut validator),
+                Err(ScaledOffsetError::Overflow { scale: 2, .. })
+            ));
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn custom_offset_primitive() {
+        use rkyv::rel_ptr::{Offset, OffsetError, OffsetPrimitive, RawRelPtr};
+
+        // A signed 40-bit little-endian integer
+        #[derive(Clone, Copy)]
+        struct I40([u8; 5]);
+
+        impl OffsetPrimitive for I40 {
+            const MIN_ISIZE: isize = -(1 << 39);
+            const MAX_ISIZE: isize = (1 << 39) - 1;
+
+            fn try_from_isize(value: isize) -> Option<Self> {
+                if (Self::MIN_ISIZE..=Self::MAX_ISIZE).contains(&value) {
+                    let [a, b, c, d, e, ..] = (value as i64).to_le_bytes();
+                    Some(Self([a, b, c, d, e]))
+                } else {
+                    None
+                }
+            }
+
+            fn into_isize(self) -> isize {
+                let [a, b, c, d, e] = self.0;
+                (i64::from_le_bytes([0, 0, 0, a, b, c, d, e]) >> 24) as isize
+            }
+        }
+
+        let max = (1usize << 39) - 1;
+        assert_eq!(I40::between(0, max).unwrap().to_isize(), max as isize);
+        assert_eq!(I40::between(max + 1, 0).unwrap().to_isize(), -(1 << 39));
+        assert_eq!(
+            I40::between(0, max + 1).err(),
+            Some(OffsetError::ExceedsStorageRange)
+        );
+        assert_eq!(
+            I40::between(max + 2, 0).err(),
+            Some(OffsetError::ExceedsStorageRange)
+        );
+        assert_eq!(I40::MIN_OFFSET, -(1 << 39));
+        assert_eq!(I40::MAX_OFFSET, (1 << 39) - 1);
+
+        let mut buf = [0u8; 16];
+        let out = buf.as_mut_ptr().cast::<RawRelPtr<I40>>();
+        unsafe {
+            RawRelPtr::emplace(8, 2, out);
+            assert_eq!((*out).offset(), -6);
+        }
+    }
 }
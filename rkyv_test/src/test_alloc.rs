@@ -16,6 +16,7 @@ mod tests {
         borrow::Cow,
         boxed::Box,
         collections::{BTreeMap, BTreeSet},
+        format,
         rc::{Rc, Weak},
         string::{String, ToString},
         vec,
@@ -97,6 +98,31 @@ mod tests {
         test_archive::<Result<(), _>>(&Err(Box::new(vec![1, 2, 3, 4])));
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_result_accessors() {
+        test_archive::<Result<Vec<u8>, String>>(&Ok(vec![1, 2, 3, 4]));
+        test_archive::<Result<Vec<u8>, String>>(&Err("hello world".to_string()));
+        test_archive::<Result<Result<Vec<u8>, String>, ()>>(&Ok(Err("nested".to_string())));
+
+        let value: Result<Vec<u8>, String> = Ok(vec![1, 2, 3, 4]);
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Result<Vec<u8>, String>>(buf.as_ref()) };
+        assert!(archived.is_ok());
+        assert!(!archived.is_err());
+        assert_eq!(archived.ok().unwrap(), &[1, 2, 3, 4]);
+        assert!(archived.err().is_none());
+        assert!(archived.as_ref().is_ok());
+
+        let value: Result<Vec<u8>, String> = Err("hello world".to_string());
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Result<Vec<u8>, String>>(buf.as_ref()) };
+        assert!(archived.is_err());
+        assert!(archived.ok().is_none());
+        assert_eq!(archived.err().unwrap(), "hello world");
+        assert_eq!(archived.as_ref().unwrap_err(), "hello world");
+    }
+
     #[cfg(all(feature = "std", feature = "validation"))]
     mod isolate {
         #[cfg(feature = "wasm")]
@@ -164,6 +190,75 @@ mod tests {
         test_archive(&vec![Test, Test]);
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(not(feature = "strict"))]
+    fn archive_16_tuple() {
+        type Tuple16 = (
+            u8,
+            i16,
+            u32,
+            i64,
+            f32,
+            f64,
+            bool,
+            char,
+            String,
+            Vec<u16>,
+            Option<u32>,
+            Box<i32>,
+            (),
+            [u8; 3],
+            Option<String>,
+            (String, u64),
+        );
+
+        let value: Tuple16 = (
+            1u8,
+            -2i16,
+            3u32,
+            -4i64,
+            5.5f32,
+            6.25f64,
+            true,
+            'x',
+            "nine".to_string(),
+            vec![10u16, 11],
+            Some(12u32),
+            Box::new(13i32),
+            (),
+            [14u8; 3],
+            Option::<String>::None,
+            ("sixteen".to_string(), 16u64),
+        );
+
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Tuple16>(buf.as_ref()) };
+        assert_eq!(archived.0, 1);
+        assert_eq!(archived.8, "nine");
+        assert_eq!(archived.9, [10, 11]);
+        assert_eq!(*archived.11, 13);
+        assert_eq!(archived.15 .0, "sixteen");
+        assert_eq!(archived.15 .1, 16);
+
+        let deserialized: Tuple16 = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized.0, value.0);
+        assert_eq!(deserialized.1, value.1);
+        assert_eq!(deserialized.2, value.2);
+        assert_eq!(deserialized.3, value.3);
+        assert_eq!(deserialized.4, value.4);
+        assert_eq!(deserialized.5, value.5);
+        assert_eq!(deserialized.6, value.6);
+        assert_eq!(deserialized.7, value.7);
+        assert_eq!(deserialized.8, value.8);
+        assert_eq!(deserialized.9, value.9);
+        assert_eq!(deserialized.10, value.10);
+        assert_eq!(deserialized.11, value.11);
+        assert_eq!(deserialized.13, value.13);
+        assert_eq!(deserialized.14, value.14);
+        assert_eq!(deserialized.15, value.15);
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn archive_tuple_struct() {
@@ -575,6 +670,348 @@ mod tests {
         assert_eq!(value.b[1], "WORLD");
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn multi_root_serializer() {
+        use rkyv::{archived_value, from_archived, ser::serializers::MultiRootSerializer};
+
+        #[derive(Archive, Serialize, Debug)]
+        struct Header {
+            version: u16,
+            name: String,
+        }
+
+        let header = Header {
+            version: 3,
+            name: "pack".to_string(),
+        };
+        let numbers = vec![1u32, 1, 2, 3, 5, 8];
+        let label = "third object".to_string();
+
+        let mut serializer = MultiRootSerializer::new(DefaultSerializer::default());
+        let header_pos = serializer.serialize_and_record(&header).unwrap();
+        let numbers_pos = serializer.serialize_and_record(&numbers).unwrap();
+        let label_pos = serializer.serialize_and_record(&label).unwrap();
+
+        let (mut serializer, manifest) = serializer.into_manifest();
+        assert_eq!(manifest, [header_pos, numbers_pos, label_pos]);
+        serializer.serialize_value(&manifest).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+
+        let manifest = unsafe { archived_root::<Vec<usize>>(buf.as_ref()) };
+        assert_eq!(manifest.len(), 3);
+        let pos = |i: usize| from_archived!(manifest[i]) as usize;
+
+        let archived_header = unsafe { archived_value::<Header>(buf.as_ref(), pos(0)) };
+        assert_eq!(archived_header.version, 3);
+        assert_eq!(archived_header.name, "pack");
+
+        let archived_numbers = unsafe { archived_value::<Vec<u32>>(buf.as_ref(), pos(1)) };
+        assert_eq!(archived_numbers.as_slice(), &[1, 1, 2, 3, 5, 8]);
+
+        let archived_label = unsafe { archived_value::<String>(buf.as_ref(), pos(2)) };
+        assert_eq!(archived_label, "third object");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn align_serializer_page_aligns_root() {
+        use rkyv::ser::serializers::AlignSerializer;
+
+        #[derive(Archive, Serialize)]
+        struct Test {
+            id: u32,
+            values: Vec<u8>,
+        }
+
+        for len in [0, 1, 7, 100, 4095, 4096, 10000] {
+            let value = Test {
+                id: len as u32,
+                values: (0..len).map(|i| i as u8).collect(),
+            };
+
+            let mut serializer = AlignSerializer::<_, 4096>::new(DefaultSerializer::default());
+            let pos = serializer.serialize_root(&value).unwrap();
+            assert_eq!(pos % 4096, 0);
+            assert_eq!(serializer.root_pos(), Some(pos));
+            let buf = serializer.into_inner().into_serializer().into_inner();
+
+            let archived = unsafe { archived_root::<Test>(buf.as_ref()) };
+            let offset_of = |ptr: *const u8| ptr as usize - buf.as_ptr() as usize;
+            assert_eq!(offset_of((archived as *const ArchivedTest).cast()), pos);
+            let values_end = offset_of(archived.values.as_ptr()) + len;
+            assert!(buf[values_end..pos].iter().all(|&b| b == 0));
+            assert_eq!(archived.id, len as u32);
+            assert_eq!(archived.values.as_slice(), value.values.as_slice());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn size_serializer_matches_archive_len() {
+        use rkyv::ser::serializers::{
+            AllocScratch, CompositeSerializer, SharedSerializeMap, SizeSerializer,
+        };
+
+        fn test_size<T>(value: &T)
+        where
+            T: Serialize<DefaultSerializer>
+                + Serialize<CompositeSerializer<SizeSerializer, AllocScratch, SharedSerializeMap>>,
+        {
+            let mut serializer = CompositeSerializer::new(
+                SizeSerializer::new(),
+                AllocScratch::default(),
+                SharedSerializeMap::default(),
+            );
+            let size_pos = serializer.serialize_value(value).unwrap();
+            let size = serializer.into_serializer().pos();
+
+            let mut serializer = DefaultSerializer::default();
+            let pos = serializer.serialize_value(value).unwrap();
+            let buf = serializer.into_serializer().into_inner();
+
+            assert_eq!(size_pos, pos);
+            assert_eq!(size, buf.len());
+        }
+
+        #[derive(Archive, Serialize)]
+        struct Child {
+            name: String,
+            shared: Rc<u16>,
+        }
+
+        #[derive(Archive, Serialize)]
+        struct Test {
+            id: u8,
+            name: String,
+            values: Vec<u64>,
+            child: Option<Box<Child>>,
+            shared: Rc<u16>,
+            alias: Rc<u16>,
+        }
+
+        let shared = Rc::new(10);
+        let value = Test {
+            id: 1,
+            name: "a name that is long enough to be stored out of line".to_string(),
+            values: vec![1, 2, 3],
+            child: Some(Box::new(Child {
+                name: "child".to_string(),
+                shared: shared.clone(),
+            })),
+            shared: shared.clone(),
+            alias: shared,
+        };
+
+        test_size(&1u8);
+        test_size(&42u64);
+        test_size(&"hello world".to_string());
+        test_size(&vec![1u8, 2, 3]);
+        test_size(&vec!["a".to_string(), "bc".to_string(), "def".to_string()]);
+        test_size(&BTreeMap::from([
+            (1u32, "one".to_string()),
+            (2, "two".to_string()),
+        ]));
+        test_size(&value);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn checksum_detects_bit_flips() {
+        use rkyv::{
+            checksum::{verify_checksum, ChecksumError, ChecksumTrailer},
+            ser::serializers::ChecksumSerializer,
+        };
+
+        #[derive(Archive, Serialize)]
+        struct Test {
+            id: u32,
+            name: String,
+            values: Vec<i16>,
+        }
+
+        let value = Test {
+            id: 7,
+            name: "checksummed".to_string(),
+            values: vec![1, -2, 3],
+        };
+
+        let mut serializer = ChecksumSerializer::new(DefaultSerializer::default());
+        serializer.serialize_value(&value).unwrap();
+        let mut buf = serializer.finish().unwrap().into_serializer().into_inner();
+
+        let archive = verify_checksum(&buf).unwrap();
+        assert_eq!(
+            archive.len(),
+            buf.len() - core::mem::size_of::<ChecksumTrailer>()
+        );
+        let archived = unsafe { archived_root::<Test>(archive) };
+        assert_eq!(archived.id, 7);
+        assert_eq!(archived.name, "checksummed");
+        assert_eq!(archived.values, [1, -2, 3]);
+
+        for i in 0..buf.len() * 8 {
+            buf[i / 8] ^= 1 << (i % 8);
+            assert!(matches!(
+                verify_checksum(&buf),
+                Err(ChecksumError::Mismatch { .. })
+            ));
+            buf[i / 8] ^= 1 << (i % 8);
+        }
+
+        assert!(verify_checksum(&buf[..buf.len() - 1]).is_err());
+        assert_eq!(
+            verify_checksum(&buf[..2]),
+            Err(ChecksumError::MissingTrailer { len: 2 })
+        );
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn vec_sort_unstable_by() {
+        let value = vec![5i32, -3, 12, 0, -8, 7, 7, 1];
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let mut buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root_mut::<Vec<i32>>(Pin::new(buf.as_mut())) };
+        archived.sort_unstable_by(|a, b| a.cmp(b));
+
+        let archived = unsafe { archived_root::<Vec<i32>>(buf.as_ref()) };
+        assert_eq!(archived.as_slice(), &[-8, -3, 0, 1, 5, 7, 7, 12]);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn vec_dedup_iter() {
+        let value = vec![1u32, 1, 2, 3, 3, 3, 5, 8, 8];
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Vec<u32>>(buf.as_ref()) };
+        let unique = archived.dedup_iter().copied().collect::<Vec<_>>();
+        assert_eq!(unique, [1, 2, 3, 5, 8]);
+
+        let value = vec![
+            "a".to_string(),
+            "a".to_string(),
+            "b".to_string(),
+            "a".to_string(),
+        ];
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Vec<String>>(buf.as_ref()) };
+        let runs = archived
+            .dedup_iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(runs, ["a", "b", "a"]);
+        // Each run yields a reference to its first element in the archive
+        assert!(core::ptr::eq(
+            archived.dedup_iter().nth(1).unwrap(),
+            &archived[2]
+        ));
+
+        let buf = rkyv::to_bytes::<_, 256>(&Vec::<u32>::new()).unwrap();
+        let archived = unsafe { archived_root::<Vec<u32>>(buf.as_ref()) };
+        assert_eq!(archived.dedup_iter().count(), 0);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn vec_try_deserialize_map() {
+        #[derive(Debug, PartialEq)]
+        enum Error {
+            Odd { index: usize, value: u32 },
+        }
+
+        let value = vec![2u32, 4, 7, 8, 9];
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Vec<u32>>(buf.as_ref()) };
+
+        let mut calls = 0;
+        let result = archived.try_deserialize_map(&mut Infallible, |item, deserializer| {
+            let index = calls;
+            calls += 1;
+            let value: u32 = item.deserialize(deserializer).unwrap();
+            if value & 1 == 1 {
+                Err(Error::Odd { index, value })
+            } else {
+                Ok(value / 2)
+            }
+        });
+        assert_eq!(result, Err(Error::Odd { index: 2, value: 7 }));
+        assert_eq!(calls, 3);
+
+        let scaled = archived
+            .try_deserialize_map(&mut Infallible, |item, _| Ok::<_, Error>(*item as u64 * 10))
+            .unwrap();
+        assert_eq!(scaled, [20, 40, 70, 80, 90]);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn vec_iter_pin_mut() {
+        let value = vec![1u32, 2, 3, 4];
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let mut buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root_mut::<Vec<u32>>(Pin::new(buf.as_mut())) };
+        let iter = archived.iter_pin_mut();
+        assert_eq!(iter.len(), 4);
+        for element in iter {
+            *element.get_mut() += 1;
+        }
+
+        let archived = unsafe { archived_root::<Vec<u32>>(buf.as_ref()) };
+        assert_eq!(archived.as_slice(), &[2, 3, 4, 5]);
+
+        // Elements that contain relative pointers can be mutated in place through the pin
+        let value = vec!["hello".to_string(), "world".to_string()];
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let mut buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root_mut::<Vec<String>>(Pin::new(buf.as_mut())) };
+        for element in archived.iter_pin_mut().rev() {
+            element.pin_mut_str().make_ascii_uppercase();
+        }
+
+        let archived = unsafe { archived_root::<Vec<String>>(buf.as_ref()) };
+        assert_eq!(archived[0], "HELLO");
+        assert_eq!(archived[1], "WORLD");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_leaf_conversions() {
+        #[derive(Archive, Serialize)]
+        struct Test {
+            name: String,
+            values: Vec<u32>,
+            tags: Vec<String>,
+            nested: Vec<Vec<u8>>,
+        }
+
+        let value = Test {
+            name: "a name that is long enough to be stored out of line".to_string(),
+            values: vec![1, 2, 3],
+            tags: vec!["a".to_string(), "bc".to_string()],
+            nested: vec![vec![], vec![4, 5]],
+        };
+
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Test>(buf.as_ref()) };
+
+        assert_eq!(String::from(&archived.name), value.name);
+        let name: String = (&archived.name).into();
+        assert_eq!(name, value.name);
+        assert_eq!(archived.name.to_string(), value.name);
+
+        assert_eq!(Vec::<u32>::from(&archived.values), value.values);
+        assert_eq!(Vec::<String>::from(&archived.tags), value.tags);
+        let nested: Vec<Vec<u8>> = (&archived.nested).into();
+        assert_eq!(nested, value.nested);
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn enum_mutable_ref() {
@@ -655,6 +1092,59 @@ mod tests {
         });
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn custom_bounds_phantom_recursive() {
+        use ::core::marker::PhantomData;
+        use rkyv::ser::ScratchSpace;
+
+        // A marker type that can't be archived
+        #[derive(Debug, PartialEq)]
+        struct Marker;
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        // The children are archived as a Vec, which needs scratch space to serialize. Without
+        // omitting the bounds on the children and supplying the serializer bounds manually, this
+        // type would fail to compile.
+        #[archive(bound(serialize = "__S: ScratchSpace + Serializer"))]
+        struct Tree<T, M> {
+            value: T,
+            #[omit_bounds]
+            children: Vec<Tree<T, M>>,
+            marker: PhantomData<M>,
+        }
+
+        let value = Tree::<i32, Marker> {
+            value: 1,
+            children: vec![
+                Tree {
+                    value: 2,
+                    children: Vec::new(),
+                    marker: PhantomData,
+                },
+                Tree {
+                    value: 3,
+                    children: vec![Tree {
+                        value: 4,
+                        children: Vec::new(),
+                        marker: PhantomData,
+                    }],
+                    marker: PhantomData,
+                },
+            ],
+            marker: PhantomData,
+        };
+
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Tree<i32, Marker>>(buf.as_ref()) };
+        assert_eq!(archived.value, 1);
+        assert_eq!(archived.children.len(), 2);
+        assert_eq!(archived.children[1].children[0].value, 4);
+
+        let deserialized: Tree<i32, Marker> = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, value);
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn complex_bounds() {
@@ -875,6 +1365,38 @@ mod tests {
         test_archive(&value);
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(feature = "rc_strong_count")]
+    fn archived_strong_count() {
+        #[derive(Archive, Serialize)]
+        struct Test {
+            shared: Vec<Rc<u32>>,
+            unique: Rc<u32>,
+            weak: Weak<u32>,
+        }
+
+        let shared = Rc::new(10);
+        let value = Test {
+            shared: vec![shared.clone(), shared.clone(), shared],
+            unique: Rc::new(20),
+            weak: Weak::new(),
+        };
+        let weak = Rc::downgrade(&value.shared[0]);
+        let value = Test { weak, ..value };
+
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Test>(buf.as_ref()) };
+
+        assert_eq!(archived.shared.len(), 3);
+        for rc in archived.shared.iter() {
+            assert_eq!(**rc, 10);
+            assert_eq!(rc.archived_strong_count(), 3);
+        }
+        assert_eq!(archived.unique.archived_strong_count(), 1);
+        assert_eq!(archived.weak.upgrade().unwrap().archived_strong_count(), 3);
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn archive_weak_ptr() {
@@ -1048,6 +1570,162 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn compare_archived_to_native() {
+        use core::cmp::Ordering;
+
+        #[derive(Archive, Serialize, Deserialize)]
+        #[archive(compare(PartialEq, PartialOrd))]
+        pub struct StructFoo {
+            a: i32,
+            b: String,
+        }
+
+        #[derive(Archive, Serialize, Deserialize)]
+        #[archive(compare(PartialEq, PartialOrd))]
+        pub struct TupleFoo(i32, String);
+
+        #[derive(Archive, Serialize, Deserialize)]
+        #[archive(compare(PartialEq, PartialOrd))]
+        pub enum EnumFoo {
+            A(i32),
+            B { value: String },
+        }
+
+        let value = StructFoo {
+            a: 10,
+            b: "hello".to_string(),
+        };
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<StructFoo>(buf.as_ref()) };
+        assert!(*archived == value);
+        assert!(value == *archived);
+
+        let greater = StructFoo {
+            a: 10,
+            b: "world".to_string(),
+        };
+        assert!(*archived != greater);
+        assert_eq!(archived.partial_cmp(&greater), Some(Ordering::Less));
+        assert_eq!(greater.partial_cmp(archived), Some(Ordering::Greater));
+        let less = StructFoo {
+            a: 9,
+            b: "zzz".to_string(),
+        };
+        assert_eq!(archived.partial_cmp(&less), Some(Ordering::Greater));
+        assert_eq!(less.partial_cmp(archived), Some(Ordering::Less));
+        assert_eq!(archived.partial_cmp(&value), Some(Ordering::Equal));
+
+        let value = TupleFoo(10, "hello".to_string());
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<TupleFoo>(buf.as_ref()) };
+        assert!(*archived == value);
+        assert!(*archived < TupleFoo(11, "".to_string()));
+        assert!(TupleFoo(11, "".to_string()) > *archived);
+        assert!(*archived > TupleFoo(10, "a".to_string()));
+
+        let value = EnumFoo::B {
+            value: "hello".to_string(),
+        };
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<EnumFoo>(buf.as_ref()) };
+        assert!(*archived == value);
+        assert!(*archived > EnumFoo::A(100));
+        assert!(EnumFoo::A(100) < *archived);
+        let greater = EnumFoo::B {
+            value: "world".to_string(),
+        };
+        assert!(*archived < greater);
+        assert!(greater > *archived);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_enum_accessors() {
+        #[derive(Archive, Serialize, Deserialize)]
+        #[archive(accessors)]
+        pub enum Example {
+            Unit,
+            Tuple(u32, String),
+            Struct { id: u32, name: String },
+            Newtype(Vec<u32>),
+            HTTPRequest(u32),
+        }
+
+        let value = Example::Struct {
+            id: 42,
+            name: "hello".to_string(),
+        };
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Example>(buf.as_ref()) };
+        let fields = archived.as_struct().unwrap();
+        assert_eq!(*fields.id, 42);
+        assert_eq!(fields.name, "hello");
+        assert!(archived.as_unit().is_none());
+        assert!(archived.as_tuple().is_none());
+        assert!(archived.as_newtype().is_none());
+
+        let value = Example::Tuple(7, "world".to_string());
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Example>(buf.as_ref()) };
+        let (a, b) = archived.as_tuple().unwrap();
+        assert_eq!(*a, 7);
+        assert_eq!(b, "world");
+        assert!(archived.as_struct().is_none());
+
+        let value = Example::Newtype(vec![1, 2, 3]);
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Example>(buf.as_ref()) };
+        assert_eq!(archived.as_newtype().unwrap().as_slice(), &[1, 2, 3]);
+
+        let value = Example::Unit;
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Example>(buf.as_ref()) };
+        assert_eq!(archived.as_unit(), Some(()));
+        assert!(archived.as_struct().is_none());
+
+        let value = Example::HTTPRequest(404);
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Example>(buf.as_ref()) };
+        assert_eq!(archived.as_http_request(), Some(&404));
+        assert!(archived.as_unit().is_none());
+
+        #[derive(Archive, Serialize, Deserialize)]
+        #[archive(accessors)]
+        pub enum Generic<T> {
+            None,
+            Some { value: T },
+        }
+
+        let value = Generic::Some { value: 10u32 };
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Generic<u32>>(buf.as_ref()) };
+        assert_eq!(*archived.as_some().unwrap().value, 10);
+        assert!(archived.as_none().is_none());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_box_get_pin_mut() {
+        use rkyv::{from_archived, to_archived, AlignedVec};
+
+        let bytes = rkyv::to_bytes::<_, 256>(&Box::new(10u32)).unwrap();
+
+        // Copy the archive into a separate writable buffer, like a memory map would provide
+        let mut buf = AlignedVec::new();
+        buf.extend_from_slice(bytes.as_ref());
+
+        {
+            let archived = unsafe { archived_root_mut::<Box<u32>>(Pin::new(buf.as_mut_slice())) };
+            *archived.get_pin_mut() = to_archived!(42u32);
+        }
+        assert_ne!(buf.as_slice(), bytes.as_slice());
+
+        let archived = unsafe { archived_root::<Box<u32>>(buf.as_ref()) };
+        assert_eq!(from_archived!(*archived.get()), 42);
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn default_type_parameters() {
@@ -1635,6 +2313,43 @@ mod tests {
         assert_eq!(archived.c, "hello world");
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_cow() {
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        struct Test<'a> {
+            a: Cow<'a, u32>,
+            b: Cow<'a, [u8]>,
+            c: Cow<'a, str>,
+        }
+
+        let borrowed = Test {
+            a: Cow::Borrowed(&100),
+            b: Cow::Borrowed(&[1, 2, 3, 4, 5, 6]),
+            c: Cow::Borrowed("hello world"),
+        };
+        let owned = Test {
+            a: Cow::Owned(100),
+            b: Cow::Owned(vec![1, 2, 3, 4, 5, 6]),
+            c: Cow::Owned("hello world".to_string()),
+        };
+
+        let borrowed_bytes = rkyv::to_bytes::<_, 256>(&borrowed).unwrap();
+        let owned_bytes = rkyv::to_bytes::<_, 256>(&owned).unwrap();
+        assert_eq!(borrowed_bytes.as_slice(), owned_bytes.as_slice());
+
+        let archived = unsafe { archived_root::<Test>(borrowed_bytes.as_ref()) };
+        assert_eq!(archived.a, 100);
+        assert_eq!(archived.b, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(archived.c, "hello world");
+
+        let deserialized: Test = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, borrowed);
+        assert!(matches!(deserialized.a, Cow::Owned(_)));
+        assert!(matches!(deserialized.b, Cow::Owned(_)));
+        assert!(matches!(deserialized.c, Cow::Owned(_)));
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn with_as_vec() {
@@ -1958,6 +2673,42 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_binary_heap() {
+        #[cfg(not(feature = "std"))]
+        use alloc::collections::BinaryHeap;
+        #[cfg(feature = "std")]
+        use std::collections::BinaryHeap;
+
+        let value = [15u32, 3, 42, 8, 23, 4, 16, 42, 1]
+            .iter()
+            .copied()
+            .collect::<BinaryHeap<_>>();
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<BinaryHeap<u32>>(buf.as_ref()) };
+
+        assert_eq!(archived.len(), value.len());
+        assert!(!archived.is_empty());
+        assert_eq!(archived.peek(), value.peek());
+        assert_eq!(archived.iter().count(), value.len());
+        assert_eq!(archived, &value);
+
+        let deserialized: BinaryHeap<u32> = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized.into_sorted_vec(), value.into_sorted_vec());
+
+        let empty = BinaryHeap::<u32>::new();
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&empty).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<BinaryHeap<u32>>(buf.as_ref()) };
+        assert!(archived.is_empty());
+        assert_eq!(archived.peek(), None);
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn archive_btree_map() {
@@ -2001,6 +2752,8 @@ mod tests {
             panic!("there should be no values in the archived empty btree");
         }
         assert!(archived.get_key_value("wrong!").is_none());
+        assert!(archived.first_key_value().is_none());
+        assert!(archived.last_key_value().is_none());
 
         let deserialized: BTreeMap<_, _> = archived.deserialize(&mut Infallible).unwrap();
         assert_eq!(value, deserialized);
@@ -2069,6 +2822,177 @@ mod tests {
         assert_eq!(value, deserialized);
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    // This test creates structures too big to fit in 16-bit offsets
+    #[cfg(not(feature = "size_16"))]
+    fn archive_btree_map_range() {
+        use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+        let mut value = BTreeMap::new();
+        for i in 0..5_000 {
+            value.insert(format!("{:05}", i * 2), i);
+        }
+
+        let mut serializer = AlignedSerializer::new(AlignedVec::new());
+        serializer.serialize_value(&value).unwrap();
+        let result = serializer.into_inner();
+        let archived = unsafe { archived_root::<BTreeMap<String, i32>>(result.as_slice()) };
+
+        let check = |start: Bound<&str>, end: Bound<&str>| {
+            let expected = value.range::<str, _>((start, end)).collect::<Vec<_>>();
+            let actual = archived.range::<str, _>((start, end)).collect::<Vec<_>>();
+            assert_eq!(expected.len(), actual.len());
+            for ((k, v), (ak, av)) in expected.iter().zip(actual.iter()) {
+                assert_eq!(k, ak);
+                assert_eq!(v, av);
+            }
+        };
+
+        // Unbounded start and end
+        check(Unbounded, Unbounded);
+        check(Unbounded, Excluded("00100"));
+        check(Unbounded, Included("00100"));
+        check(Included("09000"), Unbounded);
+        check(Excluded("09000"), Unbounded);
+
+        // Bounds on present and missing keys
+        for &(start, end) in &[("00100", "00200"), ("00101", "00199"), ("00000", "09998")] {
+            check(Included(start), Excluded(end));
+            check(Included(start), Included(end));
+            check(Excluded(start), Excluded(end));
+            check(Excluded(start), Included(end));
+        }
+
+        // Ranges spanning many leaf nodes
+        for i in (0..10_000).step_by(997) {
+            let start = format!("{:05}", i);
+            let end = format!("{:05}", i + 3_001);
+            check(Included(&start), Excluded(&end));
+            check(Excluded(&start), Included(&end));
+        }
+
+        // Empty ranges
+        assert!(archived
+            .range::<str, _>((Included("00100"), Excluded("00100")))
+            .next()
+            .is_none());
+        assert!(archived
+            .range::<str, _>((Excluded("00100"), Included("00100")))
+            .next()
+            .is_none());
+        assert!(archived
+            .range::<str, _>((Excluded("00100"), Excluded("00102")))
+            .next()
+            .is_none());
+        assert_eq!(
+            archived
+                .range::<str, _>((Included("00101"), Excluded("00103")))
+                .count(),
+            1
+        );
+
+        // Start bound after all keys
+        assert!(archived
+            .range::<str, _>((Included("99999"), Unbounded))
+            .next()
+            .is_none());
+        assert!(archived
+            .range::<str, _>((Excluded("09998"), Unbounded))
+            .next()
+            .is_none());
+
+        // Start bound after end bound
+        assert!(archived
+            .range::<str, _>((Included("00200"), Included("00100")))
+            .next()
+            .is_none());
+
+        // End bound before all keys
+        assert!(archived
+            .range::<str, _>((Unbounded, Excluded("00000")))
+            .next()
+            .is_none());
+
+        let empty = BTreeMap::<String, i32>::new();
+        let mut serializer = AlignedSerializer::new(AlignedVec::new());
+        serializer.serialize_value(&empty).unwrap();
+        let result = serializer.into_inner();
+        let archived = unsafe { archived_root::<BTreeMap<String, i32>>(result.as_slice()) };
+        assert!(archived
+            .range::<str, _>((Unbounded, Unbounded))
+            .next()
+            .is_none());
+        assert!(archived
+            .range::<str, _>((Included("a"), Excluded("b")))
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    // This test creates structures too big to fit in 16-bit offsets
+    #[cfg(not(feature = "size_16"))]
+    fn archive_btree_map_count_range() {
+        use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+        let mut value = BTreeMap::new();
+        for i in 0..5_000 {
+            value.insert(format!("{:05}", i * 2), i);
+        }
+
+        let mut serializer = AlignedSerializer::new(AlignedVec::new());
+        serializer.serialize_value(&value).unwrap();
+        let result = serializer.into_inner();
+        let archived = unsafe { archived_root::<BTreeMap<String, i32>>(result.as_slice()) };
+
+        let check = |start: Bound<&str>, end: Bound<&str>| {
+            let count = archived.count_range::<str, _>((start, end));
+            assert_eq!(count, archived.range::<str, _>((start, end)).count());
+            // BTreeMap::range panics if the bounds are equal and both excluded
+            if start != end || matches!(start, Included(_)) {
+                assert_eq!(count, value.range::<str, _>((start, end)).count());
+            }
+        };
+
+        check(Unbounded, Unbounded);
+        check(Unbounded, Excluded("00100"));
+        check(Included("09000"), Unbounded);
+        for i in (0..10_000).step_by(331) {
+            let start = format!("{:05}", i);
+            for &len in &[0, 1, 2, 3, 100, 3_001, 9_999] {
+                let end = format!("{:05}", i + len);
+                check(Included(&start), Excluded(&end));
+                check(Included(&start), Included(&end));
+                check(Excluded(&start), Excluded(&end));
+                check(Excluded(&start), Included(&end));
+            }
+        }
+
+        assert_eq!(archived.count_range::<str, _>(..), value.len());
+        assert_eq!(
+            archived.count_range::<str, _>((Included("00200"), Included("00100"))),
+            0
+        );
+        assert_eq!(
+            archived.count_range::<str, _>((Included("99999"), Unbounded)),
+            0
+        );
+
+        let empty = BTreeMap::<String, i32>::new();
+        let mut serializer = AlignedSerializer::new(AlignedVec::new());
+        serializer.serialize_value(&empty).unwrap();
+        let result = serializer.into_inner();
+        let archived = unsafe { archived_root::<BTreeMap<String, i32>>(result.as_slice()) };
+        assert_eq!(archived.count_range::<str, _>(..), 0);
+        assert_eq!(
+            archived.count_range::<str, _>((Included("a"), Excluded("b"))),
+            0
+        );
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn archive_zst_containers() {
@@ -2163,4 +3087,691 @@ mod tests {
         assert_eq!(tracker.max_allocations(), 1);
         assert_ne!(tracker.min_buffer_size(), 0);
     }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn bump_scratch() {
+        use core::alloc::Layout;
+        use rkyv::ser::{
+            serializers::{
+                AlignedSerializer, AllocScratchError, BumpScratch, CompositeSerializer,
+                CompositeSerializerError, LinearGrowth,
+            },
+            ScratchSpace,
+        };
+
+        let value = (0..100)
+            .map(|i| vec![i.to_string(); 10])
+            .collect::<Vec<_>>();
+
+        // Linear growth
+        let mut serializer = CompositeSerializer::new(
+            AlignedSerializer::new(AlignedVec::new()),
+            BumpScratch::new(LinearGrowth(64)),
+            Infallible,
+        );
+        serializer.serialize_value(&value).unwrap();
+        let (serializer, scratch, _) = serializer.into_components();
+        assert!(scratch.peak_bytes() > 0);
+        assert!(scratch.capacity() >= scratch.peak_bytes());
+        let buf = serializer.into_inner();
+        let archived = unsafe { archived_root::<Vec<Vec<String>>>(buf.as_ref()) };
+        assert_eq!(archived, &value);
+
+        // Custom growth
+        let mut calls = 0;
+        let mut serializer = CompositeSerializer::new(
+            AlignedSerializer::new(AlignedVec::new()),
+            BumpScratch::with_capacity(16, |capacity: usize, _| {
+                calls += 1;
+                capacity * 2
+            }),
+            Infallible,
+        );
+        serializer.serialize_value(&value).unwrap();
+        let (_, scratch, _) = serializer.into_components();
+        assert!(scratch.peak_bytes() > 16);
+        drop(scratch);
+        assert!(calls > 0);
+
+        // Hard limit
+        let mut serializer = CompositeSerializer::new(
+            AlignedSerializer::new(AlignedVec::new()),
+            BumpScratch::with_limit(16, LinearGrowth(16), 64),
+            Infallible,
+        );
+        match serializer.serialize_value(&value) {
+            Err(CompositeSerializerError::ScratchSpaceError(
+                AllocScratchError::ExceededLimit { .. },
+            )) => (),
+            result => panic!("expected scratch space limit error, got {:?}", result),
+        }
+
+        // Allocations are aligned, reuse chunks and must be popped in reverse order
+        let mut scratch = BumpScratch::with_capacity(32, LinearGrowth(32));
+        unsafe {
+            let a_layout = Layout::from_size_align(3, 1).unwrap();
+            let b_layout = Layout::from_size_align(32, 8).unwrap();
+            let a = scratch.push_scratch(a_layout).unwrap();
+            let b = scratch.push_scratch(b_layout).unwrap();
+            assert_eq!(b.as_ptr().cast::<u8>() as usize % 8, 0);
+            assert_eq!(scratch.capacity(), 64);
+            assert!(scratch.pop_scratch(a.cast(), a_layout).is_err());
+            scratch.pop_scratch(b.cast(), b_layout).unwrap();
+            scratch.pop_scratch(a.cast(), a_layout).unwrap();
+            assert!(scratch.pop_scratch(a.cast(), a_layout).is_err());
+
+            let b = scratch.push_scratch(b_layout).unwrap();
+            let c = scratch.push_scratch(b_layout).unwrap();
+            assert_eq!(scratch.capacity(), 64);
+            scratch.pop_scratch(c.cast(), b_layout).unwrap();
+            scratch.pop_scratch(b.cast(), b_layout).unwrap();
+        }
+        assert_eq!(scratch.peak_bytes(), 64);
+
+        // Zero-sized allocations don't allocate chunks, even if the growth policy returns zero
+        let mut scratch = BumpScratch::with_limit(0, LinearGrowth(0), 0);
+        unsafe {
+            let layout = Layout::from_size_align(0, 4).unwrap();
+            let a = scratch.push_scratch(layout).unwrap();
+            let b = scratch.push_scratch(layout).unwrap();
+            assert_eq!(a.as_ptr().cast::<u8>() as usize % 4, 0);
+            assert_eq!(scratch.capacity(), 0);
+            scratch.pop_scratch(b.cast(), layout).unwrap();
+            scratch.pop_scratch(a.cast(), layout).unwrap();
+            assert!(scratch.pop_scratch(a.cast(), layout).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_vec_binary_search() {
+        use rkyv::from_archived;
+
+        let value = (0..64u32).map(|i| i * 2).collect::<Vec<_>>();
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Vec<u32>>(buf.as_ref()) };
+
+        for (i, x) in value.iter().enumerate() {
+            assert_eq!(
+                archived.binary_search_by(|a| from_archived!(*a).cmp(x)),
+                Ok(i)
+            );
+            assert_eq!(
+                archived.binary_search_by(|a| from_archived!(*a).cmp(&(x + 1))),
+                Err(i + 1)
+            );
+            assert_eq!(
+                archived.binary_search_by_key(&(x / 2), |a| from_archived!(*a) / 2),
+                Ok(i)
+            );
+        }
+        assert_eq!(
+            archived.binary_search_by(|a| from_archived!(*a).cmp(&200)),
+            Err(64)
+        );
+
+        let buf = rkyv::to_bytes::<_, 256>(&Vec::<u32>::new()).unwrap();
+        let archived = unsafe { archived_root::<Vec<u32>>(buf.as_ref()) };
+        assert_eq!(
+            archived.binary_search_by(|a| from_archived!(*a).cmp(&0)),
+            Err(0)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_multiple_roots() {
+        use rkyv::archived_value;
+
+        let first = vec!["hello".to_string(), "world".to_string()];
+        let second = "a second root that is stored after the first".to_string();
+
+        let mut serializer = DefaultSerializer::default();
+        let first_pos = serializer.serialize_value(&first).unwrap();
+        let second_pos = serializer.serialize_value(&second).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+
+        let archived_first = unsafe { archived_value::<Vec<String>>(buf.as_ref(), first_pos) };
+        assert_eq!(archived_first, &first);
+        let archived_second = unsafe { archived_value::<String>(buf.as_ref(), second_pos) };
+        assert_eq!(archived_second, &second);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_vec_chunks_and_positions() {
+        use rkyv::from_archived;
+
+        let value = (0..10u32).collect::<Vec<_>>();
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Vec<u32>>(buf.as_ref()) };
+
+        let mut chunks = archived.chunks_exact(3);
+        for (i, chunk) in chunks.by_ref().enumerate() {
+            assert_eq!(chunk.as_ptr(), archived[i * 3..].as_ptr());
+            for (j, x) in chunk.iter().enumerate() {
+                assert_eq!(from_archived!(*x), (i * 3 + j) as u32);
+            }
+        }
+        assert_eq!(chunks.remainder().len(), 1);
+        assert_eq!(from_archived!(chunks.remainder()[0]), 9);
+
+        let base = archived.as_ptr() as usize;
+        for i in 0..archived.len() {
+            assert_eq!(
+                base + archived.position_of(i),
+                &archived[i] as *const _ as usize
+            );
+        }
+        let elements = archived.as_ptr() as usize - buf.as_ptr() as usize;
+        let pos = elements + archived.position_of(5);
+        assert_eq!(buf[pos..].as_ptr(), &archived[5] as *const _ as *const u8);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_vec_split_at_with_position() {
+        use rkyv::from_archived;
+
+        let value = (0..10u64).collect::<Vec<_>>();
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Vec<u64>>(buf.as_ref()) };
+        let pos = buf.len() - core::mem::size_of::<Archived<Vec<u64>>>();
+        let elements = archived.as_ptr() as usize - buf.as_ptr() as usize;
+
+        for mid in [0, 4, 10] {
+            let ((left, left_pos), (right, right_pos)) = archived.split_at_with_position(pos, mid);
+            assert_eq!(left.len(), mid);
+            assert_eq!(right.len(), 10 - mid);
+            assert_eq!(left_pos, elements);
+            assert_eq!(right_pos, elements + mid * 8);
+            assert_eq!(buf[left_pos..].as_ptr(), left.as_ptr().cast::<u8>());
+            assert_eq!(buf[right_pos..].as_ptr(), right.as_ptr().cast::<u8>());
+            for (i, x) in left.iter().chain(right.iter()).enumerate() {
+                assert_eq!(from_archived!(*x), i as u64);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_vec_deserialize_in_place() {
+        let mut target = Vec::<u32>::with_capacity(1024);
+        let mut bytes = Vec::<u8>::with_capacity(1024);
+        let target_ptr = target.as_ptr();
+        let bytes_ptr = bytes.as_ptr();
+
+        for len in [1000u32, 10, 1024] {
+            let value = (0..len).collect::<Vec<_>>();
+            let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+            let archived = unsafe { archived_root::<Vec<u32>>(buf.as_ref()) };
+            archived
+                .deserialize_in_place(&mut target, &mut Infallible)
+                .unwrap();
+            assert_eq!(target, value);
+            assert_eq!(target.as_ptr(), target_ptr);
+            assert_eq!(target.capacity(), 1024);
+
+            let value = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+            let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+            let archived = unsafe { archived_root::<Vec<u8>>(buf.as_ref()) };
+            archived.copy_in_place(&mut bytes);
+            assert_eq!(bytes, value);
+            assert_eq!(bytes.as_ptr(), bytes_ptr);
+            assert_eq!(bytes.capacity(), 1024);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn deserialize_into_reuses_capacity() {
+        use rkyv::DeserializeInto;
+
+        let mut vec = Vec::<u32>::with_capacity(64);
+        let mut string = String::with_capacity(64);
+        let vec_ptr = vec.as_ptr();
+        let string_ptr = string.as_ptr();
+
+        for i in 0..2u32 {
+            let value = (0..32 + i).collect::<Vec<_>>();
+            let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+            let archived = unsafe { archived_root::<Vec<u32>>(buf.as_ref()) };
+            archived
+                .deserialize_into(&mut vec, &mut Infallible)
+                .unwrap();
+            assert_eq!(vec, value);
+            assert_eq!(vec.capacity(), 64);
+            assert_eq!(vec.as_ptr(), vec_ptr);
+
+            let value = "hello world".repeat(i as usize + 1);
+            let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+            let archived = unsafe { archived_root::<String>(buf.as_ref()) };
+            archived
+                .deserialize_into(&mut string, &mut Infallible)
+                .unwrap();
+            assert_eq!(string, value);
+            assert_eq!(string.capacity(), 64);
+            assert_eq!(string.as_ptr(), string_ptr);
+        }
+
+        // Deserializing a larger value grows the target
+        let value = (0..128).collect::<Vec<u32>>();
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Vec<u32>>(buf.as_ref()) };
+        archived
+            .deserialize_into(&mut vec, &mut Infallible)
+            .unwrap();
+        assert_eq!(vec, value);
+        assert!(vec.capacity() >= 128);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_string_as_str_checked() {
+        for value in ["hello", "a string which is too long to be stored inline"].iter() {
+            let value = value.to_string();
+            let mut buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+            let archived = unsafe { archived_root::<String>(buf.as_ref()) };
+            assert_eq!(archived.as_str_checked(), Ok(value.as_str()));
+
+            // Corrupt the first byte of the string
+            let offset = archived.as_ptr() as usize - buf.as_ptr() as usize;
+            buf[offset] = 0xff;
+            let archived = unsafe { archived_root::<String>(buf.as_ref()) };
+            assert!(archived.as_str_checked().is_err());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_string_to_string_lossy() {
+        for value in ["hello", "a string which is too long to be stored inline"].iter() {
+            let value = value.to_string();
+            let mut buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+            let archived = unsafe { archived_root::<String>(buf.as_ref()) };
+            assert!(matches!(archived.to_string_lossy(), Cow::Borrowed(s) if s == value));
+
+            // Replace the second byte of the string with a lone continuation byte
+            let offset = archived.as_ptr() as usize - buf.as_ptr() as usize;
+            buf[offset + 1] = 0x80;
+            let archived = unsafe { archived_root::<String>(buf.as_ref()) };
+            let lossy = archived.to_string_lossy();
+            let mut expected = value.clone();
+            expected.replace_range(1..2, "\u{fffd}");
+            assert!(matches!(&lossy, Cow::Owned(_)));
+            assert_eq!(lossy, expected);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_attr_forwards_attributes() {
+        #[derive(Archive, Serialize)]
+        #[archive_attr(
+            derive(Debug, PartialEq, Eq, PartialOrd, Ord),
+            doc = "An archived `Entry`"
+        )]
+        struct Entry {
+            priority: u32,
+            name: String,
+        }
+
+        let value = vec![
+            Entry {
+                priority: 2,
+                name: "second".to_string(),
+            },
+            Entry {
+                priority: 1,
+                name: "first".to_string(),
+            },
+            Entry {
+                priority: 2,
+                name: "also second".to_string(),
+            },
+        ];
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Vec<Entry>>(buf.as_ref()) };
+
+        // The archived entries can be ordered with the forwarded derives
+        let sorted = archived.iter().collect::<BTreeSet<&ArchivedEntry>>();
+        let names = sorted.iter().map(|e| e.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["first", "also second", "second"]);
+        assert_eq!(archived.iter().max(), Some(&archived[0]));
+        assert_ne!(archived[0], archived[2]);
+        assert_eq!(
+            format!("{:?}", archived[1]),
+            "ArchivedEntry { priority: 1, name: \"first\" }"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_boxed_slice() {
+        use core::mem::size_of;
+        use rkyv::{boxed::ArchivedBox, FixedUsize, RelPtr};
+
+        // A boxed slice archives as a relative pointer and a length
+        assert_eq!(size_of::<Archived<Box<[u8]>>>(), size_of::<RelPtr<[u8]>>());
+        assert_eq!(
+            size_of::<RelPtr<[u8]>>(),
+            size_of::<RelPtr<u8>>() + size_of::<FixedUsize>()
+        );
+
+        for value in [
+            Vec::new().into_boxed_slice(),
+            vec![1u8].into_boxed_slice(),
+            (0..100u8).collect::<Box<[u8]>>(),
+        ] {
+            let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+            let archived: &ArchivedBox<[u8]> = unsafe { archived_root::<Box<[u8]>>(buf.as_ref()) };
+            assert_eq!(archived.get(), &*value);
+
+            let deserialized: Box<[u8]> = archived.deserialize(&mut Infallible).unwrap();
+            assert_eq!(deserialized.len(), value.len());
+            assert_eq!(deserialized, value);
+        }
+
+        let value =
+            vec!["a".to_string(), "boxed".to_string(), "slice".to_string()].into_boxed_slice();
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Box<[String]>>(buf.as_ref()) };
+        assert_eq!(archived.len(), 3);
+        let deserialized: Box<[String]> = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, value);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_vec_as_bytes() {
+        use core::hash::{BuildHasher, Hash, Hasher};
+
+        let value = vec![1u32, 0x01020304, u32::MAX, 0];
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Vec<u32>>(buf.as_ref()) };
+
+        #[cfg(not(any(feature = "archive_le", feature = "archive_be")))]
+        let to_bytes = u32::to_ne_bytes;
+        #[cfg(feature = "archive_le")]
+        let to_bytes = u32::to_le_bytes;
+        #[cfg(feature = "archive_be")]
+        let to_bytes = u32::to_be_bytes;
+        let expected = value.iter().flat_map(|v| to_bytes(*v)).collect::<Vec<u8>>();
+
+        let bytes = archived.as_bytes();
+        assert_eq!(bytes, expected.as_slice());
+        assert_eq!(bytes.as_ptr(), archived.as_ptr().cast::<u8>());
+
+        let state = ahash::RandomState::new();
+        let hash = |bytes: &[u8]| {
+            let mut hasher = state.build_hasher();
+            bytes.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(bytes), hash(&expected));
+
+        let buf = rkyv::to_bytes::<_, 256>(&b"raw bytes".to_vec()).unwrap();
+        let archived = unsafe { archived_root::<Vec<u8>>(buf.as_ref()) };
+        assert_eq!(archived.as_bytes(), b"raw bytes");
+
+        let buf = rkyv::to_bytes::<_, 256>(&Vec::<u64>::new()).unwrap();
+        let archived = unsafe { archived_root::<Vec<u64>>(buf.as_ref()) };
+        assert!(archived.as_bytes().is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    // This test creates structures too big to fit in 16-bit offsets
+    #[cfg(not(feature = "size_16"))]
+    fn archive_btree_map_first_last_key_value() {
+        for &len in &[1, 2, 10, 1_000] {
+            let value = (0..len)
+                .map(|i| (format!("{:04}", i), i))
+                .collect::<BTreeMap<_, _>>();
+
+            let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+            let archived = unsafe { archived_root::<BTreeMap<String, i32>>(buf.as_ref()) };
+
+            let (first_key, first_value) = value.first_key_value().unwrap();
+            let (archived_key, archived_value) = archived.first_key_value().unwrap();
+            assert_eq!(archived_key, first_key);
+            assert_eq!(archived_value, first_value);
+
+            let (last_key, last_value) = value.last_key_value().unwrap();
+            let (archived_key, archived_value) = archived.last_key_value().unwrap();
+            assert_eq!(archived_key, last_key);
+            assert_eq!(archived_value, last_value);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archived_vec_partition_point_by_key() {
+        use rkyv::from_archived;
+
+        #[derive(Archive, Serialize)]
+        struct Row {
+            key: u32,
+            value: u32,
+        }
+
+        let keys = [1, 3, 3, 3, 5, 8, 8, 13];
+        let value = keys
+            .iter()
+            .enumerate()
+            .map(|(i, &key)| Row {
+                key,
+                value: i as u32,
+            })
+            .collect::<Vec<_>>();
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Vec<Row>>(buf.as_ref()) };
+
+        for key in 0..15 {
+            let expected = keys.partition_point(|&k| k < key);
+            let index = archived.partition_point_by_key(&key, |row| from_archived!(row.key));
+            assert_eq!(index, expected);
+        }
+
+        // The first of several duplicate keys is found
+        let start = archived.partition_point_by_key(&3, |row| from_archived!(row.key));
+        assert_eq!(start, 1);
+        assert_eq!(archived[start].value, 1);
+        let start = archived.partition_point_by_key(&8, |row| from_archived!(row.key));
+        assert_eq!(start, 5);
+        assert_eq!(archived[start].value, 5);
+
+        // Keys past the end return the length
+        assert_eq!(
+            archived.partition_point_by_key(&100, |row| from_archived!(row.key)),
+            archived.len()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_delta_encoded() {
+        use rkyv::{vec::ArchivedDeltaVec, with::DeltaEncoded};
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        struct Timeline {
+            #[with(DeltaEncoded)]
+            timestamps: Vec<u64>,
+        }
+
+        // Spans several checkpoint blocks, with a partial block at the end
+        let mut timestamp = 1_650_000_000_000u64;
+        let timestamps = (0..3 * ArchivedDeltaVec::BLOCK_SIZE as u64 + 7)
+            .map(|i| {
+                timestamp += (i * 7919) % 1000;
+                timestamp
+            })
+            .collect::<Vec<_>>();
+        let value = Timeline { timestamps };
+
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Timeline>(buf.as_ref()) };
+
+        assert_eq!(archived.timestamps.len(), value.timestamps.len());
+        assert!(!archived.timestamps.is_empty());
+        for (i, &timestamp) in value.timestamps.iter().enumerate() {
+            assert_eq!(archived.timestamps.get(i), Some(timestamp));
+        }
+        assert_eq!(archived.timestamps.get(value.timestamps.len()), None);
+        assert!(archived
+            .timestamps
+            .iter()
+            .eq(value.timestamps.iter().copied()));
+        assert_eq!(&archived.timestamps, value.timestamps.as_slice());
+
+        let deserialized: Timeline = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, value);
+
+        // Small deltas take much less space than the full values
+        assert!(buf.len() < value.timestamps.len() * 4);
+
+        let empty = Timeline {
+            timestamps: Vec::new(),
+        };
+        let buf = rkyv::to_bytes::<_, 256>(&empty).unwrap();
+        let archived = unsafe { archived_root::<Timeline>(buf.as_ref()) };
+        assert!(archived.timestamps.is_empty());
+        assert_eq!(archived.timestamps.get(0), None);
+        assert_eq!(archived.timestamps.iter().next(), None);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_delta_encoded_non_monotonic() {
+        use rkyv::with::DeltaEncoded;
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        struct Timeline {
+            #[with(DeltaEncoded)]
+            timestamps: Vec<u64>,
+        }
+
+        let mut timestamps = vec![100, 90, 95, 0, u64::MAX, 1, u64::MAX - 1, 1 << 63, 42];
+        timestamps.extend((0..100).map(|i| if i % 2 == 0 { 1000 + i } else { 1000 - i }));
+        let value = Timeline { timestamps };
+
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Timeline>(buf.as_ref()) };
+
+        for (i, &timestamp) in value.timestamps.iter().enumerate() {
+            assert_eq!(archived.timestamps.get(i), Some(timestamp));
+        }
+        assert!(archived
+            .timestamps
+            .iter()
+            .eq(value.timestamps.iter().copied()));
+
+        let deserialized: Timeline = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, value);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(feature = "absolute_pointers")]
+    fn rebase_absolute_root_pointer() {
+        use core::mem::{align_of, size_of};
+        use rkyv::rel_ptr::{rebase, AbsoluteRelPtr};
+
+        #[derive(Archive, Serialize)]
+        struct Target {
+            name: String,
+            values: Vec<u32>,
+        }
+
+        let value = Target {
+            name: "a string long enough to be stored out of line".to_string(),
+            values: vec![1, 2, 3, 4],
+        };
+        let mut old = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let target_pos = old.len() - size_of::<ArchivedTarget>();
+
+        // Append an absolute root pointer to the archived target
+        let ptr_pos = (old.len() + align_of::<AbsoluteRelPtr<ArchivedTarget>>() - 1)
+            & !(align_of::<AbsoluteRelPtr<ArchivedTarget>>() - 1);
+        old.resize(ptr_pos + size_of::<AbsoluteRelPtr<ArchivedTarget>>(), 0);
+        unsafe {
+            let target = old.as_ptr().add(target_pos).cast::<ArchivedTarget>();
+            let out = old
+                .as_mut_ptr()
+                .add(ptr_pos)
+                .cast::<AbsoluteRelPtr<ArchivedTarget>>();
+            AbsoluteRelPtr::emplace_absolute(target, (), out);
+        }
+
+        let mut new = AlignedVec::new();
+        new.extend_from_slice(&old);
+        let (old_base, new_base) = (old.as_ptr() as usize, new.as_ptr() as usize);
+        rebase(&mut new, old_base, new_base, &[ptr_pos]);
+        drop(old);
+
+        let ptr = unsafe {
+            &*new
+                .as_ptr()
+                .add(ptr_pos)
+                .cast::<AbsoluteRelPtr<ArchivedTarget>>()
+        };
+        assert!(ptr.is_absolute());
+        assert_eq!(ptr.as_ptr() as usize, new_base + target_pos);
+        let archived = unsafe { &*ptr.as_ptr() };
+        assert_eq!(archived.name, value.name);
+        assert_eq!(archived.values, value.values);
+
+        // Archives with only relative pointers are unchanged by rebasing
+        let bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let mut copy = AlignedVec::new();
+        copy.extend_from_slice(&bytes);
+        let (old_base, new_base) = (bytes.as_ptr() as usize, copy.as_ptr() as usize);
+        rebase(&mut copy, old_base, new_base, &[]);
+        assert_eq!(copy.as_slice(), bytes.as_slice());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn string_interning_serializer() {
+        use rkyv::ser::serializers::StringInterningSerializer;
+
+        const LABELS: [&str; 3] = [
+            "category: household appliances",
+            "category: garden and outdoor",
+            "category: sporting goods",
+        ];
+
+        let value = (0..300)
+            .map(|i| LABELS[i % LABELS.len()].to_string())
+            .collect::<Vec<_>>();
+
+        let plain = rkyv::to_bytes::<_, 256>(&value).unwrap();
+
+        let mut serializer = StringInterningSerializer::new(DefaultSerializer::default());
+        serializer.serialize_value(&value).unwrap();
+        assert_eq!(serializer.interned_len(), LABELS.len());
+        let interned = serializer.into_inner().into_serializer().into_inner();
+
+        // Only one copy of each label's bytes is written, up to alignment padding
+        let label_bytes = LABELS.iter().map(|l| l.len()).sum::<usize>();
+        let all_bytes = value.iter().map(|s| s.len()).sum::<usize>();
+        let padding = core::mem::align_of::<rkyv::string::ArchivedString>();
+        assert!(interned.len() <= plain.len() - (all_bytes - label_bytes) + padding);
+        for label in LABELS.iter() {
+            let count = interned
+                .windows(label.len())
+                .filter(|w| *w == label.as_bytes())
+                .count();
+            assert_eq!(count, 1);
+        }
+
+        let archived = unsafe { archived_root::<Vec<String>>(interned.as_ref()) };
+        assert_eq!(archived.len(), value.len());
+        for (i, (a, v)) in archived.iter().zip(value.iter()).enumerate() {
+            assert_eq!(a, v);
+            let first = &archived[i % LABELS.len()];
+            assert_eq!(a.as_ptr(), first.as_ptr());
+        }
+    }
 }
@@ -4,7 +4,7 @@ mod tests {
     use rkyv::{
         archived_root,
         ser::{serializers::WriteSerializer, Serializer},
-        AlignedBytes, Archive, Deserialize, Serialize,
+        AlignedBytes, Archive, Deserialize, Infallible, Serialize,
     };
     use std::collections::{HashMap, HashSet};
 
@@ -27,6 +27,417 @@ mod tests {
             .expect_err("serialized to an undersized buffer must fail");
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(not(feature = "strict"))]
+    fn write_serializer_streaming() {
+        use rkyv::{
+            ser::serializers::{AllocScratch, CompositeSerializer},
+            AlignedVec, Infallible,
+        };
+        use std::io;
+
+        // A writer that only accepts bytes that are written in order
+        struct AppendOnly {
+            bytes: Vec<u8>,
+            writes: usize,
+        }
+
+        impl io::Write for AppendOnly {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.bytes.extend_from_slice(buf);
+                self.writes += 1;
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let value = (0..100)
+            .map(|i| (i, format!("value number {}", i)))
+            .collect::<Vec<_>>();
+
+        let mut serializer = CompositeSerializer::new(
+            WriteSerializer::new(AppendOnly {
+                bytes: Vec::new(),
+                writes: 0,
+            }),
+            AllocScratch::default(),
+            Infallible,
+        );
+        serializer.serialize_value(&value).unwrap();
+        let pos = serializer.pos();
+        let writer = serializer.into_components().0.into_inner();
+        assert_eq!(writer.bytes.len(), pos);
+        assert!(writer.writes > 1);
+
+        let mut buf = AlignedVec::new();
+        buf.extend_from_slice(&writer.bytes);
+        let archived = unsafe { archived_root::<Vec<(i32, String)>>(buf.as_ref()) };
+        assert_eq!(archived.len(), value.len());
+        for ((i, s), archived) in value.iter().zip(archived.iter()) {
+            assert_eq!(archived.0, *i);
+            assert_eq!(archived.1, *s);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn content_dedup_registry() {
+        use rkyv::{
+            ser::serializers::{
+                AlignedSerializer, AllocScratch, CompositeSerializer, ContentDedupRegistry,
+            },
+            AlignedVec,
+        };
+        use std::sync::Arc;
+
+        const CONTENTS: &str = "a shared string that is too long to be inlined";
+
+        fn count(bytes: &[u8], needle: &[u8]) -> usize {
+            bytes.windows(needle.len()).filter(|w| *w == needle).count()
+        }
+
+        let value = vec![
+            Arc::new(CONTENTS.to_string()),
+            Arc::new(CONTENTS.to_string()),
+            Arc::new("something else entirely".to_string()),
+        ];
+        assert!(!Arc::ptr_eq(&value[0], &value[1]));
+
+        let bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        assert_eq!(count(&bytes, CONTENTS.as_bytes()), 2);
+
+        let mut serializer = CompositeSerializer::new(
+            AlignedSerializer::new(AlignedVec::new()),
+            AllocScratch::default(),
+            ContentDedupRegistry::new(),
+        );
+        serializer.serialize_value(&value).unwrap();
+        let bytes = serializer.into_serializer().into_inner();
+        assert_eq!(count(&bytes, CONTENTS.as_bytes()), 1);
+
+        let archived = unsafe { archived_root::<Vec<Arc<String>>>(bytes.as_ref()) };
+        assert_eq!(archived.len(), 3);
+        assert_eq!(archived[0].as_str(), CONTENTS);
+        assert_eq!(archived[1].as_str(), CONTENTS);
+        assert_eq!(archived[2].as_str(), "something else entirely");
+        assert_eq!(&*archived[0] as *const _, &*archived[1] as *const _);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn shared_deserialize_registry_insert_shared() {
+        use rkyv::de::{deserializers::SharedDeserializeMap, SharedDeserializeRegistry};
+        use std::{any::Any, sync::Arc};
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        struct Test {
+            name: Arc<String>,
+            id: u32,
+        }
+
+        let first = Test {
+            name: Arc::new("interned".to_string()),
+            id: 1,
+        };
+        let second = Test {
+            name: Arc::new("interned".to_string()),
+            id: 2,
+        };
+        let first_buf = rkyv::to_bytes::<_, 256>(&first).unwrap();
+        let second_buf = rkyv::to_bytes::<_, 256>(&second).unwrap();
+        let archived_first = unsafe { archived_root::<Test>(first_buf.as_ref()) };
+        let archived_second = unsafe { archived_root::<Test>(second_buf.as_ref()) };
+
+        let mut deserializer = SharedDeserializeMap::new();
+        let deserialized_first: Test = archived_first.deserialize(&mut deserializer).unwrap();
+        assert_eq!(deserialized_first, first);
+
+        let interned = deserialized_first.name.clone() as Arc<dyn Any>;
+        let ptr = archived_second.name.get() as *const _ as *const u8;
+        unsafe {
+            deserializer.insert_shared(ptr, interned).unwrap();
+        }
+        let deserialized_second: Test = archived_second.deserialize(&mut deserializer).unwrap();
+        assert_eq!(deserialized_second, second);
+        assert!(Arc::ptr_eq(
+            &deserialized_first.name,
+            &deserialized_second.name
+        ));
+        assert!(!Arc::ptr_eq(&deserialized_first.name, &first.name));
+
+        // The seeded pointer can't be registered twice
+        let duplicate = Arc::new(String::new()) as Arc<dyn Any>;
+        assert!(unsafe { deserializer.insert_shared(ptr, duplicate) }.is_err());
+
+        drop(deserializer);
+        assert_eq!(Arc::strong_count(&deserialized_first.name), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "disk_scratch")]
+    fn disk_scratch_spills_and_restores() {
+        use core::alloc::Layout;
+        use rkyv::{
+            ser::{
+                serializers::{AlignedSerializer, CompositeSerializer, DiskScratch},
+                ScratchSpace,
+            },
+            AlignedVec, Infallible,
+        };
+
+        // Allocations are filled with a pattern and checked after many more have been spilled
+        let mut scratch = DiskScratch::new(64);
+        let layouts = [
+            (24, 8),
+            (100, 4),
+            (3, 1),
+            (5000, 16),
+            (0, 1),
+            (64, 64),
+            (40, 8),
+        ]
+        .iter()
+        .map(|&(size, align)| Layout::from_size_align(size, align).unwrap())
+        .collect::<Vec<_>>();
+        let mut allocations = Vec::new();
+        for (i, layout) in layouts.iter().enumerate() {
+            let mut ptr = unsafe { scratch.push_scratch(*layout).unwrap() };
+            let bytes = unsafe { ptr.as_mut() };
+            assert_eq!(bytes.len(), layout.size());
+            assert_eq!(bytes.as_ptr() as usize % layout.align(), 0);
+            bytes.iter_mut().for_each(|b| *b = i as u8);
+            allocations.push(ptr);
+        }
+        assert!(scratch.spills() >= 4);
+
+        let popped = allocations[0].cast();
+        assert!(unsafe { scratch.pop_scratch(popped, layouts[0]) }.is_err());
+
+        for (i, (ptr, layout)) in allocations.iter().zip(layouts.iter()).enumerate().rev() {
+            let bytes = unsafe { ptr.as_ref() };
+            assert!(bytes.iter().all(|&b| b == i as u8));
+            unsafe { scratch.pop_scratch(ptr.cast(), *layout).unwrap() };
+        }
+        assert!(unsafe { scratch.pop_scratch(popped, layouts[0]) }.is_err());
+
+        // Hash maps use several scratch allocations at the same time
+        let value = (0..200)
+            .map(|i| {
+                let words = (0..i % 7).map(|j| format!("{}-{}", i, j)).collect();
+                (format!("key {}", i), words)
+            })
+            .collect::<HashMap<String, Vec<String>>>();
+
+        let mut serializer = CompositeSerializer::new(
+            AlignedSerializer::new(AlignedVec::new()),
+            DiskScratch::new(32),
+            Infallible,
+        );
+        serializer.serialize_value(&value).unwrap();
+        let (serializer, scratch, _) = serializer.into_components();
+        assert!(scratch.spills() > 0);
+        let buf = serializer.into_inner();
+
+        let archived = unsafe { archived_root::<HashMap<String, Vec<String>>>(buf.as_ref()) };
+        assert_eq!(archived.len(), value.len());
+        for (key, words) in value.iter() {
+            let archived_words = archived.get(key.as_str()).unwrap();
+            assert_eq!(archived_words.len(), words.len());
+            for (a, b) in archived_words.iter().zip(words.iter()) {
+                assert_eq!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    // Threads aren't available in wasm tests
+    fn bounded_deserializer() {
+        use rkyv::{
+            de::deserializers::{BoundedDeserializer, BoundedDeserializerError},
+            Infallible,
+        };
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        // The derive macros don't apply the right bounds from Box so we have to manually specify
+        // what bounds to apply
+        #[archive(bound(serialize = "__S: Serializer"))]
+        enum Node {
+            Nil,
+            Cons(#[omit_bounds] Box<Node>),
+        }
+
+        // Serializing and dropping a deeply nested value are both recursive, so give them plenty
+        // of stack
+        let bytes = std::thread::Builder::new()
+            .stack_size(256 * 1024 * 1024)
+            .spawn(|| {
+                let mut value = Node::Nil;
+                for _ in 0..10_000 {
+                    value = Node::Cons(Box::new(value));
+                }
+                rkyv::to_bytes::<_, 256>(&value).unwrap()
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        let archived = unsafe { archived_root::<Node>(bytes.as_ref()) };
+
+        let mut deserializer = BoundedDeserializer::new(Infallible, 100);
+        let result: Result<Node, _> = archived.deserialize(&mut deserializer);
+        assert!(matches!(
+            result,
+            Err(BoundedDeserializerError::ExceededMaximumDepth { max_depth: 100 })
+        ));
+        assert_eq!(deserializer.depth(), 0);
+
+        let mut value = Node::Nil;
+        for _ in 0..50 {
+            value = Node::Cons(Box::new(value));
+        }
+        let bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<Node>(bytes.as_ref()) };
+        let mut deserializer = BoundedDeserializer::new(Infallible, 100);
+        let deserialized: Node = archived.deserialize(&mut deserializer).unwrap();
+        assert_eq!(deserialized, value);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_hash_map_with_hashes() {
+        use core::hash::{Hash, Hasher};
+        use rkyv::from_archived;
+
+        let mut value = HashMap::new();
+        for i in 0..32u32 {
+            value.insert(format!("key {}", i), i);
+        }
+
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<HashMap<String, u32>>(buf.as_ref()) };
+
+        let (key, val) = archived.get_key_value("key 7").unwrap();
+        assert_eq!(key, "key 7");
+        assert_eq!(from_archived!(*val), 7);
+        assert!(archived.get_key_value("missing").is_none());
+
+        let mut count = 0;
+        for (hash, key, val) in archived.iter_with_hashes() {
+            let mut hasher = archived.hasher();
+            key.as_str().hash(&mut hasher);
+            assert_eq!(hash, hasher.finish());
+            assert_eq!(value[key.as_str()], from_archived!(*val));
+            count += 1;
+        }
+        assert_eq!(count, value.len());
+        assert_eq!(archived.iter_with_hashes().len(), value.len());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_hash_map_contains_key() {
+        let mut value = HashMap::new();
+        for i in (0..200u32).step_by(2) {
+            value.insert(i, format!("value {}", i));
+        }
+
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<HashMap<u32, String>>(buf.as_ref()) };
+
+        for i in 0..200u32 {
+            assert_eq!(archived.contains_key(&i), archived.get(&i).is_some());
+            assert_eq!(archived.contains_key(&i), i % 2 == 0);
+        }
+        assert!(!archived.contains_key(&u32::MAX));
+
+        let empty = rkyv::to_bytes::<_, 256>(&HashMap::<u32, String>::new()).unwrap();
+        let archived = unsafe { archived_root::<HashMap<u32, String>>(empty.as_ref()) };
+        assert!(!archived.contains_key(&0));
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_hash_map_get_with_hash() {
+        use rkyv::from_archived;
+
+        let mut value = HashMap::new();
+        for i in 0..100u32 {
+            value.insert(format!("a long key that is expensive to hash {}", i), i);
+        }
+
+        let buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<HashMap<String, u32>>(buf.as_ref()) };
+
+        let mut mismatched = 0;
+        for (key, v) in value.iter() {
+            let hash = archived.hash(key.as_str());
+            let found = archived.get_with_hash(hash, key.as_str()).unwrap();
+            assert_eq!(from_archived!(*found), *v);
+            let (found_key, _) = archived
+                .get_key_value_with_hash(hash, key.as_str())
+                .unwrap();
+            assert_eq!(found_key, key);
+
+            // A mismatched hash never finds the wrong entry, but may not find the key at all
+            match archived.get_with_hash(hash.wrapping_add(1), key.as_str()) {
+                Some(found) => assert_eq!(from_archived!(*found), *v),
+                None => mismatched += 1,
+            }
+        }
+        assert!(mismatched > 0);
+
+        let missing = "a key that is not in the map";
+        assert!(archived
+            .get_with_hash(archived.hash(missing), missing)
+            .is_none());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_hash_map_keys_and_values() {
+        use core::pin::Pin;
+        use rkyv::{archived_root_mut, from_archived, to_archived};
+
+        let mut value = HashMap::new();
+        for i in 0..20u32 {
+            value.insert(format!("key {}", i), i);
+        }
+
+        let mut buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = unsafe { archived_root::<HashMap<String, u32>>(buf.as_ref()) };
+
+        assert_eq!(archived.keys().len(), archived.len());
+        assert_eq!(archived.keys().count(), archived.len());
+        assert_eq!(archived.values().len(), archived.len());
+        assert_eq!(archived.values().count(), archived.len());
+
+        let mut keys = archived.keys().map(|k| k.as_str()).collect::<Vec<_>>();
+        keys.sort_unstable();
+        let mut expected_keys = value.keys().map(|k| k.as_str()).collect::<Vec<_>>();
+        expected_keys.sort_unstable();
+        assert_eq!(keys, expected_keys);
+
+        let sum = archived.values().fold(0, |sum, v| sum + from_archived!(*v));
+        assert_eq!(sum, value.values().sum::<u32>());
+
+        let mut archived =
+            unsafe { archived_root_mut::<HashMap<String, u32>>(Pin::new(buf.as_mut_slice())) };
+        assert_eq!(archived.as_mut().values_pin().len(), value.len());
+        let mut count = 0;
+        for mut v in archived.as_mut().values_pin() {
+            *v = to_archived!(from_archived!(*v) * 2);
+            count += 1;
+        }
+        assert_eq!(count, value.len());
+
+        for (key, v) in value.iter() {
+            assert_eq!(from_archived!(archived[key.as_str()]), v * 2);
+        }
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn archive_hash_map() {
@@ -66,6 +477,33 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn hash_map_deserialize_into() {
+        use rkyv::{DeserializeInto, Infallible};
+
+        let mut target = HashMap::<String, String>::with_capacity(16);
+        target.insert("stale".to_string(), "entry".to_string());
+        let capacity = target.capacity();
+
+        for i in 0..2 {
+            let mut hash_map = HashMap::new();
+            hash_map.insert("hello".to_string(), "world".to_string());
+            hash_map.insert("foo".to_string(), i.to_string());
+
+            let mut serializer = DefaultSerializer::default();
+            serializer.serialize_value(&hash_map).unwrap();
+            let buf = serializer.into_serializer().into_inner();
+            let archived_value = unsafe { archived_root::<HashMap<String, String>>(buf.as_ref()) };
+
+            archived_value
+                .deserialize_into(&mut target, &mut Infallible)
+                .unwrap();
+            assert_eq!(target, hash_map);
+            assert_eq!(target.capacity(), capacity);
+        }
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     #[allow(deprecated)]
@@ -143,6 +581,79 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_hash_set_round_trip() {
+        let hash_set = ["hello", "foo", "baz"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<HashSet<_>>();
+
+        let buf = rkyv::to_bytes::<_, 256>(&hash_set).unwrap();
+        let archived_value = unsafe { archived_root::<HashSet<String>>(buf.as_ref()) };
+
+        assert!(!archived_value.is_empty());
+        assert!(archived_value.contains("hello"));
+        assert!(archived_value.contains("baz"));
+        assert!(!archived_value.contains("bar"));
+        assert!(!archived_value.contains(""));
+
+        let deserialized: HashSet<String> = archived_value.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, hash_set);
+
+        // The archived entries don't store the unit values
+        use rkyv::{collections::util::Entry, string::ArchivedString};
+        assert_eq!(
+            core::mem::size_of::<Entry<ArchivedString, ()>>(),
+            core::mem::size_of::<ArchivedString>()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_hash_set_relationships() {
+        fn strings(values: &[&str]) -> HashSet<String> {
+            values.iter().map(|s| s.to_string()).collect()
+        }
+
+        let small = strings(&["a", "b"]);
+        let large = strings(&["a", "b", "c", "d"]);
+        let other = strings(&["x", "y", "z"]);
+
+        let small_buf = rkyv::to_bytes::<_, 256>(&small).unwrap();
+        let large_buf = rkyv::to_bytes::<_, 256>(&large).unwrap();
+        let other_buf = rkyv::to_bytes::<_, 256>(&other).unwrap();
+        let archived_small = unsafe { archived_root::<HashSet<String>>(small_buf.as_ref()) };
+        let archived_large = unsafe { archived_root::<HashSet<String>>(large_buf.as_ref()) };
+        let archived_other = unsafe { archived_root::<HashSet<String>>(other_buf.as_ref()) };
+
+        // Against other archived sets
+        assert!(archived_small.is_subset(archived_large));
+        assert!(archived_small.is_subset(archived_small));
+        assert!(!archived_large.is_subset(archived_small));
+        assert!(!archived_small.is_subset(archived_other));
+        assert!(archived_small.is_disjoint(archived_other));
+        assert!(archived_other.is_disjoint(archived_large));
+        assert!(!archived_large.is_disjoint(archived_small));
+
+        // Against native sets
+        assert!(archived_small.is_subset_of_hash_set::<str, _, _>(&large));
+        assert!(!archived_large.is_subset_of_hash_set::<str, _, _>(&small));
+        assert!(!archived_small.is_subset_of_hash_set::<str, _, _>(&strings(&["a", "c"])));
+        assert!(archived_small.is_disjoint_from_hash_set::<str, _, _>(&other));
+        assert!(archived_large.is_disjoint_from_hash_set::<str, _, _>(&strings(&["e"])));
+        assert!(!archived_small.is_disjoint_from_hash_set::<str, _, _>(&large));
+        assert!(!archived_large.is_disjoint_from_hash_set::<str, _, _>(&strings(&["d"])));
+
+        // The empty set is a subset of and disjoint from every set
+        let empty_buf = rkyv::to_bytes::<_, 256>(&HashSet::<String>::new()).unwrap();
+        let archived_empty = unsafe { archived_root::<HashSet<String>>(empty_buf.as_ref()) };
+        assert!(archived_empty.is_subset(archived_small));
+        assert!(archived_empty.is_disjoint(archived_small));
+        assert!(archived_empty.is_subset_of_hash_set::<str, _, _>(&HashSet::<String>::new()));
+        assert!(archived_empty.is_disjoint_from_hash_set::<str, _, _>(&large));
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     #[allow(deprecated)]
@@ -179,6 +690,84 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_duration() {
+        use std::time::Duration;
+
+        for value in [
+            Duration::new(0, 0),
+            Duration::new(1, 500_000_000),
+            Duration::new(u64::MAX, 999_999_999),
+        ]
+        .iter()
+        {
+            test_archive(value);
+
+            let mut serializer = DefaultSerializer::default();
+            serializer.serialize_value(value).unwrap();
+            let buf = serializer.into_serializer().into_inner();
+            let archived = unsafe { archived_root::<Duration>(buf.as_ref()) };
+            assert_eq!(archived.as_secs(), value.as_secs());
+            assert_eq!(archived.subsec_nanos(), value.subsec_nanos());
+            assert_eq!(archived.to_duration(), *value);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_unix_timestamp() {
+        use rkyv::{
+            with::{UnixTimestamp, UnixTimestampError},
+            AlignedVec, Fallible, Infallible,
+        };
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        #[derive(Default)]
+        struct TimestampSerializer(AlignedVec);
+
+        impl Fallible for TimestampSerializer {
+            type Error = UnixTimestampError;
+        }
+
+        impl Serializer for TimestampSerializer {
+            fn pos(&self) -> usize {
+                self.0.len()
+            }
+
+            fn write(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+                self.0.extend_from_slice(bytes);
+                Ok(())
+            }
+        }
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        struct Test {
+            #[with(UnixTimestamp)]
+            time: SystemTime,
+        }
+
+        let value = Test {
+            time: UNIX_EPOCH + Duration::new(1_600_000_000, 123_456_789),
+        };
+        let mut serializer = TimestampSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let archived = unsafe { archived_root::<Test>(serializer.0.as_ref()) };
+        assert_eq!(archived.time.as_secs(), 1_600_000_000);
+        assert_eq!(archived.time.subsec_nanos(), 123_456_789);
+        let deserialized: Test = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, value);
+
+        let value = Test {
+            time: UNIX_EPOCH - Duration::from_nanos(1),
+        };
+        let mut serializer = TimestampSerializer::default();
+        assert!(matches!(
+            serializer.serialize_value(&value),
+            Err(UnixTimestampError::TimeBeforeUnixEpoch)
+        ));
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn archive_net() {
@@ -213,6 +802,37 @@ mod tests {
         test_archive(&value);
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_socket_addrs() {
+        use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+        let value = vec![
+            SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080)),
+            SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 1, 2)),
+            SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 0)),
+            SocketAddr::V6(SocketAddrV6::new(
+                Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+                65535,
+                0,
+                0,
+            )),
+        ];
+
+        test_archive(&value);
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<Vec<SocketAddr>>(buf.as_ref()) };
+        for (addr, archived) in value.iter().zip(archived.iter()) {
+            assert_eq!(archived.as_socket_addr(), *addr);
+            assert_eq!(archived.ip(), addr.ip());
+            assert_eq!(archived.port(), addr.port());
+            assert_eq!(archived.is_ipv4(), addr.is_ipv4());
+        }
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn c_string() {
@@ -278,6 +898,101 @@ mod tests {
     //     assert_eq!(*deserialized.value.read().unwrap(), 10);
     // }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(feature = "os_str")]
+    fn archive_os_string_and_path_buf() {
+        use std::{ffi::OsString, path::PathBuf};
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[archive(compare(PartialEq))]
+        #[archive_attr(derive(Debug))]
+        struct Test {
+            os_string: OsString,
+            path: PathBuf,
+        }
+
+        test_archive(&Test {
+            os_string: OsString::new(),
+            path: PathBuf::new(),
+        });
+        test_archive(&Test {
+            os_string: OsString::from("hello world"),
+            path: PathBuf::from("/usr/local/bin"),
+        });
+
+        let value = vec![PathBuf::from("a/b"), PathBuf::from("c.txt")];
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<Vec<PathBuf>>(buf.as_ref()) };
+        for (path, archived) in value.iter().zip(archived.iter()) {
+            assert_eq!(archived.to_path(), path.as_path());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(all(feature = "os_str", unix))]
+    fn archive_non_utf8_path() {
+        use rkyv::Infallible;
+        use std::{
+            ffi::{OsStr, OsString},
+            os::unix::ffi::OsStrExt,
+            path::{Path, PathBuf},
+        };
+
+        let value = PathBuf::from(OsStr::from_bytes(b"/tmp/\xff\xfe/invalid"));
+        assert!(value.to_str().is_none());
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<PathBuf>(buf.as_ref()) };
+        assert_eq!(archived.as_path(), value.as_path());
+        assert!(archived.starts_with(Path::new("/tmp")));
+        assert_eq!(archived.file_name(), Some(OsStr::new("invalid")));
+        let deserialized: PathBuf = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, value);
+
+        let value = OsStr::from_bytes(b"\x80abc").to_os_string();
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<OsString>(buf.as_ref()) };
+        assert_eq!(archived.as_encoded_bytes(), b"\x80abc");
+        let deserialized: OsString = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, value);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(feature = "os_str")]
+    fn archive_boxed_os_str() {
+        use std::ffi::{OsStr, OsString};
+
+        #[derive(Archive, Serialize)]
+        struct Test {
+            boxed: Box<OsStr>,
+            owned: OsString,
+        }
+
+        let value = Test {
+            boxed: OsStr::new("hello").into(),
+            owned: OsString::from("world"),
+        };
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&value).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<Test>(buf.as_ref()) };
+        assert_eq!(*archived.boxed, *OsStr::new("hello"));
+        assert_eq!(archived.boxed.as_encoded_bytes(), b"hello");
+        assert_eq!(archived.boxed.to_os_str(), OsStr::new("hello"));
+        assert_eq!(*archived.owned, *OsStr::new("world"));
+        assert!(*archived.boxed < *archived.owned);
+    }
+
     // #[test]
     // #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     // fn os_string() {
@@ -364,4 +1079,323 @@ mod tests {
         value.insert(());
         test_archive(&value);
     }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(all(feature = "debug_serialize", target_pointer_width = "64"))]
+    #[should_panic(expected = "offset for field `payload: ")]
+    fn debug_serialize_names_overflowing_field() {
+        use rkyv::{Archived, Fallible, RelPtr};
+
+        // Points far past the end of any archive
+        struct Far;
+
+        impl Archive for Far {
+            type Archived = RelPtr<Archived<u32>>;
+            type Resolver = ();
+
+            unsafe fn resolve(&self, pos: usize, _: (), out: *mut Self::Archived) {
+                RelPtr::emplace(pos, pos + (1 << 40), out);
+            }
+        }
+
+        impl<S: Fallible + ?Sized> Serialize<S> for Far {
+            fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+                Ok(())
+            }
+        }
+
+        #[derive(Archive, Serialize)]
+        struct Container {
+            header: u32,
+            payload: Far,
+        }
+
+        let _ = rkyv::to_bytes::<_, 256>(&Container {
+            header: 0,
+            payload: Far,
+        });
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(all(feature = "debug_serialize", target_pointer_width = "64"))]
+    fn debug_serialize_returns_overflowing_field() {
+        use rkyv::{
+            ser::{
+                breadcrumb::{self, DebugSerializeError},
+                serializers::AllocSerializer,
+            },
+            Archived, Fallible, RelPtr,
+        };
+
+        // Points far past the end of any archive
+        struct Far;
+
+        impl Archive for Far {
+            type Archived = RelPtr<Archived<u32>>;
+            type Resolver = ();
+
+            unsafe fn resolve(&self, pos: usize, _: (), out: *mut Self::Archived) {
+                RelPtr::emplace(pos, pos + (1 << 40), out);
+            }
+        }
+
+        impl<S: Fallible + ?Sized> Serialize<S> for Far {
+            fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+                Ok(())
+            }
+        }
+
+        #[derive(Archive, Serialize)]
+        struct Container {
+            header: u32,
+            payload: Far,
+        }
+
+        let mut serializer = AllocSerializer::<256>::default();
+        let value = Container {
+            header: 0,
+            payload: Far,
+        };
+        match breadcrumb::serialize_value(&mut serializer, &value) {
+            Err(DebugSerializeError::Offset(error)) => {
+                let field = error.field.unwrap();
+                assert_eq!(field.field, "payload");
+                assert!(error.to_string().starts_with("offset for field `payload: "));
+            }
+            _ => panic!("expected an offset error"),
+        }
+
+        // Offset errors panic again outside of `serialize_value`
+        let result = std::panic::catch_unwind(|| {
+            let _ = rkyv::to_bytes::<_, 256>(&Container {
+                header: 0,
+                payload: Far,
+            });
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(feature = "debug_serialize")]
+    fn profiling_serializer_records_layout() {
+        use core::mem::size_of;
+        use rkyv::{ser::serializers::ProfilingSerializer, Archived};
+
+        #[derive(Archive, Serialize)]
+        struct Document {
+            id: u64,
+            title: String,
+            tags: Vec<u16>,
+        }
+
+        let value = Document {
+            id: 42,
+            title: "a title that is too long to be stored inline".to_string(),
+            tags: vec![1, 2, 3],
+        };
+
+        let mut serializer = ProfilingSerializer::new(DefaultSerializer::default());
+        let pos = serializer.serialize_value(&value).unwrap();
+        let (serializer, entries) = serializer.into_components();
+
+        let root = entries.last().unwrap();
+        assert!(root.type_name.ends_with("::Document"));
+        assert_eq!(root.start, pos);
+        assert_eq!(root.len, size_of::<Archived<Document>>());
+        assert_eq!(root.end(), serializer.pos());
+
+        // The elements of the tags slice are recorded before the root unless they're copied
+        #[cfg(not(feature = "copy"))]
+        {
+            let tags = entries
+                .iter()
+                .filter(|e| e.type_name == "u16")
+                .collect::<Vec<_>>();
+            assert_eq!(tags.len(), 3);
+            for (i, tag) in tags.iter().enumerate() {
+                assert_eq!(tag.start, tags[0].start + i * 2);
+                assert_eq!(tag.len, 2);
+                assert!(tag.end() <= root.start);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "archive_dyn")]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_dyn_trait_objects() {
+        use rkyv::{
+            archive_dyn,
+            trait_object::{DynRegistry, DynSerializer, DynType},
+            with::{AsDyn, Map},
+        };
+
+        #[archive_dyn]
+        pub trait Shape {
+            fn area(&self) -> f32;
+            fn name(&self) -> String;
+        }
+
+        #[derive(Archive, Serialize)]
+        pub struct Circle {
+            radius: f32,
+        }
+
+        unsafe impl DynType for Circle {
+            const TYPE_ID: u32 = 1;
+        }
+
+        impl Shape for Circle {
+            fn area(&self) -> f32 {
+                3.0 * self.radius * self.radius
+            }
+
+            fn name(&self) -> String {
+                "circle".to_string()
+            }
+        }
+
+        impl Shape for ArchivedCircle {
+            fn area(&self) -> f32 {
+                3.0 * self.radius * self.radius
+            }
+
+            fn name(&self) -> String {
+                "archived circle".to_string()
+            }
+        }
+
+        #[derive(Archive, Serialize)]
+        pub struct Polygon {
+            sides: Vec<f32>,
+            label: String,
+        }
+
+        unsafe impl DynType for Polygon {
+            const TYPE_ID: u32 = 2;
+        }
+
+        impl Shape for Polygon {
+            fn area(&self) -> f32 {
+                self.sides.iter().product()
+            }
+
+            fn name(&self) -> String {
+                self.label.clone()
+            }
+        }
+
+        impl Shape for ArchivedPolygon {
+            fn area(&self) -> f32 {
+                self.sides.iter().product()
+            }
+
+            fn name(&self) -> String {
+                format!("archived {}", self.label)
+            }
+        }
+
+        #[derive(Archive, Serialize)]
+        struct Scene {
+            #[with(AsDyn)]
+            main: Box<dyn SerializeShape>,
+            #[with(Map<AsDyn>)]
+            shapes: Vec<Box<dyn SerializeShape>>,
+        }
+
+        let value = Scene {
+            main: Box::new(Circle { radius: 2.0 }),
+            shapes: vec![
+                Box::new(Polygon {
+                    sides: vec![2.0, 5.0],
+                    label: "rectangle".to_string(),
+                }),
+                Box::new(Circle { radius: 1.0 }),
+            ],
+        };
+
+        let mut serializer = DefaultSerializer::default();
+        let dyn_serializer: &mut dyn DynSerializer = &mut serializer;
+        dyn_serializer.serialize_value(&value).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+        let archived = unsafe { archived_root::<Scene>(buf.as_ref()) };
+
+        let mut registry = DynRegistry::<dyn Shape>::new();
+        assert!(registry.register::<Circle>(|circle| circle));
+        assert!(registry.register::<Polygon>(|polygon| polygon));
+        assert!(!registry.register::<Circle>(|circle| circle));
+        assert_eq!(registry.len(), 2);
+
+        let main = archived.main.get(&registry).unwrap();
+        assert_eq!(archived.main.type_id(), Circle::TYPE_ID);
+        assert_eq!(main.area(), 12.0);
+        assert_eq!(main.name(), "archived circle");
+
+        assert_eq!(archived.shapes.len(), 2);
+        let rectangle = archived.shapes[0].get(&registry).unwrap();
+        assert_eq!(rectangle.area(), 10.0);
+        assert_eq!(rectangle.name(), "archived rectangle");
+        let circle = archived.shapes[1].get(&registry).unwrap();
+        assert_eq!(circle.area(), 3.0);
+        assert_eq!(circle.name(), "archived circle");
+
+        let polygon = archived.shapes[0].downcast::<Polygon>().unwrap();
+        assert_eq!(polygon.label, "rectangle");
+        assert!(archived.shapes[0].downcast::<Circle>().is_none());
+
+        // Types that aren't registered can't be accessed as trait objects
+        let mut circles_only = DynRegistry::<dyn Shape>::new();
+        circles_only.register::<Circle>(|circle| circle);
+        assert!(archived.shapes[0].get(&circles_only).is_none());
+        assert!(archived.shapes[1].get(&circles_only).is_some());
+    }
+
+    #[test]
+    #[cfg(all(feature = "archive_dyn", unix))]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn archive_dyn_foreign_error() {
+        use rkyv::{
+            archive_dyn,
+            trait_object::{DynSerializer, DynType},
+            with::{AsDyn, AsString, AsStringError},
+        };
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::PathBuf};
+
+        #[archive_dyn]
+        pub trait Named {}
+
+        #[derive(Archive, Serialize)]
+        pub struct File {
+            #[with(AsString)]
+            path: PathBuf,
+        }
+
+        unsafe impl DynType for File {
+            const TYPE_ID: u32 = 1;
+        }
+
+        impl Named for File {}
+        impl Named for ArchivedFile {}
+
+        #[derive(Archive, Serialize)]
+        struct Directory {
+            #[with(AsDyn)]
+            entry: Box<dyn SerializeNamed>,
+        }
+
+        // Paths that aren't valid UTF-8 fail to serialize with an error from outside the serializer
+        let value = Directory {
+            entry: Box::new(File {
+                path: PathBuf::from(OsStr::from_bytes(b"invalid \xff")),
+            }),
+        };
+
+        let mut serializer = DefaultSerializer::default();
+        let dyn_serializer: &mut dyn DynSerializer = &mut serializer;
+        let error = dyn_serializer.serialize_value(&value).unwrap_err();
+        assert!(error.is::<AsStringError>());
+    }
 }
@@ -5,6 +5,7 @@ mod tests {
     use alloc::{
         boxed::Box,
         collections::{BTreeMap, BTreeSet},
+        format,
         rc::Rc,
         string::{String, ToString},
         vec,
@@ -21,6 +22,7 @@ mod tests {
     #[cfg(feature = "wasm")]
     use wasm_bindgen_test::*;
 
+    #[cfg(not(feature = "debug_rel_ptr"))]
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn basic_functionality() {
@@ -187,6 +189,40 @@ mod tests {
         result.unwrap_err();
     }
 
+    #[cfg(not(feature = "debug_rel_ptr"))]
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn check_corrupt_varint_offset() {
+        use rkyv::{
+            rel_ptr::{Offset, RawRelPtr, RelPtr, RelPtrCheckError, VarintOffset},
+            Infallible,
+        };
+
+        // Every byte has its continuation bit set, so the varint never terminates
+        let buf = AlignedBytes([0x80u8; 16]);
+        let ptr = buf.as_ptr().cast::<RawRelPtr<VarintOffset<16>>>();
+        unsafe {
+            assert!(RawRelPtr::manual_check_bytes(ptr, &mut Infallible).is_err());
+            assert!(matches!(
+                RelPtr::<u8, VarintOffset<16>>::manual_check_bytes_with_offset(
+                    ptr.cast(),
+                    &mut Infallible
+                ),
+                Err(RelPtrCheckError::OffsetCheckBytesError(_))
+            ));
+            // Unchecked access doesn't panic either
+            (*ptr).offset();
+        }
+
+        let mut buf = AlignedBytes([0u8; 16]);
+        buf[..2].copy_from_slice(VarintOffset::<2>::between(0, 12).unwrap().as_bytes());
+        let ptr = buf.as_ptr().cast::<RawRelPtr<VarintOffset<2>>>();
+        unsafe {
+            let checked = RawRelPtr::manual_check_bytes(ptr, &mut Infallible).unwrap();
+            assert_eq!(checked.offset(), 12);
+        }
+    }
+
     #[cfg(feature = "size_32")]
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
@@ -357,6 +393,40 @@ mod tests {
         ])));
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn derive_enum_explicit_discriminants() {
+        #[derive(Archive, Serialize)]
+        #[archive(discriminants)]
+        #[archive_attr(derive(CheckBytes))]
+        #[repr(u8)]
+        enum Test {
+            A = 3,
+            B(u32) = 7,
+            C,
+        }
+
+        serialize_and_check(&Test::A);
+        serialize_and_check(&Test::B(42));
+        serialize_and_check(&Test::C);
+
+        let mut serializer = DefaultSerializer::default();
+        serializer.serialize_value(&Test::A).unwrap();
+        let mut buf = serializer.into_serializer().into_inner();
+        let pos = buf.len() - core::mem::size_of::<ArchivedTest>();
+        assert_eq!(buf[pos], 3);
+
+        for tag in [0u8, 1, 4, 7, 8, 9] {
+            buf[pos] = tag;
+            let result = check_archived_root::<Test>(buf.as_ref());
+            match tag {
+                // B's payload is all zeroes, which is a valid u32
+                7 | 8 => assert!(result.is_ok()),
+                _ => assert!(result.is_err()),
+            }
+        }
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn recursive_type() {
@@ -380,6 +450,94 @@ mod tests {
         serialize_and_check(&Node::Cons(Box::new(Node::Cons(Box::new(Node::Nil)))));
     }
 
+    #[cfg(feature = "size_32")]
+    #[cfg(not(feature = "debug_rel_ptr"))]
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn check_cyclic_pointer() {
+        use rkyv::validation::{check_archived_value_with_context, validators::DefaultValidator};
+
+        #[derive(Archive, Serialize)]
+        #[archive(bound(serialize = "__S: Serializer"))]
+        #[archive_attr(derive(CheckBytes, Debug))]
+        #[archive_attr(check_bytes(
+            bound = "__C: ::rkyv::validation::ArchiveContext, <__C as ::rkyv::Fallible>::Error: ::bytecheck::Error"
+        ))]
+        enum Node {
+            Nil,
+            Cons(
+                #[omit_bounds]
+                #[archive_attr(omit_bounds)]
+                Box<Node>,
+            ),
+        }
+
+        // Acyclic archives still pass
+        let value = Node::Cons(Box::new(Node::Cons(Box::new(Node::Nil))));
+        let mut serializer = DefaultSerializer::default();
+        let pos = serializer.serialize_value(&value).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+        let mut validator = DefaultValidator::with_cycle_detection(buf.as_ref());
+        check_archived_value_with_context::<Node, _>(buf.as_ref(), pos, &mut validator).unwrap();
+
+        // Shared pointers to the same value are still allowed
+        let shared = Rc::new(10u32);
+        let value = vec![shared.clone(), shared];
+        let mut serializer = DefaultSerializer::default();
+        let pos = serializer.serialize_value(&value).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+        let mut validator = DefaultValidator::with_cycle_detection(buf.as_ref());
+        check_archived_value_with_context::<Vec<Rc<u32>>, _>(buf.as_ref(), pos, &mut validator)
+            .unwrap();
+
+        // Invalid archive (the root node points to a node that points back to the root)
+        let synthetic_buf = AlignedBytes([
+            // First node
+            1u8, 0u8, 0u8, 0u8, // Cons
+            4u8, 0u8, 0u8, 0u8, // Node is 4 bytes forward
+            // Second node (root)
+            1u8, 0u8, 0u8, 0u8, // Cons
+            244u8, 255u8, 255u8, 255u8, // Node is 12 bytes back
+        ]);
+
+        let error = check_archived_value::<Node>(synthetic_buf.as_ref(), 8).unwrap_err();
+        assert!(!error.to_string().contains("cyclic pointer"));
+
+        let mut validator = DefaultValidator::with_cycle_detection(synthetic_buf.as_ref());
+        let error =
+            check_archived_value_with_context::<Node, _>(synthetic_buf.as_ref(), 8, &mut validator)
+                .unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("cyclic pointer"), "{}", message);
+        assert!(message.contains("at offset 0x8"), "{}", message);
+    }
+
+    #[cfg(feature = "debug_rel_ptr")]
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn check_corrupt_rel_ptr_tag() {
+        let value = Box::new(42u32);
+
+        let mut serializer = DefaultSerializer::default();
+        let pos = serializer.serialize_value(&value).unwrap();
+        let mut buf = serializer.into_serializer().into_inner();
+
+        check_archived_value::<Box<u32>>(buf.as_ref(), pos).unwrap();
+
+        // The debug tag is written in native byte order after the offset
+        let tag = 0x7276_6b79u32.to_ne_bytes();
+        let tag_pos = pos + buf[pos..].windows(4).position(|w| w == tag).unwrap();
+        buf[tag_pos] ^= 0xff;
+
+        let error = check_archived_value::<Box<u32>>(buf.as_ref(), pos).unwrap_err();
+        let message = error.to_string();
+        assert!(
+            message.contains("relative pointer tag is corrupted"),
+            "{}",
+            message
+        );
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn check_shared_ptr() {
@@ -531,6 +689,41 @@ mod tests {
         check_archived_root::<Duration>(&[0xFF, 16]).unwrap_err();
     }
 
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn check_char() {
+        fn char_bytes(value: u32) -> AlignedBytes<4> {
+            #[cfg(feature = "archive_le")]
+            let bytes = value.to_le_bytes();
+            #[cfg(feature = "archive_be")]
+            let bytes = value.to_be_bytes();
+            #[cfg(not(any(feature = "archive_le", feature = "archive_be")))]
+            let bytes = value.to_ne_bytes();
+            AlignedBytes(bytes)
+        }
+
+        #[derive(Archive, Serialize)]
+        #[archive_attr(derive(CheckBytes))]
+        struct Glyph {
+            ascii: char,
+            emoji: char,
+        }
+
+        serialize_and_check(&'a');
+        serialize_and_check(&'\u{1F980}');
+        serialize_and_check(&Glyph {
+            ascii: 'z',
+            emoji: '\u{1F600}',
+        });
+
+        check_archived_root::<char>(char_bytes('a' as u32).as_ref()).unwrap();
+        check_archived_root::<char>(char_bytes(0x10FFFF).as_ref()).unwrap();
+        // Surrogate code points and values above 0x10FFFF are not valid chars
+        check_archived_root::<char>(char_bytes(0xD800).as_ref()).unwrap_err();
+        check_archived_root::<char>(char_bytes(0xDFFF).as_ref()).unwrap_err();
+        check_archived_root::<char>(char_bytes(0x110000).as_ref()).unwrap_err();
+    }
+
     #[test]
     #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
     fn check_invalid_btreemap() {
@@ -555,4 +748,278 @@ mod tests {
             )
         ));
     }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn check_multiple_roots() {
+        let first = vec!["hello".to_string(), "world".to_string()];
+        let second = "a second root that is stored after the first".to_string();
+
+        let mut serializer = DefaultSerializer::default();
+        let first_pos = serializer.serialize_value(&first).unwrap();
+        let second_pos = serializer.serialize_value(&second).unwrap();
+        let buf = serializer.into_serializer().into_inner();
+
+        let archived_first = check_archived_value::<Vec<String>>(buf.as_ref(), first_pos).unwrap();
+        assert_eq!(archived_first, &first);
+        let archived_second = check_archived_value::<String>(buf.as_ref(), second_pos).unwrap();
+        assert_eq!(archived_second, &second);
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(not(feature = "strict"))]
+    fn check_unaligned_root() {
+        use rkyv::{
+            check_archived_root_unaligned, validation::validators::CheckUnalignedError, AlignedVec,
+            Archived,
+        };
+
+        let value = (42u64, "hello world".to_string());
+        let bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+
+        let archived = check_archived_root_unaligned::<(u64, String)>(bytes.as_ref()).unwrap();
+        assert_eq!(archived.0, 42);
+        assert_eq!(archived.1, "hello world");
+
+        let mut misaligned = AlignedVec::new();
+        misaligned.push(0);
+        misaligned.extend_from_slice(bytes.as_ref());
+        match check_archived_root_unaligned::<(u64, String)>(&misaligned[1..]) {
+            Err(CheckUnalignedError::Unaligned { required, actual }) => {
+                assert_eq!(required, core::mem::align_of::<Archived<(u64, String)>>());
+                assert_eq!(actual, 1);
+            }
+            other => panic!("expected unaligned error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn check_single_field() {
+        use core::ptr;
+        use rkyv::check_archived_field;
+
+        #[derive(Archive, Serialize)]
+        #[archive_attr(derive(CheckBytes, Debug))]
+        struct Test {
+            corrupt: String,
+            valid: String,
+        }
+
+        let value = Test {
+            corrupt: "this string will be corrupted".to_string(),
+            valid: "this string will stay valid".to_string(),
+        };
+
+        let mut serializer = DefaultSerializer::default();
+        let pos = serializer.serialize_value(&value).unwrap();
+        let mut buf = serializer.into_serializer().into_inner();
+
+        // Corrupt the UTF-8 bytes of the first string
+        let start = buf
+            .windows(value.corrupt.len())
+            .position(|w| w == value.corrupt.as_bytes())
+            .unwrap();
+        buf[start] = 0xff;
+
+        check_archived_value::<Test>(buf.as_ref(), pos).unwrap_err();
+        check_archived_field::<Test, _, _>(buf.as_ref(), pos, |root| unsafe {
+            ptr::addr_of!((*root).corrupt)
+        })
+        .unwrap_err();
+
+        let valid = check_archived_field::<Test, _, _>(buf.as_ref(), pos, |root| unsafe {
+            ptr::addr_of!((*root).valid)
+        })
+        .unwrap();
+        assert_eq!(valid, "this string will stay valid");
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn check_prefix_header() {
+        use rkyv::check_archived_prefix;
+
+        #[derive(Archive, Serialize)]
+        #[archive_attr(derive(CheckBytes, Debug))]
+        struct Header {
+            magic: [u8; 4],
+            version: u16,
+            flags: u16,
+        }
+
+        let mut serializer = DefaultSerializer::default();
+        let header_pos = serializer
+            .serialize_value(&Header {
+                magic: *b"RKYV",
+                version: 2,
+                flags: 0,
+            })
+            .unwrap();
+        assert_eq!(header_pos, 0);
+        let header_len = serializer.pos();
+        serializer
+            .serialize_value(&vec!["body".to_string(), "strings".to_string()])
+            .unwrap();
+        let mut buf = serializer.into_serializer().into_inner();
+
+        // Replace everything after the header with garbage. Avoid 0xff so the lengths it decodes to
+        // still fit in a slice layout with size_64.
+        for byte in buf[header_len..].iter_mut() {
+            *byte = 0x01;
+        }
+
+        check_archived_root::<Vec<String>>(buf.as_ref()).unwrap_err();
+        let header = check_archived_prefix::<Header>(buf.as_ref()).unwrap();
+        assert_eq!(header.magic, *b"RKYV");
+        assert_eq!(header.version, 2);
+        assert_eq!(header.flags, 0);
+
+        // The header itself is still bounds checked
+        check_archived_prefix::<Header>(&buf[..header_len - 1]).unwrap_err();
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn check_error_position() {
+        use core::{mem::size_of, slice};
+        use rkyv::{
+            validation::{
+                owned::OwnedPointerError,
+                validators::{ArchiveError, DefaultValidatorError},
+                CheckArchiveError,
+            },
+            Archived, FixedIsize,
+        };
+
+        let value = vec![1u32, 2, 3, 4];
+
+        let mut serializer = DefaultSerializer::default();
+        let pos = serializer.serialize_value(&value).unwrap();
+        let mut buf = serializer.into_serializer().into_inner();
+
+        // Point the root vec's relative pointer far outside of the buffer
+        let offset: Archived<isize> = rkyv::to_archived!(0x4000 as FixedIsize);
+        let offset = unsafe {
+            slice::from_raw_parts(
+                (&offset as *const Archived<isize>).cast::<u8>(),
+                size_of::<Archived<isize>>(),
+            )
+        };
+        buf[pos..pos + offset.len()].copy_from_slice(offset);
+
+        let error = check_archived_root::<Vec<u32>>(buf.as_ref()).unwrap_err();
+        let archive_error = match &error {
+            CheckArchiveError::CheckBytesError(OwnedPointerError::ContextError(
+                DefaultValidatorError::ArchiveError(e @ ArchiveError::OutOfBounds { .. }),
+            )) => e,
+            other => panic!("expected out of bounds error, got {:?}", other),
+        };
+        let position = archive_error.position().unwrap();
+        assert_eq!(position.offset, pos);
+        assert!(error.to_string().contains(&format!("at offset {:#x}", pos)));
+
+        #[cfg(feature = "hex_context")]
+        {
+            let context = &position.context;
+            let index = context.index.unwrap();
+            assert_eq!(context.start + index, pos);
+            assert_eq!(context.bytes()[index], buf[pos]);
+            assert!(error.to_string().contains(&format!("[{:02x}]", buf[pos])));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn check_large_arrays() {
+        #[derive(Archive, Serialize)]
+        #[archive_attr(derive(CheckBytes))]
+        struct Test {
+            values: [u16; 100],
+            flags: [bool; 40],
+        }
+
+        let mut value = Test {
+            values: [0; 100],
+            flags: [false; 40],
+        };
+        for (i, v) in value.values.iter_mut().enumerate() {
+            *v = i as u16;
+        }
+        value.flags[33] = true;
+
+        let mut buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = check_archived_root::<Test>(buf.as_ref()).unwrap();
+        assert_eq!(archived.values[99], 99);
+        assert!(archived.flags[33]);
+
+        // Invalid bools past the 32nd element are still checked
+        let flag = &archived.flags[39] as *const bool as usize - buf.as_ptr() as usize;
+        buf[flag] = 2;
+        assert!(check_archived_root::<Test>(buf.as_ref()).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn check_delta_encoded() {
+        use rkyv::with::DeltaEncoded;
+
+        #[derive(Archive, Serialize)]
+        #[archive_attr(derive(CheckBytes))]
+        struct Timeline {
+            #[with(DeltaEncoded)]
+            timestamps: Vec<u64>,
+        }
+
+        // Every delta is encoded as the bytes [0xc8, 0x01]
+        let value = Timeline {
+            timestamps: (0..40).map(|i| i * 100).collect(),
+        };
+
+        let mut buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = check_archived_root::<Timeline>(buf.as_ref()).unwrap();
+        assert_eq!(archived.timestamps.get(39), Some(3900));
+
+        // Truncate the last delta by marking its last byte as continued
+        let last = buf
+            .windows(2)
+            .rposition(|bytes| bytes == [0xc8, 0x01])
+            .unwrap();
+        buf[last + 1] = 0x81;
+        assert!(check_archived_root::<Timeline>(buf.as_ref()).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    fn check_nonzero() {
+        use core::num::{NonZeroI64, NonZeroU32};
+        use rkyv::with::Niche;
+
+        #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+        #[archive_attr(derive(CheckBytes))]
+        struct Test {
+            id: NonZeroU32,
+            delta: NonZeroI64,
+            #[with(Niche)]
+            parent: Option<NonZeroU32>,
+        }
+
+        let value = Test {
+            id: NonZeroU32::new(42).unwrap(),
+            delta: NonZeroI64::new(-7).unwrap(),
+            parent: None,
+        };
+
+        let mut buf = rkyv::to_bytes::<_, 256>(&value).unwrap();
+        let archived = check_archived_root::<Test>(buf.as_ref()).unwrap();
+        assert!(archived.parent.is_none());
+        let deserialized: Test = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, value);
+
+        // Zeroing a nonzero field makes the archive invalid
+        let id = &archived.id as *const _ as usize - buf.as_ptr() as usize;
+        buf[id..id + 4].copy_from_slice(&[0; 4]);
+        assert!(check_archived_root::<Test>(buf.as_ref()).is_err());
+    }
 }
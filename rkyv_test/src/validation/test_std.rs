@@ -25,4 +25,133 @@ mod tests {
         set.insert("baz".to_string());
         serialize_and_check(&set);
     }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(feature = "os_str")]
+    fn os_string_and_path_buf() {
+        use std::{ffi::OsString, path::PathBuf};
+
+        serialize_and_check(&OsString::from("hello world"));
+        serialize_and_check(&PathBuf::from("/usr/local/bin"));
+        serialize_and_check(&vec![PathBuf::new(), PathBuf::from("a/b/c.txt")]);
+        serialize_and_check(&OsString::from("hello world").into_boxed_os_str());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(feature = "rayon")]
+    fn check_archived_root_parallel() {
+        use bytecheck::CheckBytes;
+        use rkyv::{check_archived_root, check_archived_root_parallel, Archive, Serialize};
+
+        #[derive(Archive, Serialize)]
+        #[archive_attr(derive(CheckBytes))]
+        struct Example {
+            id: u32,
+            name: String,
+            values: Vec<u32>,
+        }
+
+        let value = (0..100_000)
+            .map(|i| Example {
+                id: i,
+                name: format!("example number {}", i),
+                values: (0..i % 8).collect(),
+            })
+            .collect::<Vec<_>>();
+        let mut bytes = rkyv::to_bytes::<_, 4096>(&value).unwrap();
+
+        let sequential = check_archived_root::<Vec<Example>>(&bytes).unwrap();
+        let parallel = check_archived_root_parallel::<Example>(&bytes).unwrap();
+        assert_eq!(sequential as *const _, parallel as *const _);
+        for (i, archived) in parallel.iter().enumerate() {
+            assert_eq!(archived.id, i as u32);
+            assert_eq!(archived.name, format!("example number {}", i));
+            assert_eq!(archived.values.len(), i % 8);
+        }
+
+        // Corrupt the string of one of the elements
+        let name = parallel[54_321].name.as_ptr() as usize - bytes.as_ptr() as usize;
+        bytes[name] = 0xff;
+
+        assert!(check_archived_root::<Vec<Example>>(&bytes).is_err());
+        assert!(check_archived_root_parallel::<Example>(&bytes).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(feature = "rayon")]
+    fn check_archived_root_parallel_overlapping() {
+        use core::mem;
+        use rkyv::{
+            check_archived_root, check_archived_root_parallel,
+            validation::{validators::ParallelValidatorError, CheckArchiveError},
+            vec::ArchivedVec,
+            FixedIsize,
+        };
+
+        let value = (0..16).map(|i| vec![i; 4]).collect::<Vec<Vec<u32>>>();
+        let mut bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+
+        let elements = check_archived_root::<Vec<Vec<u32>>>(&bytes)
+            .unwrap()
+            .as_ptr() as usize
+            - bytes.as_ptr() as usize;
+        let stride = mem::size_of::<ArchivedVec<u32>>();
+        let offset_size = mem::size_of::<FixedIsize>();
+
+        // Point the fifth element at the same data as the fourth element
+        let fourth = elements + 4 * stride;
+        let fifth = elements + 5 * stride;
+        let mut offset = [0; mem::size_of::<FixedIsize>()];
+        offset.copy_from_slice(&bytes[fourth..fourth + offset_size]);
+        let offset = FixedIsize::from_ne_bytes(offset) - stride as FixedIsize;
+        bytes[fifth..fifth + offset_size].copy_from_slice(&offset.to_ne_bytes());
+
+        assert!(check_archived_root::<Vec<Vec<u32>>>(&bytes).is_err());
+        match check_archived_root_parallel::<Vec<u32>>(&bytes) {
+            Err(CheckArchiveError::ContextError(ParallelValidatorError::OverlappingClaims {
+                index,
+            })) => assert_eq!(index, 5),
+            other => panic!("expected overlapping claims, got {:?}", other),
+        }
+    }
+
+    #[test]
+    #[cfg_attr(feature = "wasm", wasm_bindgen_test)]
+    #[cfg(feature = "zstd")]
+    fn zstd_round_trip() {
+        use rkyv::{
+            check_archived_root,
+            ser::{
+                serializers::{
+                    AllocScratch, CompositeSerializer, SharedSerializeMap, ZstdSerializer,
+                },
+                Serializer,
+            },
+            zstd_decompress_to_aligned, Deserialize, Infallible,
+        };
+
+        let value = (0..64)
+            .map(|i| (format!("key {}", i % 4), vec![i; 16]))
+            .collect::<Vec<(String, Vec<u32>)>>();
+
+        let mut serializer = CompositeSerializer::new(
+            ZstdSerializer::new(Vec::new()).unwrap(),
+            AllocScratch::default(),
+            SharedSerializeMap::default(),
+        );
+        serializer.serialize_value(&value).unwrap();
+        let len = serializer.pos();
+        let (serializer, _, _) = serializer.into_components();
+        let compressed = serializer.finish().unwrap();
+        assert!(compressed.len() < len);
+
+        let bytes = zstd_decompress_to_aligned(compressed.as_slice()).unwrap();
+        assert_eq!(bytes.len(), len);
+        let archived = check_archived_root::<Vec<(String, Vec<u32>)>>(&bytes).unwrap();
+        let deserialized: Vec<(String, Vec<u32>)> = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(deserialized, value);
+    }
 }